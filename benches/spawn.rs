@@ -0,0 +1,29 @@
+//! Spawn cost of large static layouts, i.e. elements without signals.
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use haalka::prelude::*;
+
+fn grid(rows: usize, columns: usize) -> Column<Node> {
+    Column::<Node>::new().items((0..rows).map(move |_| {
+        Row::<Node>::new().items((0..columns).map(|_| {
+            El::<Node>::new()
+                .width(Val::Px(10.))
+                .height(Val::Px(10.))
+                .background_color(BackgroundColor(Color::WHITE))
+        }))
+    }))
+}
+
+fn spawn_static(c: &mut Criterion) {
+    c.bench_function("spawn 100x100 static grid", |b| {
+        b.iter_batched(
+            || (World::new(), grid(100, 100)),
+            |(mut world, grid)| grid.spawn(&mut world),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, spawn_static);
+criterion_main!(benches);
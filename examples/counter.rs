@@ -14,7 +14,7 @@ fn main() {
             Startup,
             (
                 |world: &mut World| {
-                    ui_root().spawn(world);
+                    spawn_ui_root(world, counter());
                 },
                 camera,
             ),
@@ -25,24 +25,19 @@ fn main() {
 #[derive(Component)]
 struct Counter(Mutable<i32>);
 
-fn ui_root() -> impl Element {
+fn counter() -> impl Element {
     let counter = Mutable::new(0);
-    El::<Node>::new()
-        .height(Val::Percent(100.))
-        .width(Val::Percent(100.))
-        .align_content(Align::center())
-        .child(
-            Row::<Node>::new()
-                .with_node(|mut node| node.column_gap = Val::Px(15.0))
-                .item(counter_button(counter.clone(), "-", -1))
-                .item(
-                    El::<Text>::new()
-                        .text_font(TextFont::from_font_size(25.))
-                        .text_signal(counter.signal_ref(ToString::to_string).map(Text)),
-                )
-                .item(counter_button(counter.clone(), "+", 1))
-                .update_raw_el(move |raw_el| raw_el.insert(Counter(counter))),
+    Row::<Node>::new()
+        .align(Align::center())
+        .with_node(|mut node| node.column_gap = Val::Px(15.0))
+        .item(counter_button(counter.clone(), "-", -1))
+        .item(
+            El::<Text>::new()
+                .text_font(TextFont::from_font_size(25.))
+                .text_signal(counter.signal_ref(ToString::to_string).map(Text)),
         )
+        .item(counter_button(counter.clone(), "+", 1))
+        .update_raw_el(move |raw_el| raw_el.insert(Counter(counter)))
 }
 
 fn counter_button(counter: Mutable<i32>, label: &str, step: i32) -> impl Element {
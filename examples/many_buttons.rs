@@ -10,7 +10,7 @@ fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins,
-            HaalkaPlugin,
+            HaalkaPlugin::default(),
             FrameTimeDiagnosticsPlugin,
             LogDiagnosticsPlugin::default(),
         ))
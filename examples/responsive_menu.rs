@@ -10,7 +10,7 @@ use utils::*;
 
 use std::sync::OnceLock;
 
-use bevy::{prelude::*, window::WindowResized};
+use bevy::prelude::*;
 use futures_signals::signal::Mutable;
 use haalka::prelude::*;
 
@@ -24,7 +24,6 @@ fn main() {
             })
                 .chain(),
         )
-        .add_systems(Update, on_resize)
         .run();
 }
 
@@ -99,18 +98,20 @@ fn nine_slice_button() -> impl Element {
     .pressed_sync(pressed)
 }
 
-static WIDTH: Lazy<Mutable<f32>> = Lazy::new(default);
+fn width_signal() -> impl Signal<Item = f32> + Send + 'static {
+    window_size_signal().map(|size| size.x).dedupe()
+}
 
 fn horizontal() -> impl Element {
     Row::<Node>::new()
         .width(Val::Percent(100.))
         .height(Val::Percent(100.))
-        .with_node(|mut node| node.column_gap = Val::Px(GAP))
+        .column_gap(Val::Px(GAP))
         .item(
             Column::<Node>::new()
                 .width(Val::Percent(50.))
                 .height(Val::Percent(100.))
-                .with_node(|mut node| node.row_gap = Val::Px(GAP))
+                .row_gap(Val::Px(GAP))
                 .align_content(Align::center())
                 .items((0..8).map(|_| nine_slice_button())),
         )
@@ -121,7 +122,7 @@ fn vertical() -> impl Element {
     Column::<Node>::new()
         .width(Val::Percent(100.))
         .height(Val::Percent(100.))
-        .with_node(|mut node| node.row_gap = Val::Px(GAP))
+        .row_gap(Val::Px(GAP))
         .item(El::<ImageNode>::new().image_node(ImageNode::new(image().clone())))
         .item(
             Row::<Node>::new()
@@ -129,7 +130,7 @@ fn vertical() -> impl Element {
                 .align_content(Align::center())
                 .width(Val::Percent(100.))
                 .height(Val::Percent(50.))
-                .with_node(|mut node| node.column_gap = Val::Px(GAP))
+                .column_gap(Val::Px(GAP))
                 .items((0..8).map(|_| nine_slice_button())),
         )
 }
@@ -137,13 +138,10 @@ fn vertical() -> impl Element {
 fn menu() -> impl Element {
     nine_slice_el(always(3))
         .height(Val::Px(BASE_SIZE))
-        .with_node(|mut node| {
-            node.padding = UiRect::all(Val::Px(GAP));
-        })
-        .width_signal(WIDTH.signal().map(|width| BASE_SIZE.min(width)).dedupe().map(Val::Px))
+        .padding(Edges::all(GAP))
+        .width_signal(width_signal().map(|width| BASE_SIZE.min(width)).map(Val::Px))
         .child_signal(
-            WIDTH
-                .signal()
+            width_signal()
                 .map(|width| width > 400.)
                 .dedupe()
                 .map_bool(|| horizontal().type_erase(), || vertical().type_erase()),
@@ -157,10 +155,10 @@ fn ui_root() -> impl Element {
         .align_content(Align::center())
         .child(
             Column::<Node>::new()
-                .with_node(|mut node| node.row_gap = Val::Px(GAP))
+                .row_gap(Val::Px(GAP))
                 .item(
                     Row::<Node>::new()
-                        .with_node(|mut node| node.padding.left = Val::Px(GAP))
+                        .padding(Edges::new().left(GAP))
                         .item(
                             El::<Text>::new()
                                 .text_font(TextFont::from_font_size(FONT_SIZE))
@@ -169,7 +167,7 @@ fn ui_root() -> impl Element {
                         .item(
                             El::<Text>::new()
                                 .text_font(TextFont::from_font_size(FONT_SIZE))
-                                .text_signal(WIDTH.signal_ref(ToString::to_string).map(Text)),
+                                .text_signal(width_signal().map(|width| width.to_string()).map(Text)),
                         ),
                 )
                 .item(menu()),
@@ -192,9 +190,3 @@ fn setup(
         .expect("failed to initialize IMAGE");
     commands.spawn(Camera2d);
 }
-
-fn on_resize(mut resize_events: EventReader<WindowResized>) {
-    for event in resize_events.read() {
-        WIDTH.set(event.width)
-    }
-}
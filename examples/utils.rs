@@ -134,7 +134,7 @@ pub(crate) fn examples_plugin(app: &mut App) {
             }),
             ..default()
         }),
-        HaalkaPlugin,
+        HaalkaPlugin::default(),
         FpsOverlayPlugin,
         #[cfg(feature = "debug")]
         DebugUiPlugin,
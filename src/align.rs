@@ -29,9 +29,11 @@ use super::{
     column::Column,
     el::El,
     element::ElementWrapper,
+    flexible::Flexible,
     grid::Grid,
     raw::{DeferredUpdaterAppendDirection, RawElWrapper, RawHaalkaEl},
     row::Row,
+    sizeable::Sizeable,
     stack::Stack,
 };
 
@@ -65,6 +67,7 @@ impl Align {
         self.alignments.insert(Alignment::CenterY);
         self.alignments.remove(&Alignment::Top);
         self.alignments.remove(&Alignment::Bottom);
+        self.alignments.remove(&Alignment::Baseline);
         self
     }
 
@@ -72,6 +75,7 @@ impl Align {
         self.alignments.insert(Alignment::Top);
         self.alignments.remove(&Alignment::CenterY);
         self.alignments.remove(&Alignment::Bottom);
+        self.alignments.remove(&Alignment::Baseline);
         self
     }
 
@@ -79,6 +83,18 @@ impl Align {
         self.alignments.insert(Alignment::Bottom);
         self.alignments.remove(&Alignment::CenterY);
         self.alignments.remove(&Alignment::Top);
+        self.alignments.remove(&Alignment::Baseline);
+        self
+    }
+
+    /// Vertically align the first lines of text of [`Row`] items, rather than their boxes, e.g. so
+    /// that text of differing font sizes sits on the same line; only affects [`Row`]s and their
+    /// children.
+    pub fn baseline(mut self) -> Self {
+        self.alignments.insert(Alignment::Baseline);
+        self.alignments.remove(&Alignment::CenterY);
+        self.alignments.remove(&Alignment::Top);
+        self.alignments.remove(&Alignment::Bottom);
         self
     }
 
@@ -107,6 +123,7 @@ pub enum Alignment {
     Right,
     CenterX,
     CenterY,
+    Baseline,
 }
 
 /// Holder for [`Align`] data. See [`Alignable`] and [`ChildAlignable`].
@@ -395,6 +412,9 @@ impl Alignable for AlignabilityFacade {
     fn apply_content_alignment(_node: &mut Node, _alignment: Alignment, _action: AddRemove) {}
 }
 
+impl Flexible for AlignabilityFacade {}
+impl Sizeable for AlignabilityFacade {}
+
 impl ChildAlignable for AlignabilityFacade {
     fn apply_alignment_wrapper(&self) -> fn(&mut Node, Alignment, AddRemove) {
         match self.aligner {
@@ -0,0 +1,156 @@
+//! Semantics for positioning an element relative to another element's on screen rect, see
+//! [`Anchorable`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::{prelude::*, UiSystem};
+use futures_signals::signal::Mutable;
+
+use super::raw::{DeferredUpdaterAppendDirection, RawElWrapper};
+
+/// Where to place an anchored element in relation to its target; the first word specifies the
+/// side of the target the element is placed on and the second specifies how the element is
+/// aligned along that side, e.g. [`Anchor::BottomStart`] places the element below the target,
+/// flush with the target's left edge, like a dropdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum Anchor {
+    TopStart,
+    TopCenter,
+    TopEnd,
+    BottomStart,
+    BottomCenter,
+    BottomEnd,
+    LeftStart,
+    LeftCenter,
+    LeftEnd,
+    RightStart,
+    RightCenter,
+    RightEnd,
+}
+
+impl Anchor {
+    /// Top left position of an element of `size` anchored to the `target` [`Rect`].
+    pub fn position(self, target: Rect, size: Vec2) -> Vec2 {
+        let start_x = target.min.x;
+        let center_x = target.center().x - size.x / 2.;
+        let end_x = target.max.x - size.x;
+        let start_y = target.min.y;
+        let center_y = target.center().y - size.y / 2.;
+        let end_y = target.max.y - size.y;
+        let above = target.min.y - size.y;
+        let below = target.max.y;
+        let left = target.min.x - size.x;
+        let right = target.max.x;
+        match self {
+            Anchor::TopStart => Vec2::new(start_x, above),
+            Anchor::TopCenter => Vec2::new(center_x, above),
+            Anchor::TopEnd => Vec2::new(end_x, above),
+            Anchor::BottomStart => Vec2::new(start_x, below),
+            Anchor::BottomCenter => Vec2::new(center_x, below),
+            Anchor::BottomEnd => Vec2::new(end_x, below),
+            Anchor::LeftStart => Vec2::new(left, start_y),
+            Anchor::LeftCenter => Vec2::new(left, center_y),
+            Anchor::LeftEnd => Vec2::new(left, end_y),
+            Anchor::RightStart => Vec2::new(right, start_y),
+            Anchor::RightCenter => Vec2::new(right, center_y),
+            Anchor::RightEnd => Vec2::new(right, end_y),
+        }
+    }
+}
+
+/// The element an [`Anchorable`] element is anchored to; either a known [`Entity`] or a handle to
+/// one that may not exist yet, e.g. one populated in an
+/// [`.on_spawn`](super::raw::RawHaalkaEl::on_spawn).
+#[derive(Clone, Default)]
+pub struct AnchorTarget(Mutable<Option<Entity>>);
+
+impl From<Entity> for AnchorTarget {
+    fn from(entity: Entity) -> Self {
+        Self(Mutable::new(Some(entity)))
+    }
+}
+
+impl From<Mutable<Option<Entity>>> for AnchorTarget {
+    fn from(handle: Mutable<Option<Entity>>) -> Self {
+        Self(handle)
+    }
+}
+
+#[derive(Component)]
+struct AnchoredTo {
+    target: AnchorTarget,
+    anchor: Anchor,
+}
+
+/// Enables an element to be positioned relative to another element's on screen rect, keeping its
+/// position updated as either element's layout changes; the building block for dropdowns,
+/// tooltips, docked inspectors, etc. Pairs well with a [`Portal`](super::portal::Portal), which
+/// can escape the clipping of the anchored element's logical parent.
+pub trait Anchorable: RawElWrapper {
+    /// Anchor this element to the `target` element, placing it according to the [`Anchor`]. If the
+    /// `target` does not exist, the element is left where it is.
+    fn anchored_to(self, target: impl Into<AnchorTarget>, anchor: Anchor) -> Self {
+        let target = target.into();
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                raw_el
+                    .with_component::<Node>(|mut node| node.position_type = PositionType::Absolute)
+                    .insert(AnchoredTo { target, anchor })
+            })
+        })
+    }
+}
+
+/// Logical on screen [`Rect`] of a node.
+pub(crate) fn logical_rect(computed_node: &ComputedNode, global_transform: &GlobalTransform) -> Rect {
+    let scale = computed_node.inverse_scale_factor();
+    Rect::from_center_size(
+        global_transform.translation().truncate() * scale,
+        computed_node.size() * scale,
+    )
+}
+
+fn position_anchored(
+    mut anchored: Query<(&AnchoredTo, &ComputedNode, Option<&Parent>, &mut Node)>,
+    rects: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    for (AnchoredTo { target, anchor }, computed_node, parent_option, mut node) in anchored.iter_mut() {
+        let Some(target) = target.0.get() else {
+            continue;
+        };
+        let Ok(target_rect) = rects
+            .get(target)
+            .map(|(computed_node, global_transform)| logical_rect(computed_node, global_transform))
+        else {
+            continue;
+        };
+        let origin = parent_option
+            .and_then(|parent| rects.get(parent.get()).ok())
+            .map(|(computed_node, global_transform)| {
+                let inset = computed_node.border();
+                logical_rect(computed_node, global_transform).min
+                    + Vec2::new(inset.left, inset.top) * computed_node.inverse_scale_factor()
+            })
+            .unwrap_or_default();
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let position = anchor.position(target_rect, size) - origin;
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        position_anchored
+            .before(UiSystem::Layout)
+            .run_if(any_with_component::<AnchoredTo>),
+    );
+}
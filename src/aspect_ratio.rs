@@ -0,0 +1,192 @@
+//! Element which constrains its contents to a static or reactive width to height ratio, see
+//! [`AspectRatio`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_math::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+use super::{
+    anchorable::Anchorable,
+    el::El,
+    element::{ElementWrapper, IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gesture::GestureAware,
+    global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    layout_aware::LayoutAware,
+    pointer_event_aware::{CursorOnHoverable, PointerEventAware},
+    raw::RawElWrapper,
+    resizable::Resizable,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
+    spaceable::Spaceable,
+};
+
+/// [`Element`](super::element::Element) which constrains itself, and therefore its single child, to
+/// a width to height ratio, filling as much of its parent as possible.
+///
+/// The ratio is first enforced with [`Node::aspect_ratio`]; if the resulting layout does not
+/// respect the ratio (e.g. because the parent constrains both axes or the content overflows), the
+/// largest ratio preserving size that fits in the parent is measured and applied directly.
+///
+/// # Notes
+/// The child should fill its parent, e.g. with a [`Val::Percent(100.)`](Val::Percent) width and
+/// height, to be visually constrained.
+pub struct AspectRatio {
+    el: El<Node>,
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementWrapper for AspectRatio {
+    type EL = El<Node>;
+    fn element_mut(&mut self) -> &mut Self::EL {
+        &mut self.el
+    }
+}
+
+impl Anchorable for AspectRatio {}
+impl CursorOnHoverable for AspectRatio {}
+impl Feedbackable for AspectRatio {}
+impl Flexible for AspectRatio {}
+impl GestureAware for AspectRatio {}
+impl GlobalEventAware for AspectRatio {}
+impl KeyboardEventAware for AspectRatio {}
+impl KeyboardFocusable for AspectRatio {}
+impl LayoutAware for AspectRatio {}
+impl Nameable for AspectRatio {}
+impl PointerEventAware for AspectRatio {}
+impl Resizable for AspectRatio {}
+impl ScrollSnappable for AspectRatio {}
+impl Showable for AspectRatio {}
+impl Spaceable for AspectRatio {}
+impl UiRootable for AspectRatio {}
+
+/// Ratios within this many logical pixels of the target are considered respected.
+const ASPECT_RATIO_TOLERANCE: f32 = 1.;
+
+#[derive(Component, Clone, Copy)]
+struct AspectRatioConstraint {
+    ratio: f32,
+    measured: bool,
+}
+
+fn apply_ratio(entity: &mut EntityWorldMut, ratio: f32) {
+    entity.insert(AspectRatioConstraint { ratio, measured: false });
+    if let Some(mut node) = entity.get_mut::<Node>() {
+        node.aspect_ratio = Some(ratio);
+        node.width = Val::Percent(100.);
+        node.height = Val::Auto;
+    }
+}
+
+#[allow(missing_docs)]
+impl AspectRatio {
+    pub fn new() -> Self {
+        Self {
+            el: El::<Node>::new().with_node(|mut node| {
+                node.width = Val::Percent(100.);
+                node.max_width = Val::Percent(100.);
+                node.max_height = Val::Percent(100.);
+            }),
+        }
+    }
+
+    /// Set the width to height ratio, e.g. `16. / 9.`.
+    pub fn ratio(mut self, ratio_option: impl Into<Option<f32>>) -> Self {
+        if let Some(ratio) = ratio_option.into() {
+            self.el = self
+                .el
+                .update_raw_el(|raw_el| raw_el.with_entity(move |mut entity| apply_ratio(&mut entity, ratio)));
+        }
+        self
+    }
+
+    /// Reactively set the width to height ratio. If the signal outputs [`None`], the constraint is
+    /// removed.
+    pub fn ratio_signal<S: Signal<Item = impl Into<Option<f32>>> + Send + 'static>(
+        mut self,
+        ratio_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(ratio_option_signal) = ratio_option_signal_option.into() {
+            let ratio_option_signal = ratio_option_signal.map(|ratio_option| ratio_option.into());
+            self.el = self.el.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_entity::<Option<f32>>(ratio_option_signal, |mut entity, ratio_option| {
+                    if let Some(ratio) = ratio_option {
+                        apply_ratio(&mut entity, ratio);
+                    } else {
+                        entity.remove::<AspectRatioConstraint>();
+                        if let Some(mut node) = entity.get_mut::<Node>() {
+                            node.aspect_ratio = None;
+                            node.width = Val::Percent(100.);
+                            node.height = Val::Auto;
+                        }
+                    }
+                })
+            });
+        }
+        self
+    }
+
+    /// Declare the constrained child.
+    pub fn child<IOE: IntoOptionElement>(mut self, child_option: IOE) -> Self {
+        self.el = self.el.child(child_option);
+        self
+    }
+
+    /// Declare the reactive constrained child. When the [`Signal`] outputs [`None`], the child is
+    /// removed.
+    pub fn child_signal<IOE: IntoOptionElement + 'static, S: Signal<Item = IOE> + Send + 'static>(
+        mut self,
+        child_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        self.el = self.el.child_signal(child_option_signal_option);
+        self
+    }
+}
+
+fn enforce_aspect_ratios(
+    mut constrained: Query<(&mut AspectRatioConstraint, &ComputedNode, &Parent, &mut Node)>,
+    computed_nodes: Query<&ComputedNode>,
+) {
+    for (mut constraint, computed_node, parent, mut node) in constrained.iter_mut() {
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        if size.x <= 0. || size.y <= 0. {
+            continue;
+        }
+        if !constraint.measured {
+            if (size.x - size.y * constraint.ratio).abs() <= ASPECT_RATIO_TOLERANCE {
+                continue;
+            }
+            constraint.measured = true;
+        }
+        let Ok(parent_computed_node) = computed_nodes.get(parent.get()) else {
+            continue;
+        };
+        let inset = parent_computed_node.content_inset();
+        let available = (parent_computed_node.size() - Vec2::new(inset.left + inset.right, inset.top + inset.bottom))
+            * parent_computed_node.inverse_scale_factor();
+        let width = available.x.min(available.y * constraint.ratio).max(0.);
+        let (width, height) = (Val::Px(width), Val::Px(width / constraint.ratio));
+        if node.width != width || node.height != height {
+            node.width = width;
+            node.height = height;
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        enforce_aspect_ratios.run_if(any_with_component::<AspectRatioConstraint>),
+    );
+}
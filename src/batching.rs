@@ -0,0 +1,57 @@
+//! Opt-in coalescing of reactive [`Component`] updates, see [`SignalBatchingPlugin`].
+
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    mem,
+    sync::{Arc, Mutex},
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+type BatchedUpdate = Box<dyn FnOnce(&mut World) + Send>;
+
+/// Coalesces reactive [`Component`] updates, e.g. from
+/// [`.component_signal`](super::raw::RawHaalkaEl::component_signal), targeting the same [`Entity`]
+/// and [`Component`] within a frame into a single update, applied once per frame in
+/// [`PostUpdate`]; reduces command queue churn when many [`Mutable`](futures_signals::signal::Mutable)s
+/// change in bursts. Only affects elements spawned into an [`App`] with this plugin.
+pub struct SignalBatchingPlugin;
+
+impl Plugin for SignalBatchingPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "ui")]
+        let flush = flush_batched_updates.before(bevy_ui::UiSystem::Layout);
+        #[cfg(not(feature = "ui"))]
+        let flush = flush_batched_updates;
+        app.init_resource::<BatchedUpdates>().add_systems(
+            PostUpdate,
+            flush.run_if(|batched_updates: Res<BatchedUpdates>| !batched_updates.0.lock().unwrap().is_empty()),
+        );
+    }
+}
+
+/// Updates queued by the elements of an [`App`] with the [`SignalBatchingPlugin`]; shared with
+/// those elements when they are spawned, so their reactive tasks can queue updates without
+/// [`World`] access.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct BatchedUpdates(Arc<Mutex<HashMap<(Entity, TypeId), BatchedUpdate>>>);
+
+impl BatchedUpdates {
+    /// Queue `update` for `entity`'s `C` [`Component`], replacing any update already queued for it
+    /// this frame.
+    pub(crate) fn batch<C: Component>(&self, entity: Entity, update: impl FnOnce(&mut World) + Send + 'static) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert((entity, TypeId::of::<C>()), Box::new(update));
+    }
+}
+
+fn flush_batched_updates(world: &mut World) {
+    let updates = mem::take(&mut *world.resource::<BatchedUpdates>().0.lock().unwrap());
+    for update in updates.into_values() {
+        update(world);
+    }
+}
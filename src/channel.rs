@@ -0,0 +1,38 @@
+//! Bridges from [`async_channel`]s into the signal world, see [`channel_signal`] and
+//! [`channel_signal_vec`].
+
+use futures_signals::{
+    signal::{self, Signal},
+    signal_vec::{self, SignalVec},
+};
+
+/// Outputs the latest message received on `receiver`, or [`None`] if none has been received yet;
+/// lets background threads, e.g. networking or file watchers, push data straight into reactive UI.
+/// When passed to an element, the `receiver` is dropped along with the element's tasks when it is
+/// despawned.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let (sender, receiver) = async_channel::unbounded::<String>();
+/// std::thread::spawn(move || {
+///     let _ = sender.send_blocking("connected".to_string());
+/// });
+/// El::<Text>::new().text_signal(channel_signal(receiver).map(|status| Text(status.unwrap_or_default())));
+/// ```
+pub fn channel_signal<T: Send + 'static>(
+    receiver: async_channel::Receiver<T>,
+) -> impl Signal<Item = Option<T>> + Send + 'static {
+    signal::from_stream(receiver)
+}
+
+/// Outputs every message received on `receiver`, appended in the order they are received. When
+/// passed to an element, the `receiver` is dropped along with the element's tasks when it is
+/// despawned.
+pub fn channel_signal_vec<T: Send + 'static>(
+    receiver: async_channel::Receiver<T>,
+) -> impl SignalVec<Item = T> + Send + 'static {
+    signal_vec::from_stream(receiver)
+}
@@ -0,0 +1,119 @@
+//! Access to the system clipboard, see [`copy`], [`paste`], and [`SelectableText`].
+//!
+//! With the `text_input` feature, the focused `TextInput` copies, cuts, and pastes its selection
+//! with Ctrl+C, Ctrl+X, and Ctrl+V (or Command+C, Command+X, and Command+V) through [`copy`] and
+//! [`paste`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_log::prelude::*;
+use bevy_ui::widget::TextUiReader;
+
+use super::{
+    focus::{Focusable, FocusedElement},
+    keyboard::modifiers,
+    raw::{DeferredUpdaterAppendDirection, RawElWrapper},
+};
+
+cfg_if::cfg_if! {
+    if #[cfg(target_arch = "wasm32")] {
+        fn clipboard() -> Option<web_sys::Clipboard> {
+            Some(web_sys::window()?.navigator().clipboard())
+        }
+
+        /// Write `text` to the system clipboard.
+        pub fn copy(text: impl Into<String>) {
+            if let Some(clipboard) = clipboard() {
+                let write = wasm_bindgen_futures::JsFuture::from(clipboard.write_text(&text.into()));
+                wasm_bindgen_futures::spawn_local(async move {
+                    if write.await.is_err() {
+                        warn!("failed to write to the clipboard");
+                    }
+                });
+            }
+        }
+
+        /// Read the text in the system clipboard, or [`None`] if it is empty, does not hold text, or
+        /// cannot be accessed; on the web, the browser may ask the user for permission first.
+        pub async fn paste() -> Option<String> {
+            let read = wasm_bindgen_futures::JsFuture::from(clipboard()?.read_text());
+            send_wrapper::SendWrapper::new(read).await.ok()?.as_string()
+        }
+    } else {
+        use std::sync::Mutex;
+
+        use once_cell::sync::Lazy;
+
+        // on some platforms, e.g. X11, clipboard contents only live as long as the clipboard which
+        // set them, so it is kept alive for the whole app
+        static CLIPBOARD: Lazy<Mutex<Option<arboard::Clipboard>>> = Lazy::new(|| {
+            Mutex::new(
+                arboard::Clipboard::new()
+                    .inspect_err(|error| warn!("failed to access the clipboard: {error}"))
+                    .ok(),
+            )
+        });
+
+        /// Write `text` to the system clipboard.
+        pub fn copy(text: impl Into<String>) {
+            if let Some(clipboard) = CLIPBOARD.lock().unwrap().as_mut() {
+                if let Err(error) = clipboard.set_text(text.into()) {
+                    warn!("failed to write to the clipboard: {error}");
+                }
+            }
+        }
+
+        /// Read the text in the system clipboard, or [`None`] if it is empty, does not hold text, or
+        /// cannot be accessed; on the web, the browser may ask the user for permission first.
+        pub async fn paste() -> Option<String> {
+            CLIPBOARD.lock().unwrap().as_mut()?.get_text().ok()
+        }
+    }
+}
+
+/// Marks a text element whose text is copied to the clipboard when it has focus and Ctrl+C (or
+/// Command+C) is pressed, see [`SelectableText`].
+#[derive(Component, Default)]
+pub struct TextSelectable;
+
+/// Enables copying the text of a text element to the clipboard.
+pub trait SelectableText: RawElWrapper {
+    /// Make this text element selectable; clicking it, or reaching it with keyboard navigation,
+    /// selects the whole text, which is then copied to the clipboard when Ctrl+C (or Command+C) is
+    /// pressed. Use [`KeyboardFocusable::focused_signal`](super::focus::KeyboardFocusable::focused_signal)
+    /// to style the selected state.
+    fn selectable_text(self) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                // only focusable by clicking, so plain text does not clutter the tab order
+                raw_el.insert((TextSelectable, Focusable { tab_index: -1 }))
+            })
+        })
+    }
+}
+
+fn copy_selected_text(
+    keys: Res<ButtonInput<KeyCode>>,
+    focused: Res<FocusedElement>,
+    selectables: Query<(), With<TextSelectable>>,
+    mut reader: TextUiReader,
+) {
+    let Some(entity) = focused.0.filter(|&entity| selectables.contains(entity)) else {
+        return;
+    };
+    let modifiers = modifiers(&keys);
+    if keys.just_pressed(KeyCode::KeyC) && (modifiers.control || modifiers.super_) {
+        copy(reader.iter(entity).map(|(_, _, text, _, _)| text).collect::<String>());
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        copy_selected_text.run_if(
+            any_with_component::<TextSelectable>
+                .and(|keys: Res<ButtonInput<KeyCode>>| keys.just_pressed(KeyCode::KeyC)),
+        ),
+    );
+}
@@ -3,18 +3,33 @@ use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
 use futures_signals::{
     signal::{Signal, SignalExt},
+    signal_map::{SignalMap, SignalMapExt},
     signal_vec::{SignalVec, SignalVecExt},
 };
 
 use super::{
     align::{AddRemove, AlignHolder, Alignable, Aligner, Alignment, ChildAlignable},
-    element::{IntoOptionElement, Nameable, UiRootable},
+    anchorable::Anchorable,
+    element::{IntoElement, IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gappable::Gappable,
+    gesture::GestureAware,
     global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    kinetic_scroll::KineticScrollable,
+    layout_aware::LayoutAware,
     mouse_wheel_scrollable::MouseWheelScrollable,
     pointer_event_aware::{CursorOnHoverable, PointerEventAware},
     raw::{RawElWrapper, RawHaalkaEl},
+    resizable::Resizable,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
     sizeable::Sizeable,
+    spaceable::Spaceable,
     viewport_mutable::ViewportMutable,
+    z_layer::ZLayerable,
 };
 
 /// [`Element`](super::element::Element) with vertically stacked children. Port of [MoonZoon](https://github.com/MoonZoon/MoonZoon)'s [`Column`](https://github.com/MoonZoon/MoonZoon/blob/main/crates/zoon/src/element/column.rs).
@@ -62,14 +77,28 @@ impl<NodeType: Bundle> RawElWrapper for Column<NodeType> {
     }
 }
 
+impl<NodeType: Bundle> Anchorable for Column<NodeType> {}
 impl<NodeType: Bundle> CursorOnHoverable for Column<NodeType> {}
+impl<NodeType: Bundle> Feedbackable for Column<NodeType> {}
+impl<NodeType: Bundle> Flexible for Column<NodeType> {}
+impl<NodeType: Bundle> Gappable for Column<NodeType> {}
+impl<NodeType: Bundle> GestureAware for Column<NodeType> {}
 impl<NodeType: Bundle> GlobalEventAware for Column<NodeType> {}
+impl<NodeType: Bundle> KeyboardEventAware for Column<NodeType> {}
+impl<NodeType: Bundle> KeyboardFocusable for Column<NodeType> {}
+impl<NodeType: Bundle> KineticScrollable for Column<NodeType> {}
+impl<NodeType: Bundle> LayoutAware for Column<NodeType> {}
 impl<NodeType: Bundle> Nameable for Column<NodeType> {}
 impl<NodeType: Bundle> PointerEventAware for Column<NodeType> {}
 impl<NodeType: Bundle> MouseWheelScrollable for Column<NodeType> {}
+impl<NodeType: Bundle> Resizable for Column<NodeType> {}
+impl<NodeType: Bundle> ScrollSnappable for Column<NodeType> {}
+impl<NodeType: Bundle> Showable for Column<NodeType> {}
 impl<NodeType: Bundle> Sizeable for Column<NodeType> {}
+impl<NodeType: Bundle> Spaceable for Column<NodeType> {}
 impl<NodeType: Bundle> UiRootable for Column<NodeType> {}
 impl<NodeType: Bundle> ViewportMutable for Column<NodeType> {}
+impl<NodeType: Bundle> ZLayerable for Column<NodeType> {}
 
 impl<NodeType: Bundle> Column<NodeType> {
     /// Declare a static vertically stacked child.
@@ -136,6 +165,25 @@ impl<NodeType: Bundle> Column<NodeType> {
         }
         self
     }
+
+    /// Declare reactive vertically stacked children keyed by the keys of the [`SignalMap`],
+    /// ordered by key; each key keeps its child until it is removed or its value is updated.
+    pub fn items_signal_map<
+        K: Ord + Send + 'static,
+        IE: IntoElement + 'static,
+        S: SignalMap<Key = K, Value = IE> + Send + 'static,
+    >(
+        mut self,
+        items_signal_map_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(items_signal_map) = items_signal_map_option.into() {
+            let apply_alignment = self.apply_alignment_wrapper();
+            self.raw_el = self.raw_el.children_signal_map(
+                items_signal_map.map_value(move |item| Self::align_child(item.into_element(), apply_alignment)),
+            );
+        }
+        self
+    }
 }
 
 impl<NodeType: Bundle> Alignable for Column<NodeType> {
@@ -185,6 +233,7 @@ impl<NodeType: Bundle> Alignable for Column<NodeType> {
                     AddRemove::Remove => JustifyContent::DEFAULT,
                 }
             }
+            Alignment::Baseline => {}
         }
     }
 }
@@ -228,6 +277,7 @@ impl<NodeType: Bundle> ChildAlignable for Column<NodeType> {
                     AddRemove::Remove => (Val::ZERO, Val::ZERO),
                 }
             }
+            Alignment::Baseline => {}
         }
     }
 }
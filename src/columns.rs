@@ -0,0 +1,274 @@
+//! Text that flows across multiple columns of balanced height, see [`Columns`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_text::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+
+use super::{
+    align::{Align, Alignable},
+    el::El,
+    element::{ElementWrapper, Nameable, UiRootable},
+    flexible::Flexible,
+    gappable::Gappable,
+    global_event_aware::GlobalEventAware,
+    layout_aware::LayoutAware,
+    pointer_event_aware::{CursorOnHoverable, PointerEventAware},
+    raw::RawElWrapper,
+    row::Row,
+    showable::Showable,
+    sizeable::Sizeable,
+    spaceable::Spaceable,
+    utils::clone,
+};
+
+/// Maximum number of frames spent rebalancing the heights of a [`Columns`]' columns after its text
+/// or width changes.
+pub const COLUMNS_MAX_BALANCE_ITERATIONS: u32 = 8;
+
+/// Horizontally stacked, equally wide columns of text, which long text flows across such that the
+/// columns are roughly the same height, like a newspaper article. The columns are rebalanced
+/// whenever the text or the width of the container changes.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// Columns::new(3)
+///     .gap(Val::Px(20.))
+///     .text_font(TextFont::from_font_size(14.))
+///     .text("Lorem ipsum dolor sit amet, consectetur adipiscing elit ...");
+/// ```
+pub struct Columns {
+    el: Row<Node>,
+    text_font: Mutable<TextFont>,
+    text_color: Mutable<TextColor>,
+}
+
+impl ElementWrapper for Columns {
+    type EL = Row<Node>;
+    fn element_mut(&mut self) -> &mut Self::EL {
+        &mut self.el
+    }
+}
+
+impl CursorOnHoverable for Columns {}
+impl Flexible for Columns {}
+impl Gappable for Columns {}
+impl GlobalEventAware for Columns {}
+impl LayoutAware for Columns {}
+impl Nameable for Columns {}
+impl PointerEventAware for Columns {}
+impl Showable for Columns {}
+impl Sizeable for Columns {}
+impl Spaceable for Columns {}
+impl UiRootable for Columns {}
+
+#[derive(Component)]
+struct TextFlow {
+    words: Vec<String>,
+    /// Index of the first word of each column after the first.
+    splits: Vec<usize>,
+    width: f32,
+    stale: bool,
+    iterations: u32,
+}
+
+impl TextFlow {
+    fn new(count: usize) -> Self {
+        Self {
+            words: Vec::new(),
+            splits: vec![0; count - 1],
+            width: 0.,
+            stale: true,
+            iterations: 0,
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.splits.len() + 1
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.words = text.split_whitespace().map(ToOwned::to_owned).collect();
+        // start from an even split by characters, which is refined once the columns are laid out
+        let weights = self.words.iter().map(|word| word.len() as f32 + 1.).collect::<Vec<_>>();
+        self.splits = balanced_splits(&weights, self.count());
+        self.stale = true;
+        self.iterations = 0;
+    }
+
+    fn column_range(&self, column: usize) -> std::ops::Range<usize> {
+        let start = column.checked_sub(1).map(|i| self.splits[i]).unwrap_or(0);
+        let end = self.splits.get(column).copied().unwrap_or(self.words.len());
+        start..end
+    }
+
+    fn column_text(&self, column: usize) -> String {
+        self.words[self.column_range(column)].join(" ")
+    }
+}
+
+/// Index of the column a [`Text`] node of a [`Columns`] displays.
+#[derive(Component)]
+struct TextColumn(usize);
+
+impl Columns {
+    /// Construct a new [`Columns`] with `count` columns.
+    pub fn new(count: usize) -> Self {
+        let count = count.max(1);
+        let text_font = Mutable::new(TextFont::default());
+        let text_color = Mutable::new(TextColor::default());
+        let el = Row::<Node>::new()
+            .align_content(Align::new().top())
+            .update_raw_el(|raw_el| raw_el.insert(TextFlow::new(count)))
+            .items((0..count).map(clone!((text_font, text_color) move |i| {
+                El::<Text>::new()
+                    .grow(1.)
+                    .basis(Val::ZERO)
+                    .text_font_signal(text_font.signal_cloned())
+                    .text_color_signal(text_color.signal())
+                    .update_raw_el(|raw_el| raw_el.insert(TextColumn(i)))
+            })));
+        Self {
+            el,
+            text_font,
+            text_color,
+        }
+    }
+
+    /// Set the text flowed across the columns.
+    pub fn text<T: Into<String>>(mut self, text_option: impl Into<Option<T>>) -> Self {
+        if let Some(text) = text_option.into().map(Into::into) {
+            self.el = self
+                .el
+                .update_raw_el(|raw_el| raw_el.with_component::<TextFlow>(move |mut flow| flow.set_text(&text)));
+        }
+        self
+    }
+
+    /// Reactively set the text flowed across the columns.
+    pub fn text_signal<S: Signal<Item = impl Into<String>> + Send + 'static>(
+        mut self,
+        text_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(text_signal) = text_signal_option.into() {
+            self.el = self.el.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_component::<String, TextFlow>(text_signal.map(Into::into), |mut flow, text| {
+                    flow.set_text(&text)
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the [`TextFont`] of the columns' text.
+    pub fn text_font(self, text_font_option: impl Into<Option<TextFont>>) -> Self {
+        if let Some(text_font) = text_font_option.into() {
+            self.text_font.set(text_font);
+        }
+        self
+    }
+
+    /// Set the [`TextColor`] of the columns' text.
+    pub fn text_color(self, text_color_option: impl Into<Option<TextColor>>) -> Self {
+        if let Some(text_color) = text_color_option.into() {
+            self.text_color.set(text_color);
+        }
+        self
+    }
+}
+
+/// Indices at which to split `weights` into `count` runs of roughly equal total weight.
+fn balanced_splits(weights: &[f32], count: usize) -> Vec<usize> {
+    let total = weights.iter().sum::<f32>();
+    let mut splits = Vec::with_capacity(count - 1);
+    let mut cumulative = 0.;
+    for (i, weight) in weights.iter().enumerate() {
+        // split before the word whose midpoint passes the next boundary
+        while splits.len() < count - 1 && cumulative + weight / 2. > total * (splits.len() + 1) as f32 / count as f32 {
+            splits.push(i);
+        }
+        cumulative += weight;
+    }
+    splits.resize(count - 1, weights.len());
+    splits
+}
+
+fn write_columns(flow: &TextFlow, children: &Children, columns: &mut Query<(&TextColumn, &ComputedNode, &mut Text)>) {
+    for &child in children.iter() {
+        if let Ok((&TextColumn(i), _, mut text)) = columns.get_mut(child) {
+            let column_text = flow.column_text(i);
+            if text.0 != column_text {
+                text.0 = column_text;
+            }
+        }
+    }
+}
+
+fn balance_text_columns(
+    mut flows: Query<(&mut TextFlow, &ComputedNode, &Children)>,
+    mut columns: Query<(&TextColumn, &ComputedNode, &mut Text)>,
+) {
+    for (mut flow, computed_node, children) in flows.iter_mut() {
+        let width = computed_node.size().x;
+        if width != flow.width {
+            flow.width = width;
+            flow.iterations = 0;
+        }
+        if flow.stale {
+            write_columns(&flow, children, &mut columns);
+            flow.stale = false;
+            continue;
+        }
+        if flow.iterations >= COLUMNS_MAX_BALANCE_ITERATIONS {
+            continue;
+        }
+        let mut heights = vec![0.; flow.count()];
+        for &child in children.iter() {
+            if let Ok((&TextColumn(i), computed_node, _)) = columns.get(child) {
+                heights[i] = computed_node.size().y;
+            }
+        }
+        let lengths = (0..flow.count())
+            .map(|column| {
+                flow.words[flow.column_range(column)]
+                    .iter()
+                    .map(|word| word.len() as f32 + 1.)
+                    .sum::<f32>()
+            })
+            .collect::<Vec<_>>();
+        // not laid out yet
+        if heights
+            .iter()
+            .zip(&lengths)
+            .any(|(&height, &length)| length > 0. && height <= 0.)
+        {
+            continue;
+        }
+        flow.iterations += 1;
+        // estimate each word's height from the density of the column it is currently in
+        let weights = (0..flow.count())
+            .flat_map(|column| {
+                let density = heights[column] / lengths[column];
+                flow.words[flow.column_range(column)]
+                    .iter()
+                    .map(move |word| (word.len() as f32 + 1.) * density)
+            })
+            .collect::<Vec<_>>();
+        let splits = balanced_splits(&weights, flow.count());
+        if splits == flow.splits {
+            flow.iterations = COLUMNS_MAX_BALANCE_ITERATIONS;
+        } else {
+            flow.splits = splits;
+            write_columns(&flow, children, &mut columns);
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, balance_text_columns.run_if(any_with_component::<TextFlow>));
+}
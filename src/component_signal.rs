@@ -0,0 +1,161 @@
+//! Reactive access to the [`Component`]s of arbitrary entities, see [`component_signal_of`] and
+//! [`sync_component_of`].
+
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{component::Tick, prelude::*};
+use futures_signals::signal::{Mutable, MutableSignalCloned, Signal, SignalExt};
+use once_cell::sync::Lazy;
+
+struct ComponentSyncer {
+    /// Returns whether the syncer should be kept.
+    sync: Box<dyn FnMut(&mut World, Tick, Tick) -> bool + Send>,
+    last_run: Tick,
+}
+
+static COMPONENT_SYNCERS: Lazy<Mutex<Vec<ComponentSyncer>>> = Lazy::new(Mutex::default);
+
+/// [`Signal`] returned by [`component_signal_of`]; the [`Component`] stops being tracked once this
+/// is dropped.
+struct ComponentSignal<C> {
+    signal: MutableSignalCloned<Option<C>>,
+    _alive: Arc<()>,
+}
+
+impl<C: Clone> Signal for ComponentSignal<C> {
+    type Item = Option<C>;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.get_mut().signal.poll_change_unpin(cx)
+    }
+}
+
+/// Outputs a clone of the [`Component`] `C` of `entity` whenever it changes, or [`None`] if
+/// `entity` does not have a `C`; useful for tracking game entities, e.g. a player's health, in the
+/// UI without an intermediate [`Mutable`]. Once `entity` is despawned, outputs [`None`] and stops
+/// tracking it; also stops tracking it once the returned [`Signal`] is dropped.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// fn health_bar(player: Entity) -> impl Element {
+///     El::<Text>::new().text_signal(
+///         component_signal_of::<Health>(player)
+///             .map(|health_option| Text(health_option.map(|Health(health)| health).unwrap_or_default().to_string())),
+///     )
+/// }
+/// ```
+pub fn component_signal_of<C: Component + Clone>(entity: Entity) -> impl Signal<Item = Option<C>> + Send + 'static {
+    let mutable = Mutable::new(None);
+    let alive = Arc::new(());
+    let weak: Weak<()> = Arc::downgrade(&alive);
+    COMPONENT_SYNCERS.lock().unwrap().push(ComponentSyncer {
+        sync: Box::new({
+            let mutable = mutable.clone();
+            move |world, last_run, this_run| {
+                if weak.upgrade().is_none() {
+                    return false;
+                }
+                let Ok(entity_ref) = world.get_entity(entity) else {
+                    mutable.set(None);
+                    return false;
+                };
+                match entity_ref.get_change_ticks::<C>() {
+                    Some(ticks) => {
+                        if ticks.is_changed(last_run, this_run) {
+                            mutable.set(entity_ref.get::<C>().cloned());
+                        }
+                    }
+                    None => {
+                        if mutable.lock_ref().is_some() {
+                            mutable.set(None);
+                        }
+                    }
+                }
+                true
+            }
+        }),
+        last_run: Tick::new(0),
+    });
+    ComponentSignal {
+        signal: mutable.signal_cloned(),
+        _alive: alive,
+    }
+}
+
+/// Keep `mutable` and the [`Component`] `C` of `entity` in sync in both directions. If `entity`
+/// already has a `C`, `mutable` is initialized from it, otherwise a `C` is inserted with the value of
+/// `mutable`. Values are only forwarded when they differ from the last synced value, so changes
+/// never echo back and forth; if both sides change in the same frame, the [`Component`] wins. Once
+/// `entity` is despawned, stops syncing. See
+/// [`RawHaalkaEl::sync_component`](super::raw::RawHaalkaEl::sync_component) for syncing an
+/// element's own [`Component`]s.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Component, Clone, PartialEq)]
+/// struct Health(u32);
+///
+/// fn spawn_player(world: &mut World) -> Mutable<Health> {
+///     let health = Mutable::new(Health(10));
+///     let player = world.spawn(Health(10)).id();
+///     sync_component_of(player, health.clone());
+///     health
+/// }
+/// ```
+pub fn sync_component_of<C: Component + Clone + PartialEq>(entity: Entity, mutable: Mutable<C>) {
+    let mut last_option: Option<C> = None;
+    COMPONENT_SYNCERS.lock().unwrap().push(ComponentSyncer {
+        sync: Box::new(move |world, last_run, this_run| {
+            let Ok(mut entity) = world.get_entity_mut(entity) else {
+                return false;
+            };
+            if let Some(ticks) = entity.get_change_ticks::<C>() {
+                if ticks.is_changed(last_run, this_run) {
+                    let component = entity.get::<C>().unwrap();
+                    if last_option.as_ref() != Some(component) {
+                        last_option = Some(component.clone());
+                        mutable.set(component.clone());
+                        return true;
+                    }
+                }
+            }
+            let value = mutable.get_cloned();
+            if last_option.as_ref() != Some(&value) || !entity.contains::<C>() {
+                last_option = Some(value.clone());
+                entity.insert(value);
+            }
+            true
+        }),
+        last_run: Tick::new(0),
+    });
+}
+
+fn sync_component_signals(world: &mut World) {
+    let this_run = world.change_tick();
+    COMPONENT_SYNCERS.lock().unwrap().retain_mut(|syncer| {
+        let keep = (syncer.sync)(world, syncer.last_run, this_run);
+        syncer.last_run = this_run;
+        keep
+    });
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Last,
+        sync_component_signals.run_if(|| !COMPONENT_SYNCERS.lock().unwrap().is_empty()),
+    );
+}
@@ -0,0 +1,58 @@
+//! Memoized derived state, see [`Computed`] and [`computed!`](crate::computed!).
+
+use std::{pin::Pin, sync::Arc};
+
+use futures_signals::signal::{Broadcaster, Signal, SignalExt};
+
+type BoxedSignal<T> = Pin<Box<dyn Signal<Item = T> + Send>>;
+
+/// Derived state whose value is computed once per change of its source [`Signal`], no matter how
+/// many times it is subscribed to, and only output when it changes according to [`PartialEq`];
+/// makes derived UI state, e.g. "can afford item", cheap to share between many elements. Usually
+/// created with [`computed!`](crate::computed!).
+#[derive(Clone)]
+pub struct Computed<T: Clone> {
+    broadcaster: Arc<Broadcaster<BoxedSignal<T>>>,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> Computed<T> {
+    /// Memoize the output of `signal`.
+    pub fn new(signal: impl Signal<Item = T> + Send + 'static) -> Self {
+        Self {
+            broadcaster: Arc::new(Broadcaster::new(Box::pin(signal.dedupe_cloned()))),
+        }
+    }
+
+    /// Outputs the computed value whenever it changes.
+    pub fn signal(&self) -> impl Signal<Item = T> + Send + 'static {
+        self.broadcaster.signal_cloned()
+    }
+
+    /// Outputs the `f`ed computed value whenever it changes.
+    pub fn signal_ref<B, F: FnMut(&T) -> B + Send + 'static>(&self, f: F) -> impl Signal<Item = B> + Send + 'static {
+        self.broadcaster.signal_ref(f)
+    }
+}
+
+/// Combine many [`Signal`]s into a [`Computed`], using the same syntax as
+/// [`map_ref!`](futures_signals::map_ref).
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::{computed, prelude::*};
+///
+/// let gold = Mutable::new(10);
+/// let price = Mutable::new(15);
+/// let can_afford = computed! {
+///     let gold = gold.signal(),
+///     let price = price.signal() => gold >= price
+/// };
+/// El::<Node>::new().visible_signal(can_afford.signal());
+/// ```
+#[macro_export]
+macro_rules! computed {
+    ($($tokens:tt)*) => {
+        $crate::computed::Computed::new($crate::utils::map_ref!($($tokens)*))
+    };
+}
@@ -9,12 +9,17 @@ use paste::paste;
 /// Implement [haalka](crate)-esque methods for any [`RawElWrapper`] over the named components,
 /// enabling one to quickly add high level signals-powered reactivity to any [`Bundle`], not just [bevy_ui nodes](https://github.com/bevyengine/bevy/blob/main/crates/bevy_ui/src/node_bundles.rs).
 ///
+/// Prefixing a field with `#[dedupe]` makes its `*_signal` method skip outputs equal to the current
+/// component, see [`RawHaalkaEl::component_signal_dedupe`](super::raw::RawHaalkaEl::component_signal_dedupe);
+/// its component must be [`PartialEq`]. Don't use it for components Bevy recomputes every frame,
+/// e.g. `GlobalTransform`, since the comparison would be against a value about to be overwritten.
+///
 /// # Example
 /// ```
 /// use bevy::prelude::*;
 /// use haalka::{prelude::*, impl_haalka_methods};
 ///
-/// #[derive(Component, Default)]
+/// #[derive(Component, Clone, PartialEq, Default)]
 /// struct MyComponentA(usize);
 ///
 /// #[derive(Component, Default)]
@@ -40,7 +45,7 @@ use paste::paste;
 ///
 /// impl_haalka_methods! {
 ///     MyEl {
-///        my_component_a: MyComponentA,
+///        #[dedupe] my_component_a: MyComponentA,
 ///        some_other_component_idk: MyComponentB,
 ///     }
 /// }
@@ -53,7 +58,13 @@ use paste::paste;
 /// ```
 #[macro_export]
 macro_rules! impl_haalka_methods {
-    ($el_type:ty {$($field:ident: $field_type:ty),* $(,)?}) => {
+    (@component_signal $raw_el:ident, $signal:ident) => {
+        $raw_el.component_signal($signal)
+    };
+    (@component_signal $raw_el:ident, $signal:ident, dedupe) => {
+        $raw_el.component_signal_dedupe($signal)
+    };
+    ($el_type:ty {$($(#[$dedupe:ident])? $field:ident: $field_type:ty),* $(,)?}) => {
         impl $el_type {
             $(
                 paste! {
@@ -72,7 +83,7 @@ macro_rules! impl_haalka_methods {
 
                     #[doc = concat!("Reactively set this element's [`", stringify!($field_type), "`] [`Component`]. If the [`Signal`] outputs [`None`], the `C` [`Component`] is removed.")]
                     pub fn [<$field _signal>]<S: Signal<Item = $field_type> + Send + 'static>(self, [<$field _signal>]: impl Into<Option<S>>) -> Self {
-                        self.update_raw_el(|raw_el| raw_el.component_signal([<$field _signal>]))
+                        self.update_raw_el(|raw_el| $crate::impl_haalka_methods!(@component_signal raw_el, [<$field _signal>] $(, $dedupe)?))
                     }
 
                     #[doc = concat!("Reactively run a function with mutable access (via [`Mut`]) to this element's [`", stringify!($field_type), "`] [`Component`] and the output of the [`Signal`].")]
@@ -106,20 +117,20 @@ cfg_if::cfg_if! {
                     paste! {
                         impl_haalka_methods! {
                             $el_type<Node> {
-                                node: Node,
+                                #[dedupe] node: Node,
                                 computed_node: ComputedNode,
-                                background_color: BackgroundColor,
-                                border_color: BorderColor,
-                                border_radius: BorderRadius,
-                                box_shadow: BoxShadow,
-                                focus_policy: FocusPolicy,
+                                #[dedupe] background_color: BackgroundColor,
+                                #[dedupe] border_color: BorderColor,
+                                #[dedupe] border_radius: BorderRadius,
+                                #[dedupe] box_shadow: BoxShadow,
+                                #[dedupe] focus_policy: FocusPolicy,
                                 scroll_position: ScrollPosition,
-                                transform: Transform,
+                                #[dedupe] transform: Transform,
                                 global_transform: GlobalTransform,
-                                visibility: Visibility,
+                                #[dedupe] visibility: Visibility,
                                 inherited_visibility: InheritedVisibility,
                                 view_visibility: ViewVisibility,
-                                z_index: ZIndex,
+                                #[dedupe] z_index: ZIndex,
                                 global_z_index: GlobalZIndex,
                             }
                         }
@@ -128,20 +139,20 @@ cfg_if::cfg_if! {
                                 image_node: ImageNode,
                                 image_node_size: ImageNodeSize,
                                 content_size: ContentSize,
-                                node: Node,
+                                #[dedupe] node: Node,
                                 computed_node: ComputedNode,
-                                background_color: BackgroundColor,
-                                border_color: BorderColor,
-                                border_radius: BorderRadius,
-                                box_shadow: BoxShadow,
-                                focus_policy: FocusPolicy,
+                                #[dedupe] background_color: BackgroundColor,
+                                #[dedupe] border_color: BorderColor,
+                                #[dedupe] border_radius: BorderRadius,
+                                #[dedupe] box_shadow: BoxShadow,
+                                #[dedupe] focus_policy: FocusPolicy,
                                 scroll_position: ScrollPosition,
-                                transform: Transform,
+                                #[dedupe] transform: Transform,
                                 global_transform: GlobalTransform,
-                                visibility: Visibility,
+                                #[dedupe] visibility: Visibility,
                                 inherited_visibility: InheritedVisibility,
                                 view_visibility: ViewVisibility,
-                                z_index: ZIndex,
+                                #[dedupe] z_index: ZIndex,
                                 global_z_index: GlobalZIndex,
                             }
                         }
@@ -153,40 +164,40 @@ cfg_if::cfg_if! {
                                 text_color: TextColor,
                                 text_node_flags: TextNodeFlags,
                                 content_size: ContentSize,
-                                node: Node,
+                                #[dedupe] node: Node,
                                 computed_node: ComputedNode,
-                                background_color: BackgroundColor,
-                                border_color: BorderColor,
-                                border_radius: BorderRadius,
-                                box_shadow: BoxShadow,
-                                focus_policy: FocusPolicy,
+                                #[dedupe] background_color: BackgroundColor,
+                                #[dedupe] border_color: BorderColor,
+                                #[dedupe] border_radius: BorderRadius,
+                                #[dedupe] box_shadow: BoxShadow,
+                                #[dedupe] focus_policy: FocusPolicy,
                                 scroll_position: ScrollPosition,
-                                transform: Transform,
+                                #[dedupe] transform: Transform,
                                 global_transform: GlobalTransform,
-                                visibility: Visibility,
+                                #[dedupe] visibility: Visibility,
                                 inherited_visibility: InheritedVisibility,
                                 view_visibility: ViewVisibility,
-                                z_index: ZIndex,
+                                #[dedupe] z_index: ZIndex,
                                 global_z_index: GlobalZIndex,
                             }
                         }
                         impl_haalka_methods! {
                             $el_type<Button> {
                                 interaction: Interaction,
-                                node: Node,
+                                #[dedupe] node: Node,
                                 computed_node: ComputedNode,
-                                background_color: BackgroundColor,
-                                border_color: BorderColor,
-                                border_radius: BorderRadius,
-                                box_shadow: BoxShadow,
-                                focus_policy: FocusPolicy,
+                                #[dedupe] background_color: BackgroundColor,
+                                #[dedupe] border_color: BorderColor,
+                                #[dedupe] border_radius: BorderRadius,
+                                #[dedupe] box_shadow: BoxShadow,
+                                #[dedupe] focus_policy: FocusPolicy,
                                 scroll_position: ScrollPosition,
-                                transform: Transform,
+                                #[dedupe] transform: Transform,
                                 global_transform: GlobalTransform,
-                                visibility: Visibility,
+                                #[dedupe] visibility: Visibility,
                                 inherited_visibility: InheritedVisibility,
                                 view_visibility: ViewVisibility,
-                                z_index: ZIndex,
+                                #[dedupe] z_index: ZIndex,
                                 global_z_index: GlobalZIndex,
                             }
                         }
@@ -1,18 +1,39 @@
+use bevy_color::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_picking::prelude::*;
+use bevy_text::prelude::*;
 use bevy_ui::prelude::*;
-use futures_signals::signal::{Signal, SignalExt};
+use futures_signals::{
+    signal::{Mutable, Signal, SignalExt},
+    signal_vec::{MutableVec, SignalVecExt},
+};
 
+#[cfg(feature = "clipboard")]
+use super::clipboard::SelectableText;
 use super::{
     align::{AddRemove, AlignHolder, Alignable, Aligner, Alignment, ChildAlignable},
+    anchorable::Anchorable,
     column::Column,
     element::{IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gesture::GestureAware,
     global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    kinetic_scroll::KineticScrollable,
+    layout_aware::LayoutAware,
     mouse_wheel_scrollable::MouseWheelScrollable,
     pointer_event_aware::{CursorOnHoverable, PointerEventAware},
     raw::{RawElWrapper, RawHaalkaEl},
+    resizable::Resizable,
+    scroll_snap::ScrollSnappable,
+    showable::{on_first_shown, raw_show_signal, Showable},
     sizeable::Sizeable,
+    spaceable::Spaceable,
+    utils::{clone, spawn},
     viewport_mutable::ViewportMutable,
+    z_layer::ZLayerable,
 };
 
 // TODO: add the extra flag machinery that MoonZoon has to ensure that El's have exactly one child
@@ -66,14 +87,29 @@ impl<NodeType> RawElWrapper for El<NodeType> {
     }
 }
 
+impl<NodeType: Bundle> Anchorable for El<NodeType> {}
 impl<NodeType: Bundle> CursorOnHoverable for El<NodeType> {}
+impl<NodeType: Bundle> Feedbackable for El<NodeType> {}
+impl<NodeType: Bundle> Flexible for El<NodeType> {}
+impl<NodeType: Bundle> GestureAware for El<NodeType> {}
 impl<NodeType: Bundle> GlobalEventAware for El<NodeType> {}
+impl<NodeType: Bundle> KeyboardEventAware for El<NodeType> {}
+impl<NodeType: Bundle> KeyboardFocusable for El<NodeType> {}
+impl<NodeType: Bundle> KineticScrollable for El<NodeType> {}
+impl<NodeType: Bundle> LayoutAware for El<NodeType> {}
 impl<NodeType: Bundle> Nameable for El<NodeType> {}
 impl<NodeType: Bundle> PointerEventAware for El<NodeType> {}
 impl<NodeType: Bundle> MouseWheelScrollable for El<NodeType> {}
+impl<NodeType: Bundle> Resizable for El<NodeType> {}
+impl<NodeType: Bundle> ScrollSnappable for El<NodeType> {}
+#[cfg(feature = "clipboard")]
+impl<NodeType: Bundle> SelectableText for El<NodeType> {}
+impl<NodeType: Bundle> Showable for El<NodeType> {}
 impl<NodeType: Bundle> Sizeable for El<NodeType> {}
+impl<NodeType: Bundle> Spaceable for El<NodeType> {}
 impl<NodeType: Bundle> UiRootable for El<NodeType> {}
 impl<NodeType: Bundle> ViewportMutable for El<NodeType> {}
+impl<NodeType: Bundle> ZLayerable for El<NodeType> {}
 
 impl<NodeType: Bundle> El<NodeType> {
     /// Declare a static child.
@@ -102,6 +138,179 @@ impl<NodeType: Bundle> El<NodeType> {
         }
         self
     }
+
+    /// Declare a child which is only constructed and spawned once this element is first shown, i.e.
+    /// neither it nor any of its ancestors have [`Display::None`] or are
+    /// [`Visibility::Hidden`](bevy_render::prelude::Visibility::Hidden), e.g. for the contents of
+    /// hidden tabs and menus, which then don't slow down startup.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let settings_open = Mutable::new(false);
+    /// El::<Node>::new()
+    ///     .show_signal(settings_open.signal())
+    ///     .child_lazy(|| El::<Text>::new().text(Text::new("settings")));
+    /// ```
+    pub fn child_lazy<IOE: IntoOptionElement + 'static>(
+        mut self,
+        child: impl FnOnce() -> IOE + Send + 'static,
+    ) -> Self {
+        let shown = Mutable::new(false);
+        let mut child_option = Some(child);
+        self.raw_el = on_first_shown(self.raw_el, shown.clone());
+        self.child_signal(shown.signal().map(move |shown| {
+            shown
+                .then(|| child_option.take())
+                .flatten()
+                .and_then(|child| child().into_option_element())
+        }))
+    }
+
+    /// Declare a reactive child chosen by the key output by the [`Signal`], e.g. a [`bool`]. Each
+    /// key's child is spawned the first time the key is output and is afterwards only hidden and
+    /// shown, see [`Showable`], rather than despawned and respawned, so rapidly alternating views
+    /// don't spawn and despawn entities every switch, at the cost of keeping every key's child alive.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let editing = Mutable::new(false);
+    /// El::<Node>::new().child_signal_pooled(editing.signal(), |editing| {
+    ///     if editing {
+    ///         El::<Text>::new().text(Text::new("editing"))
+    ///     } else {
+    ///         El::<Text>::new().text(Text::new("viewing"))
+    ///     }
+    /// });
+    /// ```
+    pub fn child_signal_pooled<
+        K: Clone + PartialEq + Send + Sync + 'static,
+        IOE: IntoOptionElement + 'static,
+        S: Signal<Item = K> + Send + 'static,
+    >(
+        mut self,
+        key_signal_option: impl Into<Option<S>>,
+        mut child: impl FnMut(K) -> IOE + Send + 'static,
+    ) -> Self {
+        if let Some(key_signal) = key_signal_option.into() {
+            let apply_alignment = self.apply_alignment_wrapper();
+            let active = Mutable::new(None);
+            // keys in the order their children were first spawned
+            let keys = MutableVec::new();
+            let sync_keys = key_signal.for_each_sync(clone!((active, keys) move |key| {
+                if !keys.lock_ref().contains(&key) {
+                    keys.lock_mut().push_cloned(key.clone());
+                }
+                active.set(Some(key));
+            }));
+            self.raw_el = self
+                .raw_el
+                .hold_tasks([spawn(sync_keys)])
+                .children_signal_vec(keys.signal_vec_cloned().map(move |key: K| {
+                    child(key.clone()).into_option_element().map(|child| {
+                        let shown = active.signal_ref(move |active| active.as_ref() == Some(&key)).dedupe();
+                        raw_show_signal(Self::align_child(child, apply_alignment).into_raw(), shown)
+                    })
+                }));
+        }
+        self
+    }
+}
+
+impl El<Text> {
+    /// Reactively set the string of this element's [`Text`] in place, see
+    /// [`.text_section_signal`](Self::text_section_signal).
+    pub fn text_string_signal<S: Signal<Item = impl Into<String>> + Send + 'static>(
+        self,
+        string_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        self.text_section_signal(0, string_signal_option)
+    }
+
+    /// Reactively set the string of the `index`th section of this element's text, where the
+    /// [`Text`] itself is the `0`th section and its [`TextSpan`] descendants follow in depth first
+    /// order. Rather than replacing the whole [`Text`], the section's existing string is reused and
+    /// left untouched when the new one is equal, avoiding reallocation and relayout, e.g. for
+    /// frequently updating counters.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let score = Mutable::new(0);
+    /// El::<Text>::new()
+    ///     .text(Text::new("score: "))
+    ///     .update_raw_el(|raw_el| raw_el.child(RawHaalkaEl::from(TextSpan::default())))
+    ///     .text_section_signal(1, score.signal().map(|score| score.to_string()));
+    /// ```
+    pub fn text_section_signal<S: Signal<Item = impl Into<String>> + Send + 'static>(
+        mut self,
+        index: usize,
+        string_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(string_signal) = string_signal_option.into() {
+            self.raw_el = self.raw_el.on_signal_one_shot(
+                string_signal.map(Into::into),
+                move |In((entity, string)): In<(Entity, String)>, mut writer: TextUiWriter| {
+                    if let Some(mut text) = writer.get_text(entity, index) {
+                        if *text != string {
+                            text.clear();
+                            text.push_str(&string);
+                        }
+                    }
+                },
+            );
+        }
+        self
+    }
+
+    /// Reactively set the [`TextFont`] of the `index`th section of this element's text, see
+    /// [`.text_section_signal`](Self::text_section_signal).
+    pub fn text_section_font_signal<S: Signal<Item = TextFont> + Send + 'static>(
+        mut self,
+        index: usize,
+        font_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(font_signal) = font_signal_option.into() {
+            self.raw_el = self.raw_el.on_signal_one_shot(
+                font_signal,
+                move |In((entity, font)): In<(Entity, TextFont)>, mut writer: TextUiWriter| {
+                    if let Some(mut text_font) = writer.get_font(entity, index) {
+                        *text_font = font;
+                    }
+                },
+            );
+        }
+        self
+    }
+
+    /// Reactively set the [`TextColor`] of the `index`th section of this element's text, see
+    /// [`.text_section_signal`](Self::text_section_signal).
+    pub fn text_section_color_signal<S: Signal<Item = impl Into<Color>> + Send + 'static>(
+        mut self,
+        index: usize,
+        color_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(color_signal) = color_signal_option.into() {
+            self.raw_el = self.raw_el.on_signal_one_shot(
+                color_signal.map(Into::into),
+                move |In((entity, color)): In<(Entity, Color)>, mut writer: TextUiWriter| {
+                    if let Some(mut text_color) = writer.get_color(entity, index) {
+                        if text_color.0 != color {
+                            text_color.0 = color;
+                        }
+                    }
+                },
+            );
+        }
+        self
+    }
 }
 
 impl<NodeType: Bundle> Alignable for El<NodeType> {
@@ -151,6 +360,7 @@ impl<NodeType: Bundle> Alignable for El<NodeType> {
                     AddRemove::Remove => JustifyContent::DEFAULT,
                 }
             }
+            Alignment::Baseline => {}
         }
     }
 }
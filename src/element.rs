@@ -4,11 +4,15 @@ use std::borrow::Cow;
 
 use super::{
     align::{AlignabilityFacade, Alignable, Aligner, ChildAlignable},
+    el::El,
     raw::{RawElWrapper, RawElement, RawHaalkaEl},
+    sizeable::Sizeable,
 };
 use bevy_core::prelude::*;
+use bevy_core_pipeline::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_picking::prelude::*;
+use bevy_ui::prelude::*;
 use futures_signals::signal::{Signal, SignalExt};
 
 /// [`Element`]s are [`RawElement`]s that wrap [bevy_ui nodes](https://github.com/bevyengine/bevy/blob/main/crates/bevy_ui/src/node_bundles.rs)
@@ -183,11 +187,54 @@ pub trait UiRootable: RawElWrapper {
     }
 }
 
+fn full_window_root() -> El<Node> {
+    El::<Node>::new()
+        .width(Val::Percent(100.))
+        .height(Val::Percent(100.))
+        .ui_root()
+}
+
+/// Spawn `element` as the only child of a [`UiRoot`] which fills the window, returning the root
+/// [`Entity`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// fn setup(world: &mut World) {
+///     spawn_ui_root(world, El::<Text>::new().text(Text::new("hello")));
+/// }
+/// ```
+pub fn spawn_ui_root<IOE: IntoOptionElement>(world: &mut World, element: IOE) -> Entity {
+    full_window_root().child(element).spawn(world)
+}
+
+/// Same as [`spawn_ui_root`], but also spawns a dedicated [`Camera2d`] marked as the
+/// [`IsDefaultUiCamera`] which renders the UI, returning the root and camera [`Entity`]s.
+pub fn spawn_ui_root_with_camera<IOE: IntoOptionElement>(world: &mut World, element: IOE) -> (Entity, Entity) {
+    let camera = world.spawn((Camera2d, IsDefaultUiCamera)).id();
+    let root = full_window_root()
+        .update_raw_el(move |raw_el| raw_el.insert(TargetCamera(camera)))
+        .child(element)
+        .spawn(world);
+    (root, camera)
+}
+
 /// Convenience trait for adding a [`Name`] to an [`Element`].
 pub trait Nameable: RawElWrapper {
-    /// Set the [`Name`] of this element.
+    /// Set the [`Name`] of this element. With the `profiling` feature, this element's profiling
+    /// spans are also tagged with it.
     fn name<T: Into<Cow<'static, str>>>(mut self, name_option: impl Into<Option<T>>) -> Self {
         if let Some(name) = name_option.into() {
+            let name = name.into();
+            #[cfg(feature = "profiling")]
+            {
+                let profiling_name = name.clone();
+                self = self.update_raw_el(|raw_el| {
+                    raw_el.update_node_builder(|node_builder| node_builder.profiling_name(profiling_name))
+                });
+            }
             self = self.update_raw_el(|raw_el| raw_el.insert(Name::new(name)));
         }
         self
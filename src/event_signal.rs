@@ -0,0 +1,64 @@
+//! Forwarding of Bevy [`Event`]s into the signal world, see [`event_signal`] and [`event_stream`].
+
+use std::sync::Mutex;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use futures_signals::signal::{self, Signal};
+use once_cell::sync::Lazy;
+
+/// Returns whether the forwarder should be kept.
+type EventStreamForwarder = Box<dyn FnMut(&World) -> bool + Send>;
+
+static EVENT_STREAM_FORWARDERS: Lazy<Mutex<Vec<EventStreamForwarder>>> = Lazy::new(Mutex::default);
+
+/// Outputs the latest [`Event`] `E` sent since this signal was requested, or [`None`] if none has
+/// been sent yet; events sent before are never output. If multiple `E`s are sent in a single frame,
+/// only the last is output; use [`event_stream`] to handle every one.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct GameOver;
+///
+/// El::<Node>::new().show_signal(event_signal::<GameOver>().map(|game_over| game_over.is_some()));
+/// ```
+pub fn event_signal<E: Event + Clone>() -> impl Signal<Item = Option<E>> + Send + 'static {
+    // each subscriber gets its own stream, so events are never replayed to later subscribers
+    signal::from_stream(event_stream::<E>())
+}
+
+/// Stream of every [`Event`] `E` sent since the stream was requested. Stops forwarding once the
+/// returned [`Receiver`](async_channel::Receiver) is dropped.
+pub fn event_stream<E: Event + Clone>() -> async_channel::Receiver<E> {
+    let (sender, receiver) = async_channel::unbounded();
+    let mut cursor_option = None;
+    EVENT_STREAM_FORWARDERS.lock().unwrap().push(Box::new(move |world| {
+        if sender.is_closed() {
+            return false;
+        }
+        if let Some(events) = world.get_resource::<Events<E>>() {
+            // skip events sent before the stream was requested
+            let cursor = cursor_option.get_or_insert_with(|| events.get_cursor_current());
+            for event in cursor.read(events) {
+                let _ = sender.try_send(event.clone());
+            }
+        }
+        true
+    }));
+    receiver
+}
+
+fn forward_events(world: &mut World) {
+    EVENT_STREAM_FORWARDERS
+        .lock()
+        .unwrap()
+        .retain_mut(|forward| forward(world));
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Last, forward_events);
+}
@@ -0,0 +1,181 @@
+//! Declarative gamepad rumble and audio cues for interactive elements, see [`Feedbackable`] and
+//! [`FeedbackProvider`].
+
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy_log::prelude::*;
+use bevy_picking::prelude::*;
+
+use super::pointer_event_aware::PointerEventAware;
+
+/// Interaction which plays an element's [`Feedback`].
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum FeedbackTrigger {
+    /// When the element starts being hovered.
+    Hover,
+    /// When the element starts being pressed.
+    #[default]
+    Press,
+    /// When the element is clicked.
+    Click,
+}
+
+/// Gamepad rumble played as part of a [`Feedback`].
+#[derive(Clone, Copy, Debug)]
+pub struct Rumble {
+    #[allow(missing_docs)]
+    pub intensity: GamepadRumbleIntensity,
+    #[allow(missing_docs)]
+    pub duration: Duration,
+}
+
+/// Rumble and/or audio cue played by the [`FeedbackProvider`] when an element is interacted with,
+/// see [`Feedbackable::feedback`].
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use bevy::{input::gamepad::GamepadRumbleIntensity, prelude::*};
+/// use haalka::prelude::*;
+///
+/// El::<Node>::new().feedback(
+///     Feedback::rumble(GamepadRumbleIntensity::WEAK_MAX, Duration::from_millis(80))
+///         .sound("sounds/click.ogg")
+///         .on(FeedbackTrigger::Click),
+/// );
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct Feedback {
+    /// Interaction which plays this [`Feedback`].
+    pub trigger: FeedbackTrigger,
+    #[allow(missing_docs)]
+    pub rumble: Option<Rumble>,
+    /// Identifies the audio cue to play, e.g. an asset path; interpreted by the
+    /// [`FeedbackProvider`], so requires a custom one, see [`RumbleFeedbackProvider`].
+    pub sound: Option<String>,
+}
+
+impl Feedback {
+    /// [`Feedback`] which rumbles all connected gamepads with `intensity` for `duration`.
+    pub fn rumble(intensity: GamepadRumbleIntensity, duration: Duration) -> Self {
+        Self {
+            rumble: Some(Rumble { intensity, duration }),
+            ..Self::default()
+        }
+    }
+
+    /// Also play the audio cue `sound`, e.g. an asset path.
+    pub fn sound(mut self, sound: impl Into<String>) -> Self {
+        self.sound = Some(sound.into());
+        self
+    }
+
+    /// Play this [`Feedback`] on `trigger` rather than when pressed.
+    pub fn on(mut self, trigger: FeedbackTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+}
+
+/// Plays [`Feedback`]s, e.g. by spawning audio players for their sounds; install one with
+/// [`ActiveFeedbackProvider`].
+pub trait FeedbackProvider: Send + Sync + 'static {
+    /// Play `feedback` for the interacted with `entity`.
+    fn play(&mut self, world: &mut World, entity: Entity, feedback: &Feedback);
+}
+
+/// [`FeedbackProvider`] which rumbles all connected gamepads; the default. It does not play
+/// [`Feedback::sound`]s, logging a warning the first time it is given one; install a custom
+/// [`FeedbackProvider`] which, e.g., spawns an `AudioPlayer` for them instead.
+#[derive(Default)]
+pub struct RumbleFeedbackProvider {
+    warned_sound: bool,
+}
+
+impl FeedbackProvider for RumbleFeedbackProvider {
+    fn play(&mut self, world: &mut World, _: Entity, feedback: &Feedback) {
+        if feedback.sound.is_some() && !self.warned_sound {
+            self.warned_sound = true;
+            warn!("`RumbleFeedbackProvider` ignores `Feedback::sound`s, install a custom `FeedbackProvider` for them");
+        }
+        let Some(Rumble { intensity, duration }) = feedback.rumble else {
+            return;
+        };
+        let gamepads = world
+            .query_filtered::<Entity, With<Gamepad>>()
+            .iter(world)
+            .collect::<Vec<_>>();
+        if let Some(mut requests) = world.get_resource_mut::<Events<GamepadRumbleRequest>>() {
+            for gamepad in gamepads {
+                requests.send(GamepadRumbleRequest::Add {
+                    duration,
+                    intensity,
+                    gamepad,
+                });
+            }
+        }
+    }
+}
+
+/// The [`FeedbackProvider`] which plays all [`Feedback`]s, [`RumbleFeedbackProvider`] by default.
+#[derive(Resource)]
+pub struct ActiveFeedbackProvider(pub Box<dyn FeedbackProvider>);
+
+impl ActiveFeedbackProvider {
+    #[allow(missing_docs)]
+    pub fn new(provider: impl FeedbackProvider) -> Self {
+        Self(Box::new(provider))
+    }
+}
+
+impl Default for ActiveFeedbackProvider {
+    fn default() -> Self {
+        Self::new(RumbleFeedbackProvider::default())
+    }
+}
+
+fn play(entity: Entity, feedback: Feedback, commands: &mut Commands) {
+    commands.queue(move |world: &mut World| {
+        world.resource_scope(|world, mut provider: Mut<ActiveFeedbackProvider>| {
+            provider.0.play(world, entity, &feedback)
+        });
+    });
+}
+
+/// Enables attaching [`Feedback`] to interactive elements declaratively.
+pub trait Feedbackable: PointerEventAware {
+    /// Play `feedback` with the [`ActiveFeedbackProvider`] whenever its [`FeedbackTrigger`]
+    /// occurs on this element. This method can be called repeatedly to attach many such
+    /// [`Feedback`]s.
+    fn feedback(self, feedback: Feedback) -> Self {
+        match feedback.trigger {
+            FeedbackTrigger::Hover => self.on_hovered_change_with_system(
+                move |In((entity, hovered)): In<(Entity, bool)>, mut commands: Commands| {
+                    if hovered {
+                        play(entity, feedback.clone(), &mut commands);
+                    }
+                },
+            ),
+            FeedbackTrigger::Press => self.on_pressed_change_with_system(
+                move |In((entity, pressed)): In<(Entity, bool)>, mut commands: Commands| {
+                    if pressed {
+                        play(entity, feedback.clone(), &mut commands);
+                    }
+                },
+            ),
+            FeedbackTrigger::Click => self.on_click_with_system(
+                move |In((entity, _)): In<(Entity, Pointer<Click>)>, mut commands: Commands| {
+                    play(entity, feedback.clone(), &mut commands);
+                },
+            ),
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ActiveFeedbackProvider>();
+}
@@ -0,0 +1,41 @@
+//! HTTP requests with reactive status, see [`fetch`].
+
+use super::future_signal::{future_signal, FutureSignal};
+
+pub use ehttp::{Request, Response};
+
+/// Send a GET request to `url`, outputting its progress as a
+/// [`FutureState`](super::future_signal::FutureState); responses with an unsuccessful status are
+/// [`Failed`](super::future_signal::FutureState::Failed). Uses the browser's fetch API on the web.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// El::<Node>::new().child_signal(fetch("https://example.com/leaderboard.json").map(|state| match state {
+///     FutureState::Pending => El::<Text>::new().text(Text::new("loading ...")),
+///     FutureState::Ready(response) => El::<Text>::new().text(Text(response.text().unwrap_or_default().to_string())),
+///     FutureState::Failed(error) => El::<Text>::new().text(Text(error)),
+/// }));
+/// ```
+pub fn fetch(url: impl ToString) -> FutureSignal<Response, String> {
+    fetch_request(Request::get(url))
+}
+
+/// Send `request`, outputting its progress as a
+/// [`FutureState`](super::future_signal::FutureState), see [`fetch`].
+pub fn fetch_request(request: Request) -> FutureSignal<Response, String> {
+    let (sender, receiver) = async_channel::bounded(1);
+    ehttp::fetch(request, move |result| {
+        let _ = sender.try_send(result);
+    });
+    future_signal(async move {
+        let response = receiver.recv().await.map_err(|error| error.to_string())??;
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(format!("{} {}", response.status, response.status_text))
+        }
+    })
+}
@@ -0,0 +1,109 @@
+//! Semantics for managing how elements distribute the remaining space of their parent, see
+//! [`Flexible`].
+
+use super::raw::{DeferredUpdaterAppendDirection, RawElWrapper};
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+/// Enables an element to have a static or reactive share of the remaining space along its parent's
+/// main axis, i.e. its flex grow, flex shrink, and flex basis, with consideration for any potential
+/// [haalka](crate) managed wrapper nodes.
+pub trait Flexible: RawElWrapper {
+    /// Set the share of its parent's remaining space this element grows into.
+    fn grow(mut self, grow_option: impl Into<Option<f32>>) -> Self {
+        if let Some(grow) = grow_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.flex_grow = grow)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the share of its parent's remaining space this element grows into. If the
+    /// signal outputs [`None`] the grow is set to `0.`.
+    fn grow_signal<S: Signal<Item = impl Into<Option<f32>>> + Send + 'static>(
+        mut self,
+        grow_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(grow_option_signal) = grow_option_signal_option.into() {
+            let grow_option_signal = grow_option_signal.map(|grow_option| grow_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<f32>, Node>(
+                        grow_option_signal,
+                        move |mut node, grow_option| node.flex_grow = grow_option.unwrap_or(0.),
+                    )
+                })
+            });
+        }
+        self
+    }
+
+    /// Set how much this element shrinks, relative to its siblings, when its parent overflows.
+    fn shrink(mut self, shrink_option: impl Into<Option<f32>>) -> Self {
+        if let Some(shrink) = shrink_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.flex_shrink = shrink)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set how much this element shrinks, relative to its siblings, when its parent
+    /// overflows. If the signal outputs [`None`] the shrink is set to `1.`.
+    fn shrink_signal<S: Signal<Item = impl Into<Option<f32>>> + Send + 'static>(
+        mut self,
+        shrink_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(shrink_option_signal) = shrink_option_signal_option.into() {
+            let shrink_option_signal = shrink_option_signal.map(|shrink_option| shrink_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<f32>, Node>(
+                        shrink_option_signal,
+                        move |mut node, shrink_option| node.flex_shrink = shrink_option.unwrap_or(1.),
+                    )
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the initial length of this element along its parent's main axis, before any remaining
+    /// space is distributed.
+    fn basis(mut self, basis_option: impl Into<Option<Val>>) -> Self {
+        if let Some(basis) = basis_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.flex_basis = basis)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the initial length of this element along its parent's main axis, before any
+    /// remaining space is distributed. If the signal outputs [`None`] the basis is set to
+    /// [`Val::Auto`].
+    fn basis_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        basis_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(basis_option_signal) = basis_option_signal_option.into() {
+            let basis_option_signal = basis_option_signal.map(|basis_option| basis_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Val>, Node>(
+                        basis_option_signal,
+                        move |mut node, basis_option| node.flex_basis = basis_option.unwrap_or(Val::Auto),
+                    )
+                })
+            });
+        }
+        self
+    }
+}
@@ -0,0 +1,500 @@
+//! Keyboard focus management with tab and directional navigation, and focus ring rendering, see
+//! [`KeyboardFocusable`], [`FocusedElement`], and [`FocusRing`].
+
+use std::time::Duration;
+
+use apply::Apply;
+use bevy_app::prelude::*;
+use bevy_color::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_input::{gamepad::Gamepad, prelude::*};
+use bevy_math::Vec2;
+use bevy_picking::{backend::HitData, pointer::Location, prelude::*};
+use bevy_render::{camera::NormalizedRenderTarget, view::InheritedVisibility};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::prelude::*;
+use bevy_window::{PrimaryWindow, WindowRef};
+use futures_signals::signal::{Mutable, ReadOnlyMutable, Signal};
+
+use super::{
+    anchorable::logical_rect,
+    input_block::InputBlocked,
+    raw::{
+        observe, register_system, utils::remove_system_holder_on_remove, DeferredUpdaterAppendDirection, RawElWrapper,
+    },
+    utils::clone,
+};
+
+/// Marks an element as able to receive keyboard focus, see [`KeyboardFocusable`].
+#[derive(Component, Clone, Copy, Default, Debug)]
+pub struct Focusable {
+    /// Position in the tab order; elements with positive indices come first, in ascending order,
+    /// followed by those with index `0`, in layout order. Elements with negative indices can only be
+    /// focused by clicking or programmatically.
+    pub tab_index: i32,
+}
+
+/// Marker [`Component`] which prevents a [`Focusable`] element from being focused when pressed;
+/// it can still be focused with keyboard navigation or programmatically.
+#[derive(Component, Default)]
+pub struct FocusOnPressDisabled;
+
+/// Marker [`Component`] which stops the arrow keys and D-pad from moving focus away from a focused
+/// element, and Enter and a gamepad's south button from clicking it; for elements which handle
+/// those inputs themselves, e.g. [`TextInput`](super::text_input::TextInput)s. Tab still moves
+/// focus away.
+#[derive(Component, Default)]
+pub struct FocusNavigationDisabled;
+
+/// The element which currently has keyboard focus, if any; can be set directly to move focus.
+#[derive(Resource, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FocusedElement(pub Option<Entity>);
+
+/// [`SystemSet`] of the [`Update`] systems which move focus and react to it; order systems which
+/// read or write [`FocusedElement`] relative to it.
+#[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct FocusSystem;
+
+/// Whether focus was last moved with the keyboard or a gamepad, rather than a pointer or
+/// programmatically, i.e. whether the [`FocusRing`] should be shown, like CSS's `:focus-visible`.
+#[derive(Resource, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct FocusVisible(pub bool);
+
+/// Appearance of the [`Outline`] drawn around the focused element while [`FocusVisible`]; set
+/// [`FocusRing::enabled`] to `false` to style focus manually.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct FocusRing {
+    #[allow(missing_docs)]
+    pub enabled: bool,
+    #[allow(missing_docs)]
+    pub color: Color,
+    #[allow(missing_docs)]
+    pub width: Val,
+    /// Space between the focused element's border and the outline.
+    pub offset: Val,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::srgb(0.25, 0.55, 1.),
+            width: Val::Px(2.),
+            offset: Val::Px(2.),
+        }
+    }
+}
+
+// the focused element's own [`Outline`], restored when the focus ring is removed
+#[derive(Component)]
+struct FocusRingOutline(Option<Outline>);
+
+/// How much more distance perpendicular to the direction of navigation counts than distance along
+/// it when picking the next element to focus with the arrow keys or D-pad.
+pub const DIRECTIONAL_NAVIGATION_PERPENDICULAR_WEIGHT: f32 = 2.;
+
+#[derive(Event)]
+struct FocusedChange(bool);
+
+/// Enables receiving keyboard focus, either by clicking, with Tab/Shift-Tab traversal, or with the
+/// arrow keys and gamepad D-pad, which move focus spatially; Enter or a gamepad's south button
+/// clicks the focused element. See [`FocusedElement`].
+pub trait KeyboardFocusable: RawElWrapper {
+    /// Make this element focusable, in layout order.
+    fn focusable(self) -> Self {
+        self.tab_index(0)
+    }
+
+    /// Make this element focusable, with the position in the tab order described by
+    /// [`Focusable::tab_index`].
+    fn tab_index(self, tab_index: i32) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                raw_el.insert(Focusable { tab_index })
+            })
+        })
+    }
+
+    /// Focus this element when it is spawned.
+    fn focus(self) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.on_spawn(|world, entity| {
+                world.get_resource_or_insert_with(FocusedElement::default).0 = Some(entity);
+            })
+        })
+    }
+
+    /// Reactively focus or unfocus this element.
+    fn focus_signal(self, focus_signal: impl Signal<Item = bool> + Send + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.on_signal_one_shot(
+                focus_signal,
+                |In((entity, focus)): In<(Entity, bool)>, mut focused: ResMut<FocusedElement>| {
+                    if focus {
+                        focused.0 = Some(entity);
+                    } else if focused.0 == Some(entity) {
+                        focused.0 = None;
+                    }
+                },
+            )
+        })
+    }
+
+    /// When this element's focused state changes, run a [`System`] which takes
+    /// [`In`](`System::In`) this element's [`Entity`] and its current focused state. This method
+    /// can be called repeatedly to register many such handlers.
+    fn on_focused_change_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, bool)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                let system_holder = Mutable::new(None);
+                raw_el
+                    .on_spawn(clone!((system_holder) move |world, entity| {
+                        let system = register_system(world, handler);
+                        system_holder.set(Some(system));
+                        observe(world, entity, move |focused_change: Trigger<FocusedChange>, mut commands: Commands| {
+                            commands.run_system_with_input(system, (entity, focused_change.event().0));
+                        });
+                    }))
+                    .apply(remove_system_holder_on_remove(system_holder))
+            })
+        })
+    }
+
+    /// When this element's focused state changes, run a function with its current focused state.
+    /// This method can be called repeatedly to register many such handlers.
+    fn on_focused_change(self, mut handler: impl FnMut(bool) + Send + Sync + 'static) -> Self {
+        self.on_focused_change_with_system(move |In((_, is_focused))| handler(is_focused))
+    }
+
+    /// Run a function when this element gains focus. This method can be called repeatedly to
+    /// register many such handlers.
+    fn on_focus(self, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_focused_change(move |is_focused| {
+            if is_focused {
+                handler()
+            }
+        })
+    }
+
+    /// Run a function when this element loses focus. This method can be called repeatedly to
+    /// register many such handlers.
+    fn on_blur(self, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_focused_change(move |is_focused| {
+            if !is_focused {
+                handler()
+            }
+        })
+    }
+
+    /// Sync a [`Mutable<bool>`] with this element's focused state.
+    fn focused_sync(self, focused: Mutable<bool>) -> Self {
+        self.on_focused_change(move |is_focused| focused.set_neq(is_focused))
+    }
+
+    /// Build on this element with a read only view of its focused state, avoiding the need for a
+    /// separate [`Mutable`] and [`.focused_sync`](KeyboardFocusable::focused_sync); use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive focused signals.
+    fn focused_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<bool>) -> Self) -> Self {
+        let focused = Mutable::new(false);
+        f(self.focused_sync(focused.clone()), focused.read_only())
+    }
+}
+
+/// Focus the closest focusable ancestor of pressed elements, or unfocus if there is none; pressing
+/// an element with [`FocusOnPressDisabled`] leaves focus unchanged.
+fn focus_on_press(
+    mut downs: EventReader<Pointer<Down>>,
+    focusables: Query<Has<FocusOnPressDisabled>, With<Focusable>>,
+    parents: Query<&Parent>,
+    mut focused: ResMut<FocusedElement>,
+    mut focus_visible: ResMut<FocusVisible>,
+) {
+    for down in downs.read() {
+        if !matches!(down.button, PointerButton::Primary) {
+            continue;
+        }
+        focus_visible.set_if_neq(FocusVisible(false));
+        let focusable_option = std::iter::once(down.target)
+            .chain(parents.iter_ancestors(down.target))
+            .find_map(|entity| focusables.get(entity).ok().map(|disabled| (entity, disabled)));
+        match focusable_option {
+            Some((_, true)) => (),
+            Some((entity, false)) => {
+                focused.set_if_neq(FocusedElement(Some(entity)));
+            }
+            None => {
+                focused.set_if_neq(FocusedElement(None));
+            }
+        }
+    }
+}
+
+/// Focusable elements in tab order, skipping those which are hidden or beneath the
+/// [`InputBlock`](super::input_block::InputBlock).
+fn tab_order(
+    roots: &Query<Entity, (With<Node>, Without<Parent>)>,
+    children: &Query<&Children>,
+    nodes: &Query<(&Node, Option<&InheritedVisibility>, Option<&Focusable>)>,
+    input_blocked: &InputBlocked,
+) -> Vec<Entity> {
+    fn visit(
+        entity: Entity,
+        children: &Query<&Children>,
+        nodes: &Query<(&Node, Option<&InheritedVisibility>, Option<&Focusable>)>,
+        order: &mut Vec<(i32, Entity)>,
+    ) {
+        let Ok((node, visibility_option, focusable_option)) = nodes.get(entity) else {
+            return;
+        };
+        if node.display == Display::None || visibility_option.is_some_and(|visibility| !visibility.get()) {
+            return;
+        }
+        if let Some(&Focusable { tab_index }) = focusable_option {
+            if tab_index >= 0 {
+                order.push((tab_index, entity));
+            }
+        }
+        for &child in children.get(entity).into_iter().flatten() {
+            visit(child, children, nodes, order);
+        }
+    }
+    let mut order = vec![];
+    for root in roots.iter() {
+        visit(root, children, nodes, &mut order);
+    }
+    // stable, so ties stay in layout order
+    order.sort_by_key(|&(tab_index, _)| if tab_index > 0 { (0, tab_index) } else { (1, 0) });
+    order
+        .into_iter()
+        .map(|(_, entity)| entity)
+        .filter(|&entity| !input_blocked.blocked(entity))
+        .collect()
+}
+
+fn tab_navigation(
+    keys: Res<ButtonInput<KeyCode>>,
+    roots: Query<Entity, (With<Node>, Without<Parent>)>,
+    children: Query<&Children>,
+    nodes: Query<(&Node, Option<&InheritedVisibility>, Option<&Focusable>)>,
+    input_blocked: InputBlocked,
+    mut focused: ResMut<FocusedElement>,
+    mut focus_visible: ResMut<FocusVisible>,
+) {
+    let order = tab_order(&roots, &children, &nodes, &input_blocked);
+    if order.is_empty() {
+        return;
+    }
+    focus_visible.set_if_neq(FocusVisible(true));
+    let backward = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let current_option = focused
+        .0
+        .and_then(|focused| order.iter().position(|&entity| entity == focused));
+    let next = match (current_option, backward) {
+        (Some(current), false) => (current + 1) % order.len(),
+        (Some(current), true) => (current + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+    focused.0 = Some(order[next]);
+}
+
+/// Direction of the arrow key or D-pad button just pressed on the keyboard or any gamepad, with
+/// `y` pointing down like UI coordinates.
+fn navigation_direction(keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> Option<Vec2> {
+    [
+        (KeyCode::ArrowUp, GamepadButton::DPadUp, Vec2::NEG_Y),
+        (KeyCode::ArrowDown, GamepadButton::DPadDown, Vec2::Y),
+        (KeyCode::ArrowLeft, GamepadButton::DPadLeft, Vec2::NEG_X),
+        (KeyCode::ArrowRight, GamepadButton::DPadRight, Vec2::X),
+    ]
+    .into_iter()
+    .find(|&(key, button, _)| keys.just_pressed(key) || gamepads.iter().any(|gamepad| gamepad.just_pressed(button)))
+    .map(|(_, _, direction)| direction)
+}
+
+/// Move focus from the focused element to the closest focusable element in the pressed direction,
+/// weighting distance perpendicular to that direction more heavily so focus stays in the same row
+/// or column when it can; does nothing if no element is focused or the focused element has
+/// [`FocusNavigationDisabled`].
+fn directional_navigation(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    roots: Query<Entity, (With<Node>, Without<Parent>)>,
+    children: Query<&Children>,
+    nodes: Query<(&Node, Option<&InheritedVisibility>, Option<&Focusable>)>,
+    rects: Query<(&ComputedNode, &GlobalTransform)>,
+    navigation_disabled: Query<(), With<FocusNavigationDisabled>>,
+    input_blocked: InputBlocked,
+    mut focused: ResMut<FocusedElement>,
+    mut focus_visible: ResMut<FocusVisible>,
+) {
+    if focused.0.is_none_or(|entity| navigation_disabled.contains(entity)) {
+        return;
+    }
+    let Some(direction) = navigation_direction(&keys, &gamepads) else {
+        return;
+    };
+    let order = tab_order(&roots, &children, &nodes, &input_blocked);
+    let position = |entity: Entity| {
+        transforms
+            .get(entity)
+            .ok()
+            .map(|transform| transform.translation().truncate())
+    };
+    let Some((current, current_position)) = focused
+        .0
+        .filter(|focused| order.contains(focused))
+        .and_then(|focused| position(focused).map(|position| (focused, position)))
+    else {
+        return;
+    };
+    let next_option = order
+        .into_iter()
+        .filter(|&entity| entity != current)
+        .filter_map(|entity| {
+            let delta = position(entity)? - current_position;
+            let along = delta.dot(direction);
+            (along > 0.).then(|| {
+                (
+                    entity,
+                    along + DIRECTIONAL_NAVIGATION_PERPENDICULAR_WEIGHT * delta.perp_dot(direction).abs(),
+                )
+            })
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    if let Some((next, _)) = next_option {
+        focused.0 = Some(next);
+        focus_visible.set_if_neq(FocusVisible(true));
+    }
+}
+
+/// Send a synthetic primary button [`Pointer<Click>`] to the focused element when Enter or a
+/// gamepad's south button (A on Xbox controllers) is pressed, so its
+/// [`.on_click`](super::pointer_event_aware::PointerEventAware::on_click) handlers run, unless it
+/// has [`FocusNavigationDisabled`].
+fn activate_focused(
+    focused: Res<FocusedElement>,
+    transforms: Query<&GlobalTransform>,
+    navigation_disabled: Query<(), With<FocusNavigationDisabled>>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+    mut commands: Commands,
+) {
+    let Some(entity) = focused.0.filter(|&entity| !navigation_disabled.contains(entity)) else {
+        return;
+    };
+    let Some(target) = WindowRef::Primary
+        .normalize(windows.get_single().ok())
+        .map(NormalizedRenderTarget::Window)
+    else {
+        return;
+    };
+    // pointer locations are in logical pixels
+    let position = rects
+        .get(entity)
+        .map(|(computed_node, global_transform)| logical_rect(computed_node, global_transform).center())
+        .unwrap_or_default();
+    let click = Click {
+        button: PointerButton::Primary,
+        hit: HitData::new(Entity::PLACEHOLDER, 0., None, None),
+        duration: Duration::ZERO,
+    };
+    commands.trigger_targets(
+        Pointer::new(entity, PointerId::Mouse, Location { target, position }, click),
+        entity,
+    );
+}
+
+fn unfocus_removed_or_blocked(
+    focusables: Query<(), With<Focusable>>,
+    input_blocked: InputBlocked,
+    mut focused: ResMut<FocusedElement>,
+) {
+    if let Some(entity) = focused.0 {
+        if !focusables.contains(entity) || input_blocked.blocked(entity) {
+            focused.0 = None;
+        }
+    }
+}
+
+fn dispatch_focused_change(
+    focused: Res<FocusedElement>,
+    mut previous_option: Local<Option<Entity>>,
+    mut commands: Commands,
+) {
+    if *previous_option != focused.0 {
+        if let Some(previous) = previous_option.take() {
+            commands.trigger_targets(FocusedChange(false), previous);
+        }
+        if let Some(entity) = focused.0 {
+            commands.trigger_targets(FocusedChange(true), entity);
+        }
+        *previous_option = focused.0;
+    }
+}
+
+fn sync_focus_ring(
+    focused: Res<FocusedElement>,
+    focus_visible: Res<FocusVisible>,
+    focus_ring: Res<FocusRing>,
+    ringed: Query<(Entity, &FocusRingOutline)>,
+    outlines: Query<&Outline>,
+    mut commands: Commands,
+) {
+    let target_option = focused.0.filter(|_| focus_visible.0 && focus_ring.enabled);
+    for (entity, FocusRingOutline(outline_option)) in ringed.iter() {
+        if Some(entity) == target_option {
+            continue;
+        }
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<FocusRingOutline>();
+            match outline_option {
+                Some(outline) => entity.try_insert(*outline),
+                None => entity.remove::<Outline>(),
+            };
+        }
+    }
+    if let Some(target) = target_option {
+        if let Some(mut entity) = commands.get_entity(target) {
+            if !ringed.contains(target) {
+                entity.try_insert(FocusRingOutline(outlines.get(target).ok().copied()));
+            }
+            let FocusRing {
+                color, width, offset, ..
+            } = *focus_ring;
+            entity.try_insert(Outline { width, offset, color });
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FocusedElement>()
+        .init_resource::<FocusVisible>()
+        .init_resource::<FocusRing>()
+        .add_systems(
+            Update,
+            (
+                focus_on_press.run_if(on_event::<Pointer<Down>>),
+                tab_navigation.run_if(|keys: Res<ButtonInput<KeyCode>>| keys.just_pressed(KeyCode::Tab)),
+                directional_navigation,
+                unfocus_removed_or_blocked.run_if(|focused: Res<FocusedElement>| focused.0.is_some()),
+                dispatch_focused_change.run_if(resource_changed::<FocusedElement>),
+                sync_focus_ring.run_if(
+                    resource_changed::<FocusedElement>
+                        .or(resource_changed::<FocusVisible>)
+                        .or(resource_changed::<FocusRing>),
+                ),
+                activate_focused.run_if(|keys: Res<ButtonInput<KeyCode>>, gamepads: Query<&Gamepad>| {
+                    keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+                        || gamepads
+                            .iter()
+                            .any(|gamepad| gamepad.just_pressed(GamepadButton::South))
+                }),
+            )
+                .chain()
+                .in_set(FocusSystem),
+        );
+}
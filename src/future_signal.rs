@@ -0,0 +1,81 @@
+//! Bridges from [`Future`]s into the signal world, see [`future_signal`].
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bevy_tasks::{AsyncComputeTaskPool, Task};
+use futures_signals::signal::{Mutable, MutableSignalCloned, Signal};
+
+use super::utils::clone;
+
+/// Progress of a [`Future`] passed to [`future_signal`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FutureState<T, E> {
+    /// The [`Future`] has not completed yet.
+    Pending,
+    /// The [`Future`] completed successfully.
+    Ready(T),
+    /// The [`Future`] completed with an error.
+    Failed(E),
+}
+
+impl<T, E> FutureState<T, E> {
+    /// Whether the [`Future`] has not completed yet.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+}
+
+/// [`Signal`] returned by [`future_signal`]; the [`Future`] is cancelled once this is dropped.
+pub struct FutureSignal<T, E> {
+    signal: MutableSignalCloned<FutureState<T, E>>,
+    _task: Task<()>,
+}
+
+impl<T: Clone, E: Clone> Signal for FutureSignal<T, E> {
+    type Item = FutureState<T, E>;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().signal).poll_change(cx)
+    }
+}
+
+/// Run `future` on the [`AsyncComputeTaskPool`], outputting its progress as a [`FutureState`]; when
+/// passed to an element, the future is cancelled if the element is despawned before it completes.
+/// Useful for loading data per element.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// async fn load_profile() -> Result<String, String> {
+///     Ok("avi".to_string())
+/// }
+///
+/// El::<Text>::new().text_signal(future_signal(load_profile()).map(|state| {
+///     Text(match state {
+///         FutureState::Pending => "loading ...".to_string(),
+///         FutureState::Ready(name) => name,
+///         FutureState::Failed(error) => error,
+///     })
+/// }));
+/// ```
+pub fn future_signal<T: Clone + Send + Sync + 'static, E: Clone + Send + Sync + 'static>(
+    future: impl Future<Output = Result<T, E>> + Send + 'static,
+) -> FutureSignal<T, E> {
+    let state = Mutable::new(FutureState::Pending);
+    let task = AsyncComputeTaskPool::get().spawn(clone!((state) async move {
+        state.set(match future.await {
+            Ok(value) => FutureState::Ready(value),
+            Err(error) => FutureState::Failed(error),
+        });
+    }));
+    FutureSignal {
+        signal: state.signal_cloned(),
+        _task: task,
+    }
+}
@@ -0,0 +1,68 @@
+//! Reactive access to the state of connected gamepads, see [`gamepad_axis_signal`] and
+//! [`gamepad_button_signal`].
+
+use std::{collections::HashMap, sync::Mutex};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{gamepad::Gamepad, prelude::*, InputSystem};
+use futures_signals::signal::{Mutable, Signal};
+use once_cell::sync::Lazy;
+
+static AXES: Lazy<Mutex<HashMap<GamepadAxis, Mutable<f32>>>> = Lazy::new(Mutex::default);
+static BUTTONS_PRESSED: Lazy<Mutex<HashMap<GamepadButton, Mutable<bool>>>> = Lazy::new(Mutex::default);
+
+/// Outputs the value of `axis`, in `[-1, 1]`, of whichever connected gamepad has it furthest from
+/// rest; outputs `0.` if there are no connected gamepads. Useful for virtual cursors, radial menus,
+/// and settings sliders.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// El::<Text>::new().text_signal(
+///     gamepad_axis_signal(GamepadAxis::LeftStickX).map(|x| Text(format!("{x:.2}"))),
+/// );
+/// ```
+pub fn gamepad_axis_signal(axis: GamepadAxis) -> impl Signal<Item = f32> + Send + 'static {
+    AXES.lock()
+        .unwrap()
+        .entry(axis)
+        .or_insert_with(Mutable::default)
+        .signal()
+}
+
+/// Outputs whether `button` is held on any connected gamepad.
+pub fn gamepad_button_signal(button: GamepadButton) -> impl Signal<Item = bool> + Send + 'static {
+    BUTTONS_PRESSED
+        .lock()
+        .unwrap()
+        .entry(button)
+        .or_insert_with(Mutable::default)
+        .signal()
+}
+
+fn sync_gamepads(gamepads: Query<&Gamepad>) {
+    for (&axis, value) in AXES.lock().unwrap().iter() {
+        value.set_neq(
+            gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad.get(axis))
+                .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+                .unwrap_or_default(),
+        );
+    }
+    for (&button, pressed) in BUTTONS_PRESSED.lock().unwrap().iter() {
+        pressed.set_neq(gamepads.iter().any(|gamepad| gamepad.pressed(button)));
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        sync_gamepads
+            .after(InputSystem)
+            .run_if(|| !AXES.lock().unwrap().is_empty() || !BUTTONS_PRESSED.lock().unwrap().is_empty()),
+    );
+}
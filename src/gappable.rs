@@ -0,0 +1,88 @@
+//! Semantics for managing the static or reactive spacing between an element's children, see
+//! [`Gappable`].
+
+use super::raw::RawElWrapper;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+/// Enables an element to have static or reactive spacing between its children, i.e. its
+/// [`Node::row_gap`] and [`Node::column_gap`].
+pub trait Gappable: RawElWrapper {
+    /// Set both the vertical and horizontal spacing between this element's children.
+    fn gap(self, gap_option: impl Into<Option<Val>>) -> Self {
+        let gap_option = gap_option.into();
+        self.row_gap(gap_option).column_gap(gap_option)
+    }
+
+    /// Reactively set both the vertical and horizontal spacing between this element's children. If
+    /// the signal outputs [`None`] the gaps are set to [`Val::ZERO`].
+    fn gap_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        gap_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(gap_option_signal) = gap_option_signal_option.into() {
+            let gap_option_signal = gap_option_signal.map(|gap_option| gap_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_component::<Option<Val>, Node>(gap_option_signal, |mut node, gap_option| {
+                    let gap = gap_option.unwrap_or(Val::ZERO);
+                    node.row_gap = gap;
+                    node.column_gap = gap;
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the vertical spacing between this element's children.
+    fn row_gap(mut self, row_gap_option: impl Into<Option<Val>>) -> Self {
+        if let Some(row_gap) = row_gap_option.into() {
+            self = self.update_raw_el(|raw_el| raw_el.with_component::<Node>(move |mut node| node.row_gap = row_gap));
+        }
+        self
+    }
+
+    /// Reactively set the vertical spacing between this element's children. If the signal outputs
+    /// [`None`] the gap is set to [`Val::ZERO`].
+    fn row_gap_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        row_gap_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(row_gap_option_signal) = row_gap_option_signal_option.into() {
+            let row_gap_option_signal = row_gap_option_signal.map(|row_gap_option| row_gap_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_component::<Option<Val>, Node>(
+                    row_gap_option_signal,
+                    |mut node, row_gap_option| node.row_gap = row_gap_option.unwrap_or(Val::ZERO),
+                )
+            });
+        }
+        self
+    }
+
+    /// Set the horizontal spacing between this element's children.
+    fn column_gap(mut self, column_gap_option: impl Into<Option<Val>>) -> Self {
+        if let Some(column_gap) = column_gap_option.into() {
+            self = self
+                .update_raw_el(|raw_el| raw_el.with_component::<Node>(move |mut node| node.column_gap = column_gap));
+        }
+        self
+    }
+
+    /// Reactively set the horizontal spacing between this element's children. If the signal
+    /// outputs [`None`] the gap is set to [`Val::ZERO`].
+    fn column_gap_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        column_gap_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(column_gap_option_signal) = column_gap_option_signal_option.into() {
+            let column_gap_option_signal = column_gap_option_signal.map(|column_gap_option| column_gap_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_component::<Option<Val>, Node>(
+                    column_gap_option_signal,
+                    |mut node, column_gap_option| node.column_gap = column_gap_option.unwrap_or(Val::ZERO),
+                )
+            });
+        }
+        self
+    }
+}
@@ -0,0 +1,272 @@
+//! Recognition of swipe, pinch, and two finger pan gestures, and tracking of multiple simultaneous
+//! touch points, on top of pointer events, see [`GestureAware`].
+
+use std::collections::HashMap;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_picking::prelude::*;
+use bevy_utils::Instant;
+
+use super::pointer_event_aware::PointerEventAware;
+
+/// Minimum distance, in logical pixels, a pointer must travel between being pressed and released to
+/// count as a [`Swipe`].
+pub const SWIPE_MIN_DISTANCE: f32 = 50.;
+
+/// Minimum average speed, in logical pixels per second, a pointer must travel at between being
+/// pressed and released to count as a [`Swipe`].
+pub const SWIPE_MIN_VELOCITY: f32 = 300.;
+
+/// Dominant direction of a [`Swipe`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Sent to a [`GestureAware`] element when a single pointer is quickly pressed, moved, and released
+/// on it, see [`SWIPE_MIN_DISTANCE`] and [`SWIPE_MIN_VELOCITY`].
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Swipe {
+    /// Dominant direction of the swipe.
+    pub direction: SwipeDirection,
+    /// Average velocity of the swipe, in logical pixels per second.
+    pub velocity: Vec2,
+}
+
+/// Sent to a [`GestureAware`] element when two pointers pressed on it move closer together or
+/// further apart.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Pinch {
+    /// Ratio of the current distance between the pointers to the distance at the last [`Pinch`].
+    pub scale: f32,
+}
+
+/// Sent to a [`GestureAware`] element when two pointers pressed on it move together.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TwoFingerPan {
+    /// Movement of the midpoint between the pointers since the last [`TwoFingerPan`].
+    pub delta: Vec2,
+}
+
+/// A pointer pressed on a [`GestureAware`] element, see [`TouchPointChange`].
+#[derive(Clone, Copy, Debug)]
+pub struct TouchPoint {
+    /// Identifies the pointer, e.g. a finger, across [`TouchPointChange`]s.
+    pub id: PointerId,
+    /// Where the pointer was pressed, in logical pixels.
+    pub start: Vec2,
+    /// Current position of the pointer, in logical pixels.
+    pub position: Vec2,
+}
+
+/// Sent to a [`GestureAware`] element whenever a pointer is pressed on, moved across, or released
+/// from it, with every pointer still pressed on it, in the order they were pressed.
+#[derive(Event, Clone, Debug)]
+pub struct TouchPointChange(pub Vec<TouchPoint>);
+
+struct PressedPointer {
+    start: Vec2,
+    start_time: Instant,
+    position: Vec2,
+}
+
+#[derive(Component, Default)]
+struct GestureState {
+    pointers: HashMap<PointerId, PressedPointer>,
+    // set once more than one pointer is pressed, so lifting one of them does not swipe
+    multi: bool,
+}
+
+/// Enables recognizing swipe, pinch, and two finger pan gestures, so UIs work naturally on
+/// touchscreens; gestures are recognized on this element and any of its descendants.
+pub trait GestureAware: PointerEventAware {
+    /// When a [`Swipe`] is recognized on this element, run a [`System`] which takes
+    /// [`In`](`System::In`) this element's [`Entity`] and the [`Swipe`]. This method can be called
+    /// repeatedly to register many such handlers.
+    fn on_swipe_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, Swipe)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert((GestureState::default(), PickingBehavior::default()))
+                .on_event_with_system::<Swipe, _>(handler)
+        })
+    }
+
+    /// Run a function when this element is swiped in `direction`.
+    fn on_swipe(self, direction: SwipeDirection, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_swipe_with_system(move |In((_, swipe)): In<(_, Swipe)>| {
+            if swipe.direction == direction {
+                handler()
+            }
+        })
+    }
+
+    /// Run a function with every [`TouchPoint`] pressed on this element, in the order they were
+    /// pressed, whenever one is pressed, moved, or released; useful for custom multi touch
+    /// interactions, e.g. rotating or zooming inside a widget.
+    fn on_touch_point_change(self, mut handler: impl FnMut(Vec<TouchPoint>) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert((GestureState::default(), PickingBehavior::default()))
+                .on_event::<TouchPointChange>(move |TouchPointChange(points)| handler(points))
+        })
+    }
+
+    /// Run a function with the [`Pinch::scale`] whenever two pointers pressed on this element move
+    /// closer together or further apart.
+    fn on_pinch(self, mut handler: impl FnMut(f32) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert((GestureState::default(), PickingBehavior::default()))
+                .on_event::<Pinch>(move |pinch| handler(pinch.scale))
+        })
+    }
+
+    /// Run a function with the [`TwoFingerPan::delta`] whenever two pointers pressed on this element
+    /// move together.
+    fn on_two_finger_pan(self, mut handler: impl FnMut(Vec2) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert((GestureState::default(), PickingBehavior::default()))
+                .on_event::<TwoFingerPan>(move |pan| handler(pan.delta))
+        })
+    }
+}
+
+fn trigger_touch_point_change(entity: Entity, state: &GestureState, commands: &mut Commands) {
+    let mut pointers = state.pointers.iter().collect::<Vec<_>>();
+    pointers.sort_by_key(|(_, pointer)| pointer.start_time);
+    let points = pointers
+        .into_iter()
+        .map(|(&id, pointer)| TouchPoint {
+            id,
+            start: pointer.start,
+            position: pointer.position,
+        })
+        .collect();
+    commands.trigger_targets(TouchPointChange(points), entity);
+}
+
+fn gesture_down(down: Trigger<Pointer<Down>>, mut states: Query<&mut GestureState>, mut commands: Commands) {
+    if let Ok(mut state) = states.get_mut(down.entity()) {
+        let position = down.pointer_location.position;
+        state.pointers.insert(
+            down.pointer_id,
+            PressedPointer {
+                start: position,
+                start_time: Instant::now(),
+                position,
+            },
+        );
+        if state.pointers.len() > 1 {
+            state.multi = true;
+        }
+        trigger_touch_point_change(down.entity(), &state, &mut commands);
+    }
+}
+
+fn gesture_drag(drag: Trigger<Pointer<Drag>>, mut states: Query<&mut GestureState>, mut commands: Commands) {
+    let entity = drag.entity();
+    let Ok(mut state) = states.get_mut(entity) else {
+        return;
+    };
+    let mut positions = state.pointers.values().map(|pointer| pointer.position);
+    let before = match (positions.next(), positions.next(), positions.next()) {
+        (Some(a), Some(b), None) => Some((a, b)),
+        _ => None,
+    };
+    let Some(pointer) = state.pointers.get_mut(&drag.pointer_id) else {
+        return;
+    };
+    pointer.position = drag.pointer_location.position;
+    trigger_touch_point_change(entity, &state, &mut commands);
+    let Some((a, b)) = before else { return };
+    let mut positions = state.pointers.values().map(|pointer| pointer.position);
+    let (Some(c), Some(d)) = (positions.next(), positions.next()) else {
+        return;
+    };
+    let (distance_before, distance_after) = (a.distance(b), c.distance(d));
+    if distance_before > 0. && distance_after != distance_before {
+        commands.trigger_targets(
+            Pinch {
+                scale: distance_after / distance_before,
+            },
+            entity,
+        );
+    }
+    let delta = (c + d) / 2. - (a + b) / 2.;
+    if delta != Vec2::ZERO {
+        commands.trigger_targets(TwoFingerPan { delta }, entity);
+    }
+}
+
+fn release(entity: Entity, pointer_id: PointerId, position: Vec2, state: &mut GestureState, commands: &mut Commands) {
+    let Some(pointer) = state.pointers.remove(&pointer_id) else {
+        return;
+    };
+    trigger_touch_point_change(entity, state, commands);
+    let multi = state.multi;
+    if state.pointers.is_empty() {
+        state.multi = false;
+    }
+    if multi {
+        return;
+    }
+    let displacement = position - pointer.start;
+    let seconds = pointer.start_time.elapsed().as_secs_f32().max(f32::EPSILON);
+    let velocity = displacement / seconds;
+    if displacement.length() >= SWIPE_MIN_DISTANCE && velocity.length() >= SWIPE_MIN_VELOCITY {
+        let direction = if displacement.x.abs() > displacement.y.abs() {
+            if displacement.x > 0. {
+                SwipeDirection::Right
+            } else {
+                SwipeDirection::Left
+            }
+        } else if displacement.y > 0. {
+            SwipeDirection::Down
+        } else {
+            SwipeDirection::Up
+        };
+        commands.trigger_targets(Swipe { direction, velocity }, entity);
+    }
+}
+
+fn gesture_up(up: Trigger<Pointer<Up>>, mut states: Query<&mut GestureState>, mut commands: Commands) {
+    if let Ok(mut state) = states.get_mut(up.entity()) {
+        release(
+            up.entity(),
+            up.pointer_id,
+            up.pointer_location.position,
+            &mut state,
+            &mut commands,
+        );
+    }
+}
+
+// pointers released outside the element only receive a drag end
+fn gesture_drag_end(drag_end: Trigger<Pointer<DragEnd>>, mut states: Query<&mut GestureState>, mut commands: Commands) {
+    if let Ok(mut state) = states.get_mut(drag_end.entity()) {
+        release(
+            drag_end.entity(),
+            drag_end.pointer_id,
+            drag_end.pointer_location.position,
+            &mut state,
+            &mut commands,
+        );
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(gesture_down)
+        .add_observer(gesture_drag)
+        .add_observer(gesture_up)
+        .add_observer(gesture_drag_end);
+}
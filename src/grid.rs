@@ -6,19 +6,34 @@ use bevy_ui::prelude::*;
 use bevy_utils::default;
 use futures_signals::{
     signal::{Signal, SignalExt},
+    signal_map::{SignalMap, SignalMapExt},
     signal_vec::{SignalVec, SignalVecExt},
 };
 
 use super::{
     align::{AddRemove, AlignHolder, Alignable, Aligner, Alignment, ChildAlignable},
-    element::{IntoOptionElement, Nameable, UiRootable},
+    anchorable::Anchorable,
+    element::{IntoElement, IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gappable::Gappable,
+    gesture::GestureAware,
     global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    kinetic_scroll::KineticScrollable,
+    layout_aware::LayoutAware,
     mouse_wheel_scrollable::MouseWheelScrollable,
     pointer_event_aware::{CursorOnHoverable, PointerEventAware},
     raw::{RawElWrapper, RawHaalkaEl},
+    resizable::Resizable,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
     sizeable::Sizeable,
+    spaceable::Spaceable,
     stack::Stack,
     viewport_mutable::ViewportMutable,
+    z_layer::ZLayerable,
 };
 
 /// [`Element`](super::element::Element) with children aligned in a grid using a simple [`.row_wrap_cell_width`](Grid::row_wrap_cell_width) grid layout model. Port of [MoonZoon](https://github.com/MoonZoon/MoonZoon)'s [`Grid`](https://github.com/MoonZoon/MoonZoon/blob/main/crates/zoon/src/element/grid.rs).
@@ -74,14 +89,28 @@ impl<NodeType: Bundle> RawElWrapper for Grid<NodeType> {
     }
 }
 
+impl<NodeType: Bundle> Anchorable for Grid<NodeType> {}
 impl<NodeType: Bundle> CursorOnHoverable for Grid<NodeType> {}
+impl<NodeType: Bundle> Feedbackable for Grid<NodeType> {}
+impl<NodeType: Bundle> Flexible for Grid<NodeType> {}
+impl<NodeType: Bundle> Gappable for Grid<NodeType> {}
+impl<NodeType: Bundle> GestureAware for Grid<NodeType> {}
 impl<NodeType: Bundle> GlobalEventAware for Grid<NodeType> {}
+impl<NodeType: Bundle> KeyboardEventAware for Grid<NodeType> {}
+impl<NodeType: Bundle> KeyboardFocusable for Grid<NodeType> {}
+impl<NodeType: Bundle> KineticScrollable for Grid<NodeType> {}
+impl<NodeType: Bundle> LayoutAware for Grid<NodeType> {}
 impl<NodeType: Bundle> Nameable for Grid<NodeType> {}
 impl<NodeType: Bundle> PointerEventAware for Grid<NodeType> {}
 impl<NodeType: Bundle> MouseWheelScrollable for Grid<NodeType> {}
+impl<NodeType: Bundle> Resizable for Grid<NodeType> {}
+impl<NodeType: Bundle> ScrollSnappable for Grid<NodeType> {}
+impl<NodeType: Bundle> Showable for Grid<NodeType> {}
 impl<NodeType: Bundle> Sizeable for Grid<NodeType> {}
+impl<NodeType: Bundle> Spaceable for Grid<NodeType> {}
 impl<NodeType: Bundle> UiRootable for Grid<NodeType> {}
 impl<NodeType: Bundle> ViewportMutable for Grid<NodeType> {}
+impl<NodeType: Bundle> ZLayerable for Grid<NodeType> {}
 
 /// Must substract this from the total row width of a [`Grid`] due to [float precision shenanigans](https://github.com/bevyengine/bevy/issues/12152). See an example usage in the [snake example](https://github.com/databasedav/haalka/blob/e12350c55d7aace07bc27787989c79d5a4e064e5/examples/snake.rs#L112).
 pub const GRID_TRACK_FLOAT_PRECISION_SLACK: f32 = 0.0001;
@@ -208,6 +237,25 @@ impl<NodeType: Bundle> Grid<NodeType> {
         }
         self
     }
+
+    /// Declare reactive grid children keyed by the keys of the [`SignalMap`],
+    /// ordered by key; each key keeps its child until it is removed or its value is updated.
+    pub fn cells_signal_map<
+        K: Ord + Send + 'static,
+        IE: IntoElement + 'static,
+        S: SignalMap<Key = K, Value = IE> + Send + 'static,
+    >(
+        mut self,
+        cells_signal_map_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(cells_signal_map) = cells_signal_map_option.into() {
+            let apply_alignment = self.apply_alignment_wrapper();
+            self.raw_el = self.raw_el.children_signal_map(
+                cells_signal_map.map_value(move |cell| Self::align_child(cell.into_element(), apply_alignment)),
+            );
+        }
+        self
+    }
 }
 
 impl<NodeType: Bundle> Alignable for Grid<NodeType> {
@@ -0,0 +1,255 @@
+//! Undo and redo for [`Mutable`] and [`MutableVec`] state, see [`History`] and [`VecHistory`].
+
+use futures_signals::{
+    signal::{Mutable, Signal},
+    signal_vec::{MutableVec, MutableVecLockMut},
+};
+
+/// Snapshots recorded by a [`History`] or [`VecHistory`].
+#[derive(Clone)]
+struct Snapshots<T> {
+    undos: Mutable<Vec<T>>,
+    redos: Mutable<Vec<T>>,
+    limit: Option<usize>,
+}
+
+impl<T> Snapshots<T> {
+    fn new() -> Self {
+        Self {
+            undos: Mutable::new(vec![]),
+            redos: Mutable::new(vec![]),
+            limit: None,
+        }
+    }
+
+    fn record(&self, snapshot: T) {
+        let mut undos = self.undos.lock_mut();
+        undos.push(snapshot);
+        if let Some(limit) = self.limit {
+            if undos.len() > limit {
+                let excess = undos.len() - limit;
+                undos.drain(..excess);
+            }
+        }
+        drop(undos);
+        let mut redos = self.redos.lock_mut();
+        if !redos.is_empty() {
+            redos.clear();
+        }
+    }
+
+    /// Pop a snapshot from `from`, pushing `current` to `to`.
+    fn step(from: &Mutable<Vec<T>>, to: &Mutable<Vec<T>>, current: impl FnOnce(T) -> T) -> bool {
+        let Some(snapshot) = from.lock_mut().pop() else {
+            return false;
+        };
+        to.lock_mut().push(current(snapshot));
+        true
+    }
+
+    fn can_undo_signal(&self) -> impl Signal<Item = bool> + Send + 'static
+    where
+        T: Send + Sync + 'static,
+    {
+        self.undos.signal_ref(|undos| !undos.is_empty())
+    }
+
+    fn can_redo_signal(&self) -> impl Signal<Item = bool> + Send + 'static
+    where
+        T: Send + Sync + 'static,
+    {
+        self.redos.signal_ref(|redos| !redos.is_empty())
+    }
+
+    fn clear(&self) {
+        self.undos.lock_mut().clear();
+        self.redos.lock_mut().clear();
+    }
+}
+
+/// Wrapper around a [`Mutable`] which records a snapshot of its value before every change made
+/// through it, enabling [`undo`](History::undo) and [`redo`](History::redo), e.g. for editor style
+/// UIs. Changes made directly to the underlying [`Mutable`] are not recorded.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let color = History::new(Color::WHITE);
+/// color.set(Color::BLACK);
+/// El::<Node>::new()
+///     .background_color_signal(color.signal().map(BackgroundColor))
+///     .on_click(clone!((color) move || { color.undo(); }));
+/// ```
+#[derive(Clone)]
+pub struct History<T> {
+    mutable: Mutable<T>,
+    snapshots: Snapshots<T>,
+}
+
+impl<T: Clone> History<T> {
+    /// Wrap a new [`Mutable`] holding `value`.
+    pub fn new(value: T) -> Self {
+        Self::from(Mutable::new(value))
+    }
+
+    /// Record at most `limit` snapshots, discarding the oldest ones.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.snapshots.limit = Some(limit);
+        self
+    }
+
+    /// The underlying [`Mutable`]; changes made directly to it are not recorded.
+    pub fn mutable(&self) -> &Mutable<T> {
+        &self.mutable
+    }
+
+    /// Set the value, recording the previous one.
+    pub fn set(&self, value: T) {
+        self.snapshots.record(self.mutable.replace(value));
+    }
+
+    /// Set the value if it differs from the current one, recording the previous one.
+    pub fn set_neq(&self, value: T)
+    where
+        T: PartialEq,
+    {
+        if *self.mutable.lock_ref() != value {
+            self.set(value);
+        }
+    }
+
+    /// Modify the value in place, recording the previous one.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut lock = self.mutable.lock_mut();
+        self.snapshots.record(T::clone(&lock));
+        f(&mut lock);
+    }
+
+    /// Restore the previously recorded value, returning whether there was one.
+    pub fn undo(&self) -> bool {
+        Snapshots::step(&self.snapshots.undos, &self.snapshots.redos, |snapshot| {
+            self.mutable.replace(snapshot)
+        })
+    }
+
+    /// Reapply the most recently undone value, returning whether there was one.
+    pub fn redo(&self) -> bool {
+        Snapshots::step(&self.snapshots.redos, &self.snapshots.undos, |snapshot| {
+            self.mutable.replace(snapshot)
+        })
+    }
+
+    /// Forget all recorded values.
+    pub fn clear(&self) {
+        self.snapshots.clear()
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> History<T> {
+    /// Outputs the current value.
+    pub fn signal(&self) -> impl Signal<Item = T> + Send + 'static {
+        self.mutable.signal_cloned()
+    }
+
+    /// Outputs whether there is a value to [`undo`](History::undo) to.
+    pub fn can_undo_signal(&self) -> impl Signal<Item = bool> + Send + 'static {
+        self.snapshots.can_undo_signal()
+    }
+
+    /// Outputs whether there is a value to [`redo`](History::redo) to.
+    pub fn can_redo_signal(&self) -> impl Signal<Item = bool> + Send + 'static {
+        self.snapshots.can_redo_signal()
+    }
+}
+
+impl<T> From<Mutable<T>> for History<T> {
+    fn from(mutable: Mutable<T>) -> Self {
+        Self {
+            mutable,
+            snapshots: Snapshots::new(),
+        }
+    }
+}
+
+/// Wrapper around a [`MutableVec`] which records a snapshot of its contents before every change
+/// made through it, enabling [`undo`](VecHistory::undo) and [`redo`](VecHistory::redo). Changes
+/// made directly to the underlying [`MutableVec`] are not recorded.
+#[derive(Clone)]
+pub struct VecHistory<T> {
+    mutable_vec: MutableVec<T>,
+    snapshots: Snapshots<Vec<T>>,
+}
+
+impl<T: Clone> VecHistory<T> {
+    /// Wrap a new [`MutableVec`] holding `values`.
+    pub fn new(values: Vec<T>) -> Self {
+        Self::from(MutableVec::new_with_values(values))
+    }
+
+    /// Record at most `limit` snapshots, discarding the oldest ones.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.snapshots.limit = Some(limit);
+        self
+    }
+
+    /// The underlying [`MutableVec`]; changes made directly to it are not recorded.
+    pub fn mutable_vec(&self) -> &MutableVec<T> {
+        &self.mutable_vec
+    }
+
+    /// Modify the contents, recording the previous ones.
+    pub fn update(&self, f: impl FnOnce(&mut MutableVecLockMut<T>)) {
+        let mut lock = self.mutable_vec.lock_mut();
+        self.snapshots.record(lock.to_vec());
+        f(&mut lock);
+    }
+
+    /// Restore the previously recorded contents, returning whether there were any.
+    pub fn undo(&self) -> bool {
+        Snapshots::step(&self.snapshots.undos, &self.snapshots.redos, |snapshot| {
+            self.replace(snapshot)
+        })
+    }
+
+    /// Reapply the most recently undone contents, returning whether there were any.
+    pub fn redo(&self) -> bool {
+        Snapshots::step(&self.snapshots.redos, &self.snapshots.undos, |snapshot| {
+            self.replace(snapshot)
+        })
+    }
+
+    /// Forget all recorded contents.
+    pub fn clear(&self) {
+        self.snapshots.clear()
+    }
+
+    fn replace(&self, values: Vec<T>) -> Vec<T> {
+        let mut lock = self.mutable_vec.lock_mut();
+        let previous = lock.to_vec();
+        lock.replace_cloned(values);
+        previous
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> VecHistory<T> {
+    /// Outputs whether there are contents to [`undo`](VecHistory::undo) to.
+    pub fn can_undo_signal(&self) -> impl Signal<Item = bool> + Send + 'static {
+        self.snapshots.can_undo_signal()
+    }
+
+    /// Outputs whether there are contents to [`redo`](VecHistory::redo) to.
+    pub fn can_redo_signal(&self) -> impl Signal<Item = bool> + Send + 'static {
+        self.snapshots.can_redo_signal()
+    }
+}
+
+impl<T> From<MutableVec<T>> for VecHistory<T> {
+    fn from(mutable_vec: MutableVec<T>) -> Self {
+        Self {
+            mutable_vec,
+            snapshots: Snapshots::new(),
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! Global keyboard shortcuts and shortcut sequences scoped to the lifetime of an element, see
+//! [`Hotkey`], [`HotkeySequence`], and
+//! [`KeyboardEventAware::hotkey`](super::keyboard_event_aware::KeyboardEventAware::hotkey).
+
+use std::{fmt, str::FromStr, time::Duration};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemId};
+use bevy_input::prelude::*;
+use bevy_log::prelude::*;
+use bevy_render::view::InheritedVisibility;
+use bevy_utils::Instant;
+
+use super::{
+    input_block::InputBlocked,
+    keyboard::{modifiers, Modifiers},
+};
+
+/// Maximum time between the presses of a [`HotkeySequence`].
+pub const HOTKEY_SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A key pressed with an exact set of [`Modifiers`], parsed from strings like `"Ctrl+S"`,
+/// `"Ctrl+Shift+Z"`, or `"Escape"`; modifier and key names are case insensitive. Displays as a
+/// string which parses back to the same [`Hotkey`], e.g. `"Ctrl+s"`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Hotkey {
+    /// Modifiers which must be held, and no others.
+    pub modifiers: Modifiers,
+    /// Key which must be pressed.
+    pub key: KeyCode,
+}
+
+impl FromStr for Hotkey {
+    type Err = String;
+
+    fn from_str(chord: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::default();
+        let mut key_option = None;
+        for part in chord.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.control = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "super" | "cmd" | "command" | "meta" | "win" => modifiers.super_ = true,
+                name => {
+                    if key_option.is_some() {
+                        return Err(format!("hotkey '{chord}' has more than one non modifier key"));
+                    }
+                    key_option =
+                        Some(key_code(name).ok_or_else(|| format!("unknown key '{part}' in hotkey '{chord}'"))?);
+                }
+            }
+        }
+        key_option
+            .map(|key| Hotkey { modifiers, key })
+            .ok_or_else(|| format!("hotkey '{chord}' has no non modifier key"))
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Modifiers {
+            shift,
+            control,
+            alt,
+            super_,
+        } = self.modifiers;
+        for (held, name) in [(control, "Ctrl"), (shift, "Shift"), (alt, "Alt"), (super_, "Super")] {
+            if held {
+                write!(f, "{name}+")?;
+            }
+        }
+        // keys the parser doesn't know fall back to their [`KeyCode`] name
+        match key_name(self.key) {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{:?}", self.key),
+        }
+    }
+}
+
+/// [`Hotkey`]s which must be pressed one after another, each within [`HOTKEY_SEQUENCE_TIMEOUT`] of
+/// the last, parsed from whitespace separated chords like `"Ctrl+K Ctrl+S"` or `"g then i"`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct HotkeySequence(pub Vec<Hotkey>);
+
+impl FromStr for HotkeySequence {
+    type Err = String;
+
+    fn from_str(sequence: &str) -> Result<Self, Self::Err> {
+        let hotkeys = sequence
+            .split_whitespace()
+            .filter(|part| !part.eq_ignore_ascii_case("then"))
+            .map(str::parse)
+            .collect::<Result<Vec<Hotkey>, _>>()?;
+        if hotkeys.is_empty() {
+            return Err(format!("hotkey sequence '{sequence}' is empty"));
+        }
+        Ok(HotkeySequence(hotkeys))
+    }
+}
+
+impl From<Hotkey> for HotkeySequence {
+    fn from(hotkey: Hotkey) -> Self {
+        HotkeySequence(vec![hotkey])
+    }
+}
+
+impl fmt::Display for HotkeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, hotkey) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{hotkey}")?;
+        }
+        Ok(())
+    }
+}
+
+const LETTERS: [KeyCode; 26] = [
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+];
+const DIGITS: [KeyCode; 10] = [
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+const FUNCTION_KEYS: [KeyCode; 12] = [
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+];
+
+fn key_code(name: &str) -> Option<KeyCode> {
+    if let &[c] = name.as_bytes() {
+        if c.is_ascii_lowercase() {
+            return Some(LETTERS[(c - b'a') as usize]);
+        }
+        if c.is_ascii_digit() {
+            return Some(DIGITS[(c - b'0') as usize]);
+        }
+    }
+    if let Some(n) = name.strip_prefix('f').and_then(|n| n.parse::<usize>().ok()) {
+        return (1..=FUNCTION_KEYS.len()).contains(&n).then(|| FUNCTION_KEYS[n - 1]);
+    }
+    Some(match name {
+        "esc" | "escape" => KeyCode::Escape,
+        "enter" | "return" => KeyCode::Enter,
+        "space" => KeyCode::Space,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "up" => KeyCode::ArrowUp,
+        "down" => KeyCode::ArrowDown,
+        "left" => KeyCode::ArrowLeft,
+        "right" => KeyCode::ArrowRight,
+        "-" | "minus" => KeyCode::Minus,
+        "=" | "equal" => KeyCode::Equal,
+        "," | "comma" => KeyCode::Comma,
+        "." | "period" => KeyCode::Period,
+        "/" | "slash" => KeyCode::Slash,
+        _ => return None,
+    })
+}
+
+// inverse of [`key_code`], so [`Hotkey`]s display as they are parsed
+fn key_name(key: KeyCode) -> Option<String> {
+    if let Some(i) = LETTERS.iter().position(|&letter| letter == key) {
+        return Some(char::from(b'a' + i as u8).to_string());
+    }
+    if let Some(i) = DIGITS.iter().position(|&digit| digit == key) {
+        return Some(i.to_string());
+    }
+    if let Some(i) = FUNCTION_KEYS.iter().position(|&function_key| function_key == key) {
+        return Some(format!("f{}", i + 1));
+    }
+    Some(
+        match key {
+            KeyCode::Escape => "esc",
+            KeyCode::Enter => "enter",
+            KeyCode::Space => "space",
+            KeyCode::Tab => "tab",
+            KeyCode::Backspace => "backspace",
+            KeyCode::Delete => "delete",
+            KeyCode::Insert => "insert",
+            KeyCode::Home => "home",
+            KeyCode::End => "end",
+            KeyCode::PageUp => "pageup",
+            KeyCode::PageDown => "pagedown",
+            KeyCode::ArrowUp => "up",
+            KeyCode::ArrowDown => "down",
+            KeyCode::ArrowLeft => "left",
+            KeyCode::ArrowRight => "right",
+            KeyCode::Minus => "minus",
+            KeyCode::Equal => "equal",
+            KeyCode::Comma => "comma",
+            KeyCode::Period => "period",
+            KeyCode::Slash => "slash",
+            _ => return None,
+        }
+        .to_string(),
+    )
+}
+
+struct RegisteredHotkey {
+    sequence: HotkeySequence,
+    entity: Entity,
+    visible_only: bool,
+    system: SystemId,
+}
+
+/// Hotkeys registered by live elements, in registration order.
+#[derive(Resource, Default)]
+pub(crate) struct Hotkeys(Vec<RegisteredHotkey>);
+
+impl Hotkeys {
+    /// Register `system` to run when `sequence` is pressed, logging a warning if another element
+    /// already registered `sequence`; on conflict, the most recently registered handler wins.
+    pub(crate) fn register(&mut self, sequence: HotkeySequence, entity: Entity, visible_only: bool, system: SystemId) {
+        let conflicts = self
+            .0
+            .iter()
+            .filter(|registered| registered.sequence == sequence && registered.entity != entity)
+            .map(|registered| registered.entity)
+            .collect::<Vec<_>>();
+        if !conflicts.is_empty() {
+            warn!("hotkey '{sequence}' registered by {entity} conflicts with {conflicts:?}, which will be shadowed");
+        }
+        self.0.push(RegisteredHotkey {
+            sequence,
+            entity,
+            visible_only,
+            system,
+        });
+    }
+
+    pub(crate) fn unregister(&mut self, entity: Entity) {
+        self.0.retain(|registered| registered.entity != entity);
+    }
+}
+
+const MODIFIER_KEYS: [KeyCode; 8] = [
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
+
+fn dispatch_hotkeys(
+    keys: Res<ButtonInput<KeyCode>>,
+    hotkeys: Res<Hotkeys>,
+    visibilities: Query<&InheritedVisibility>,
+    input_blocked: InputBlocked,
+    // recently pressed hotkeys, each within the timeout of the last
+    mut recent: Local<Vec<Hotkey>>,
+    mut last_press_option: Local<Option<Instant>>,
+    mut commands: Commands,
+) {
+    let modifiers = modifiers(&keys);
+    for &key in keys.get_just_pressed() {
+        if MODIFIER_KEYS.contains(&key) {
+            continue;
+        }
+        let now = Instant::now();
+        if last_press_option.is_some_and(|last_press| now.duration_since(last_press) > HOTKEY_SEQUENCE_TIMEOUT) {
+            recent.clear();
+        }
+        *last_press_option = Some(now);
+        recent.push(Hotkey { modifiers, key });
+        let registered_option = hotkeys.0.iter().rev().find(|registered| {
+            recent.ends_with(&registered.sequence.0)
+                && !input_blocked.blocked(registered.entity)
+                && (!registered.visible_only
+                    || visibilities
+                        .get(registered.entity)
+                        .is_ok_and(|visibility| visibility.get()))
+        });
+        if let Some(registered) = registered_option {
+            commands.run_system(registered.system);
+            recent.clear();
+        } else if !hotkeys.0.iter().any(|registered| {
+            (1..registered.sequence.0.len()).any(|len| recent.ends_with(&registered.sequence.0[..len]))
+        }) {
+            // no sequence in progress, so only the latest press can start one
+            let latest = recent.pop();
+            recent.clear();
+            recent.extend(latest);
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Hotkeys>().add_systems(
+        Update,
+        dispatch_hotkeys.run_if(|hotkeys: Res<Hotkeys>, keys: Res<ButtonInput<KeyCode>>| {
+            !hotkeys.0.is_empty() && keys.get_just_pressed().next().is_some()
+        }),
+    );
+}
@@ -0,0 +1,118 @@
+//! Suppression of pointer and keyboard interaction with everything beneath a [`ZLayer`], so modals
+//! and loading screens can reliably gate the UI underneath them, see [`InputBlock`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemParam};
+use bevy_hierarchy::prelude::*;
+use bevy_picking::prelude::*;
+use bevy_ui::prelude::*;
+use bevy_utils::prelude::*;
+
+use super::z_layer::{ZLayer, ZLayers};
+
+/// Stack of [`ZLayer`]s beneath which pointer and keyboard interaction is suppressed; only the
+/// most recently pushed [`ZLayer`] is in effect. While blocked, an invisible overlay just beneath
+/// the [`ZLayer`] absorbs pointer events, and hotkeys, focused keyboard input, focus navigation,
+/// and non hover mouse wheel scrolling ignore elements beneath the [`ZLayer`], which also lose
+/// focus. An element is beneath the [`ZLayer`] if the [`GlobalZIndex`] of it or its closest
+/// ancestor with one, or `0` if there is none, is less than that of the [`ZLayer`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// fn open_modal(mut input_block: ResMut<InputBlock>) {
+///     input_block.push(ZLayer::Popup);
+/// }
+///
+/// fn close_modal(mut input_block: ResMut<InputBlock>) {
+///     input_block.pop();
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct InputBlock(Vec<ZLayer>);
+
+impl InputBlock {
+    /// Block interaction with everything beneath `layer` until the matching [`.pop`](Self::pop).
+    pub fn push(&mut self, layer: ZLayer) {
+        self.0.push(layer);
+    }
+
+    /// Stop the most recently pushed block, returning its [`ZLayer`].
+    pub fn pop(&mut self) -> Option<ZLayer> {
+        self.0.pop()
+    }
+
+    /// The [`ZLayer`] beneath which interaction is currently blocked, if any.
+    pub fn layer(&self) -> Option<ZLayer> {
+        self.0.last().copied()
+    }
+}
+
+/// Whether elements are beneath the current [`InputBlock`].
+#[derive(SystemParam)]
+pub(crate) struct InputBlocked<'w, 's> {
+    input_block: Res<'w, InputBlock>,
+    z_layers: Res<'w, ZLayers>,
+    parents: Query<'w, 's, &'static Parent>,
+    global_z_indices: Query<'w, 's, &'static GlobalZIndex>,
+}
+
+impl InputBlocked<'_, '_> {
+    pub(crate) fn blocked(&self, entity: Entity) -> bool {
+        let Some(layer) = self.input_block.layer() else {
+            return false;
+        };
+        let z_index = std::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .find_map(|entity| self.global_z_indices.get(entity).ok())
+            .map(|global_z_index| global_z_index.0)
+            .unwrap_or_default();
+        z_index < self.z_layers.z_index(layer)
+    }
+}
+
+fn sync_input_block_overlay(
+    input_block: Res<InputBlock>,
+    z_layers: Res<ZLayers>,
+    mut overlay_option: Local<Option<Entity>>,
+    mut commands: Commands,
+) {
+    if let Some(layer) = input_block.layer() {
+        let global_z_index = GlobalZIndex(z_layers.z_index(layer) - 1);
+        if let Some(mut overlay) = overlay_option.and_then(|overlay| commands.get_entity(overlay)) {
+            overlay.insert(global_z_index);
+            return;
+        }
+        let overlay = commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.),
+                    top: Val::Px(0.),
+                    width: Val::Vw(100.),
+                    height: Val::Vh(100.),
+                    ..default()
+                },
+                global_z_index,
+                PickingBehavior {
+                    should_block_lower: true,
+                    is_hoverable: false,
+                },
+            ))
+            .id();
+        *overlay_option = Some(overlay);
+    } else if let Some(overlay) = overlay_option.take() {
+        if let Some(overlay) = commands.get_entity(overlay) {
+            overlay.despawn_recursive();
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<InputBlock>().add_systems(
+        Update,
+        sync_input_block_overlay.run_if(resource_changed::<InputBlock>.or(resource_changed::<ZLayers>)),
+    );
+}
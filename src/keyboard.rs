@@ -0,0 +1,77 @@
+//! Reactive access to the keyboard's state, see [`key_pressed_signal`] and [`modifiers_signal`].
+
+use std::{collections::HashMap, sync::Mutex};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_input::{prelude::*, InputSystem};
+use futures_signals::signal::{Mutable, Signal};
+use once_cell::sync::Lazy;
+
+static KEYS_PRESSED: Lazy<Mutex<HashMap<KeyCode, Mutable<bool>>>> = Lazy::new(Mutex::default);
+static MODIFIERS: Lazy<Mutable<Modifiers>> = Lazy::new(Mutable::default);
+
+/// Which modifier keys are held; the left and right variants of each key are not distinguished.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Modifiers {
+    /// Either Shift key.
+    pub shift: bool,
+    /// Either Control key.
+    pub control: bool,
+    /// Either Alt key.
+    pub alt: bool,
+    /// The Windows, Command, or Super key.
+    pub super_: bool,
+}
+
+/// Which [`Modifiers`] are held according to `keys`.
+pub(crate) fn modifiers(keys: &ButtonInput<KeyCode>) -> Modifiers {
+    Modifiers {
+        shift: keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+        control: keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+        alt: keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        super_: keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+    }
+}
+
+/// Outputs whether `key` is held.
+pub fn key_pressed_signal(key: KeyCode) -> impl Signal<Item = bool> + Send + 'static {
+    KEYS_PRESSED
+        .lock()
+        .unwrap()
+        .entry(key)
+        .or_insert_with(Mutable::default)
+        .signal()
+}
+
+/// Outputs which [`Modifiers`] are held.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// // extended tooltip while Alt is held
+/// El::<Text>::new()
+///     .text(Text::new("details"))
+///     .visible_signal(modifiers_signal().map(|modifiers| modifiers.alt));
+/// ```
+pub fn modifiers_signal() -> impl Signal<Item = Modifiers> + Send + 'static {
+    MODIFIERS.signal()
+}
+
+fn sync_keyboard(keys: Res<ButtonInput<KeyCode>>) {
+    for (key, pressed) in KEYS_PRESSED.lock().unwrap().iter() {
+        pressed.set_neq(keys.pressed(*key));
+    }
+    MODIFIERS.set_neq(modifiers(&keys));
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        sync_keyboard
+            .after(InputSystem)
+            .run_if(resource_exists_and_changed::<ButtonInput<KeyCode>>),
+    );
+}
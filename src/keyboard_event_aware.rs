@@ -0,0 +1,163 @@
+//! Semantics for managing how an [`Element`](super::element::Element) reacts to keyboard input while
+//! it, or one of its descendants, has focus, and for registering element scoped hotkeys, see
+//! [`KeyboardEventAware`].
+
+use apply::Apply;
+use bevy_app::prelude::*;
+use bevy_derive::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_log::prelude::*;
+use futures_signals::signal::Mutable;
+
+use super::{
+    focus::FocusedElement,
+    hotkey::{Hotkey, HotkeySequence, Hotkeys},
+    input_block::InputBlocked,
+    raw::{register_system, utils::remove_system_holder_on_remove, RawElWrapper, RawHaalkaEl},
+    utils::clone,
+};
+
+/// [`KeyboardInput`] received while an element has focus; bubbles up from the
+/// [`FocusedElement`] through its ancestors.
+#[derive(Component, Clone, Debug, Deref)]
+pub struct FocusedKeyboardInput(pub KeyboardInput);
+
+impl Event for FocusedKeyboardInput {
+    type Traversal = &'static Parent;
+
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// Enables reacting to keyboard input while this element, or one of its descendants, has focus,
+/// see [`KeyboardFocusable`](super::focus::KeyboardFocusable).
+pub trait KeyboardEventAware: RawElWrapper {
+    /// When a [`KeyboardInput`] is received while this element, or one of its descendants, has
+    /// focus, run a [`System`] which takes [`In`](`System::In`) this element's [`Entity`] and the
+    /// [`FocusedKeyboardInput`]. This method can be called repeatedly to register many such
+    /// handlers.
+    fn on_key_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, FocusedKeyboardInput)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| raw_el.on_event_with_system::<FocusedKeyboardInput, _>(handler))
+    }
+
+    /// When a [`KeyboardInput`] is received while this element, or one of its descendants, has
+    /// focus, run a function with it. This method can be called repeatedly to register many such
+    /// handlers.
+    fn on_key(self, mut handler: impl FnMut(&KeyboardInput) + Send + Sync + 'static) -> Self {
+        self.on_key_with_system(move |In((_, input)): In<(_, FocusedKeyboardInput)>| handler(&input))
+    }
+
+    /// When `key_code` is pressed while this element, or one of its descendants, has focus, run a
+    /// function; also runs for the repeats generated by holding the key down. This method can be
+    /// called repeatedly to register many such handlers.
+    fn on_key_down(self, key_code: KeyCode, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_key(move |input| {
+            if input.key_code == key_code && input.state == ButtonState::Pressed {
+                handler()
+            }
+        })
+    }
+
+    /// When `key_code` is released while this element, or one of its descendants, has focus, run a
+    /// function. This method can be called repeatedly to register many such handlers.
+    fn on_key_up(self, key_code: KeyCode, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_key(move |input| {
+            if input.key_code == key_code && input.state == ButtonState::Released {
+                handler()
+            }
+        })
+    }
+
+    /// While this element is alive, run a [`System`] whenever the `chord`, e.g. `"Ctrl+S"`, is
+    /// pressed, regardless of focus, see [`Hotkey`] for the accepted syntax. If another live element
+    /// registered the same chord, a warning is logged and the most recently registered handler wins.
+    /// If `chord` cannot be parsed, an error is logged and no handler is registered.
+    fn hotkey_with_system<Marker>(
+        self,
+        chord: &str,
+        handler: impl IntoSystem<(), (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| register_hotkey(raw_el, chord.parse::<Hotkey>().map(Into::into), false, handler))
+    }
+
+    /// While this element is alive, run a function whenever the `chord`, e.g. `"Ctrl+S"`, is
+    /// pressed, regardless of focus; see
+    /// [`.hotkey_with_system`](KeyboardEventAware::hotkey_with_system).
+    fn hotkey(self, chord: &str, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.hotkey_with_system(chord, move || handler())
+    }
+
+    /// While this element is alive, run a function whenever the `sequence` of chords, e.g.
+    /// `"Ctrl+K Ctrl+S"` or `"g then i"`, is pressed, regardless of focus, see [`HotkeySequence`]
+    /// for the accepted syntax; useful for command palette style tooling. Conflicts and parse errors
+    /// are handled like in [`.hotkey_with_system`](KeyboardEventAware::hotkey_with_system).
+    fn hotkey_sequence(self, sequence: &str, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| register_hotkey(raw_el, sequence.parse(), false, move || handler()))
+    }
+
+    /// While this element is alive and visible, run a function whenever the `chord`, e.g.
+    /// `"Ctrl+S"`, is pressed, regardless of focus; useful for shortcuts that only make sense while
+    /// a panel is open. See [`.hotkey_with_system`](KeyboardEventAware::hotkey_with_system).
+    fn hotkey_while_visible(self, chord: &str, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            register_hotkey(raw_el, chord.parse::<Hotkey>().map(Into::into), true, move || handler())
+        })
+    }
+}
+
+fn register_hotkey<Marker>(
+    raw_el: RawHaalkaEl,
+    sequence_result: Result<HotkeySequence, String>,
+    visible_only: bool,
+    handler: impl IntoSystem<(), (), Marker> + Send + 'static,
+) -> RawHaalkaEl {
+    let sequence = match sequence_result {
+        Ok(sequence) => sequence,
+        Err(error) => {
+            error!("{error}");
+            return raw_el;
+        }
+    };
+    let system_holder = Mutable::new(None);
+    raw_el
+        .on_spawn(clone!((system_holder) move |world, entity| {
+            let system = register_system(world, handler);
+            system_holder.set(Some(system));
+            world.get_resource_or_insert_with(Hotkeys::default).register(sequence, entity, visible_only, system);
+        }))
+        .on_remove(|world, entity| {
+            if let Some(mut hotkeys) = world.get_resource_mut::<Hotkeys>() {
+                hotkeys.unregister(entity);
+            }
+        })
+        .apply(remove_system_holder_on_remove(system_holder))
+}
+
+fn dispatch_focused_keyboard_input(
+    mut inputs: EventReader<KeyboardInput>,
+    focused: Res<FocusedElement>,
+    input_blocked: InputBlocked,
+    mut commands: Commands,
+) {
+    let Some(entity) = focused.0.filter(|&entity| !input_blocked.blocked(entity)) else {
+        inputs.clear();
+        return;
+    };
+    for input in inputs.read() {
+        commands.trigger_targets(FocusedKeyboardInput(input.clone()), entity);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        dispatch_focused_keyboard_input.run_if(on_event::<KeyboardInput>),
+    );
+}
@@ -0,0 +1,213 @@
+//! Semantics for scrolling [mutable viewport](super::viewport_mutable::ViewportMutable)s by dragging
+//! them, coasting with momentum after release like native touchscreen lists, see
+//! [`KineticScrollable`].
+
+use std::time::Duration;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_math::prelude::*;
+use bevy_picking::prelude::*;
+use bevy_time::prelude::*;
+use bevy_ui::prelude::*;
+
+use super::{
+    mouse_wheel_scrollable::ScrollDirection,
+    raw::RawElWrapper,
+    viewport_mutable::{MutableViewport, ViewportAnimation},
+};
+
+/// Fraction of a kinetic scroll's velocity retained after coasting for one second.
+pub const KINETIC_SCROLL_FRICTION: f32 = 0.05;
+
+/// Fraction of drag movement applied while a kinetic scroll is dragged past the end of its
+/// content.
+pub const KINETIC_SCROLL_EDGE_RESISTANCE: f32 = 0.35;
+
+// releasing a drag after holding still for this long does not fling
+const FLING_TIMEOUT: Duration = Duration::from_millis(100);
+// fraction of the distance past the end of the content remaining after springing back for one second
+const SPRING_BACK: f32 = 0.0001;
+// coasting slower than this, in logical pixels per second, stops
+const MIN_VELOCITY: f32 = 10.;
+
+#[derive(Component)]
+struct KineticScroll {
+    direction: ScrollDirection,
+    velocity: Vec2,
+    dragging: bool,
+    last_drag: Duration,
+}
+
+/// Enables [mutable viewport](super::viewport_mutable::ViewportMutable)s to be scrolled by
+/// dragging them, with momentum and edge resistance.
+pub trait KineticScrollable: RawElWrapper {
+    /// Scroll this element's viewport along the `direction` by dragging it with a touch or the
+    /// primary mouse button. Released drags coast with their velocity, decaying per
+    /// [`KINETIC_SCROLL_FRICTION`], and drags past the end of the content are resisted per
+    /// [`KINETIC_SCROLL_EDGE_RESISTANCE`] before springing back on release. Drags do not bubble past
+    /// this element, so nested kinetic scrollables do not scroll together.
+    ///
+    /// # Notes
+    /// Requires [`.mutable_viewport(...)`](super::viewport_mutable::ViewportMutable::mutable_viewport).
+    fn kinetic_scroll(self, direction: ScrollDirection) -> Self {
+        self.update_raw_el(move |raw_el| {
+            raw_el
+                .insert((
+                    PickingBehavior::default(),
+                    KineticScroll {
+                        direction,
+                        velocity: Vec2::ZERO,
+                        dragging: false,
+                        last_drag: Duration::ZERO,
+                    },
+                ))
+                .on_event_with_system_stop_propagation::<Pointer<DragStart>, _>(
+                    |In((entity, drag_start)): In<(Entity, Pointer<DragStart>)>,
+                     mut kinetic_scrolls: Query<&mut KineticScroll>,
+                     mut commands: Commands| {
+                        if !matches!(drag_start.button, PointerButton::Primary) {
+                            return;
+                        }
+                        if let Ok(mut kinetic_scroll) = kinetic_scrolls.get_mut(entity) {
+                            kinetic_scroll.dragging = true;
+                            kinetic_scroll.velocity = Vec2::ZERO;
+                        }
+                        // grabbing the viewport stops any smooth scrolling
+                        commands.entity(entity).remove::<ViewportAnimation>();
+                    },
+                )
+                .on_event_with_system_stop_propagation::<Pointer<Drag>, _>(
+                    |In((entity, drag)): In<(Entity, Pointer<Drag>)>,
+                     mut kinetic_scrolls: Query<(&mut KineticScroll, &mut Node)>,
+                     scenes: Query<(&ComputedNode, &Parent), With<MutableViewport>>,
+                     computed_nodes: Query<&ComputedNode>,
+                     time: Res<Time>,
+                     ui_scale_option: Option<Res<UiScale>>| {
+                        let Ok((mut kinetic_scroll, mut node)) = kinetic_scrolls.get_mut(entity) else {
+                            return;
+                        };
+                        if !kinetic_scroll.dragging || !matches!(drag.button, PointerButton::Primary) {
+                            return;
+                        }
+                        let Some(overflow) = overflow(entity, &scenes, &computed_nodes) else {
+                            return;
+                        };
+                        let delta = drag.delta / ui_scale_option.map(|ui_scale| ui_scale.0).unwrap_or(1.)
+                            * axes(kinetic_scroll.direction);
+                        let offset = offset(&node);
+                        let next = offset + delta;
+                        let past_end = next.cmplt(-overflow) | next.cmpgt(Vec2::ZERO);
+                        let next = Vec2::select(past_end, offset + delta * KINETIC_SCROLL_EDGE_RESISTANCE, next);
+                        let dt = time.delta_secs();
+                        if dt > 0. {
+                            kinetic_scroll.velocity = kinetic_scroll.velocity.lerp(delta / dt, 0.5);
+                        }
+                        kinetic_scroll.last_drag = time.elapsed();
+                        set_offset(&mut node, offset, next);
+                    },
+                )
+                .on_event_with_system_stop_propagation::<Pointer<DragEnd>, _>(
+                    |In((entity, _)): In<(Entity, Pointer<DragEnd>)>,
+                     mut kinetic_scrolls: Query<&mut KineticScroll>,
+                     time: Res<Time>| {
+                        if let Ok(mut kinetic_scroll) = kinetic_scrolls.get_mut(entity) {
+                            kinetic_scroll.dragging = false;
+                            if time.elapsed().saturating_sub(kinetic_scroll.last_drag) > FLING_TIMEOUT {
+                                kinetic_scroll.velocity = Vec2::ZERO;
+                            }
+                        }
+                    },
+                )
+        })
+    }
+}
+
+fn axes(direction: ScrollDirection) -> Vec2 {
+    match direction {
+        ScrollDirection::Horizontal => Vec2::X,
+        ScrollDirection::Vertical => Vec2::Y,
+        ScrollDirection::Both => Vec2::ONE,
+    }
+}
+
+fn px(val: Val) -> f32 {
+    if let Val::Px(px) = val {
+        px
+    } else {
+        0.
+    }
+}
+
+fn offset(node: &Node) -> Vec2 {
+    Vec2::new(px(node.left), px(node.top))
+}
+
+// only touches changed offsets to avoid triggering change detection every frame
+fn set_offset(node: &mut Mut<Node>, offset: Vec2, next: Vec2) {
+    if next.x != offset.x {
+        node.left = Val::Px(next.x);
+    }
+    if next.y != offset.y {
+        node.top = Val::Px(next.y);
+    }
+}
+
+// how far, in logical pixels, the scene extends past its viewport
+fn overflow(
+    entity: Entity,
+    scenes: &Query<(&ComputedNode, &Parent), With<MutableViewport>>,
+    computed_nodes: &Query<&ComputedNode>,
+) -> Option<Vec2> {
+    let (scene, parent) = scenes.get(entity).ok()?;
+    let viewport = computed_nodes.get(parent.get()).ok()?;
+    Some((scene.size() - viewport.size()).max(Vec2::ZERO) * scene.inverse_scale_factor())
+}
+
+fn coast_kinetic_scrolls(
+    mut kinetic_scrolls: Query<(Entity, &mut KineticScroll, &mut Node)>,
+    scenes: Query<(&ComputedNode, &Parent), With<MutableViewport>>,
+    computed_nodes: Query<&ComputedNode>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut kinetic_scroll, mut node) in kinetic_scrolls.iter_mut() {
+        if kinetic_scroll.dragging {
+            continue;
+        }
+        let Some(overflow) = overflow(entity, &scenes, &computed_nodes) else {
+            continue;
+        };
+        let offset = offset(&node);
+        if kinetic_scroll.velocity == Vec2::ZERO && offset == offset.clamp(-overflow, Vec2::ZERO) {
+            continue;
+        }
+        let next = offset + kinetic_scroll.velocity * dt;
+        let clamped = next.clamp(-overflow, Vec2::ZERO);
+        // past the end of the content, spring back rather than coast
+        let past_end = next.cmpne(clamped);
+        let velocity = Vec2::select(
+            past_end,
+            Vec2::ZERO,
+            kinetic_scroll.velocity * KINETIC_SCROLL_FRICTION.powf(dt),
+        );
+        kinetic_scroll.velocity = if velocity.length() < MIN_VELOCITY {
+            Vec2::ZERO
+        } else {
+            velocity
+        };
+        let mut next = clamped + (next - clamped) * SPRING_BACK.powf(dt);
+        if (next - clamped).abs().max_element() < 0.5 {
+            next = clamped;
+        }
+        set_offset(&mut node, offset, next);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        coast_kinetic_scrolls.run_if(any_with_component::<KineticScroll>),
+    );
+}
@@ -0,0 +1,314 @@
+//! Semantics for reacting to an element's own computed layout and screen position, see
+//! [`LayoutAware`].
+
+use apply::Apply;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::{prelude::*, update::update_clipping_system, UiSystem};
+use bevy_window::{PrimaryWindow, Window};
+use futures_signals::signal::{Mutable, ReadOnlyMutable};
+
+use super::{
+    raw::{
+        observe, register_system, utils::remove_system_holder_on_remove, DeferredUpdaterAppendDirection, RawElWrapper,
+    },
+    utils::clone,
+};
+
+/// Tracks the last dispatched logical size of an element listening to size changes.
+#[derive(Component, Default)]
+struct SizeListener {
+    size: Option<Vec2>,
+}
+
+#[derive(Event)]
+struct SizeChange(Vec2);
+
+/// Tracks the last dispatched logical screen rect of an element listening to rect changes.
+#[derive(Component, Default)]
+struct GlobalRectListener {
+    rect: Option<Rect>,
+}
+
+#[derive(Event)]
+struct GlobalRectChange(Rect);
+
+/// Tracks the last dispatched viewport visibility of an element listening to visibility changes.
+#[derive(Component, Default)]
+struct ViewportVisibilityListener {
+    visible: Option<bool>,
+}
+
+#[derive(Event)]
+struct ViewportVisibilityChange(bool);
+
+/// Functions to run once an element has been through its first layout pass.
+#[derive(Component, Default)]
+struct OnSpawnDeferred {
+    fs: Vec<Box<dyn FnOnce(&mut World, Entity) + Send + Sync>>,
+    /// Whether the element existed before the latest layout pass.
+    ready: bool,
+}
+
+/// Enables reacting to an element's own computed layout, e.g. for container query style behavior,
+/// with consideration for any potential [haalka](crate) managed wrapper nodes.
+///
+/// All sizes are in logical pixels and are reported after bevy_ui's layout has run.
+pub trait LayoutAware: RawElWrapper {
+    /// When this element's computed size changes, run a [`System`] which takes
+    /// [`In`](`System::In`) this element's [`Entity`] and its new size. This method can be called
+    /// repeatedly to register many such handlers.
+    fn on_size_change_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, Vec2)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                let system_holder = Mutable::new(None);
+                raw_el
+                    .insert(SizeListener::default())
+                    .on_spawn(clone!((system_holder) move |world, entity| {
+                        let system = register_system(world, handler);
+                        system_holder.set(Some(system));
+                        observe(world, entity, move |size_change: Trigger<SizeChange>, mut commands: Commands| {
+                            commands.run_system_with_input(system, (entity, size_change.event().0));
+                        });
+                    }))
+                    .apply(remove_system_holder_on_remove(system_holder))
+            })
+        })
+    }
+
+    /// When this element's computed size changes, run a function with its new size. This method
+    /// can be called repeatedly to register many such handlers.
+    fn on_size_change(self, mut handler: impl FnMut(Vec2) + Send + Sync + 'static) -> Self {
+        self.on_size_change_with_system(move |In((_, size))| handler(size))
+    }
+
+    /// When this element's screen rect, relative to the top left corner of the window, changes, run
+    /// a [`System`] which takes [`In`](`System::In`) this element's [`Entity`] and its new rect.
+    /// Useful for anchoring, culling, or connecting world space lines to UI nodes. This method can
+    /// be called repeatedly to register many such handlers.
+    fn on_global_rect_change_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, Rect)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                let system_holder = Mutable::new(None);
+                raw_el
+                    .insert(GlobalRectListener::default())
+                    .on_spawn(clone!((system_holder) move |world, entity| {
+                        let system = register_system(world, handler);
+                        system_holder.set(Some(system));
+                        observe(world, entity, move |rect_change: Trigger<GlobalRectChange>, mut commands: Commands| {
+                            commands.run_system_with_input(system, (entity, rect_change.event().0));
+                        });
+                    }))
+                    .apply(remove_system_holder_on_remove(system_holder))
+            })
+        })
+    }
+
+    /// When this element's screen rect changes, run a function with its new rect. This method can
+    /// be called repeatedly to register many such handlers.
+    fn on_global_rect_change(self, mut handler: impl FnMut(Rect) + Send + Sync + 'static) -> Self {
+        self.on_global_rect_change_with_system(move |In((_, rect))| handler(rect))
+    }
+
+    /// Sync a [`Mutable`] with this element's screen rect; use [`Mutable::signal`] to derive a
+    /// reactive rect signal.
+    fn global_rect_sync(self, rect: Mutable<Rect>) -> Self {
+        self.on_global_rect_change(move |new_rect| rect.set_neq(new_rect))
+    }
+
+    /// When whether any part of this element is within the bounds of the window and its clipping
+    /// ancestors, e.g. scroll containers, changes, run a [`System`] which takes
+    /// [`In`](`System::In`) this element's [`Entity`] and whether it is visible. Useful for lazy
+    /// loading or pausing offscreen animations. This method can be called repeatedly to register
+    /// many such handlers.
+    fn on_viewport_visibility_change_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, bool)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                let system_holder = Mutable::new(None);
+                raw_el
+                    .insert(ViewportVisibilityListener::default())
+                    .on_spawn(clone!((system_holder) move |world, entity| {
+                        let system = register_system(world, handler);
+                        system_holder.set(Some(system));
+                        observe(world, entity, move |visibility_change: Trigger<ViewportVisibilityChange>, mut commands: Commands| {
+                            commands.run_system_with_input(system, (entity, visibility_change.event().0));
+                        });
+                    }))
+                    .apply(remove_system_holder_on_remove(system_holder))
+            })
+        })
+    }
+
+    /// When whether any part of this element is within the bounds of the window and its clipping
+    /// ancestors changes, run a function with whether it is visible. This method can be called
+    /// repeatedly to register many such handlers.
+    fn on_viewport_visibility_change(self, mut handler: impl FnMut(bool) + Send + Sync + 'static) -> Self {
+        self.on_viewport_visibility_change_with_system(move |In((_, visible))| handler(visible))
+    }
+
+    /// Sync a [`Mutable<bool>`] with whether any part of this element is within the bounds of the
+    /// window and its clipping ancestors; use [`Mutable::signal`] to derive a reactive visibility
+    /// signal.
+    fn in_viewport_sync(self, in_viewport: Mutable<bool>) -> Self {
+        self.on_viewport_visibility_change(move |visible| in_viewport.set_neq(visible))
+    }
+
+    /// Run a function with mutable access to the [`World`] and this element's [`Entity`] after
+    /// bevy_ui's first layout pass including it, e.g. for measuring or scrolling to a position,
+    /// which need computed [`Node`] sizes. This method can be called repeatedly to register many
+    /// such functions.
+    fn on_spawn_deferred(self, f: impl FnOnce(&mut World, Entity) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                raw_el.on_spawn(|world, entity| {
+                    let mut entity = world.entity_mut(entity);
+                    if let Some(mut on_spawn_deferred) = entity.get_mut::<OnSpawnDeferred>() {
+                        on_spawn_deferred.fs.push(Box::new(f));
+                    } else {
+                        entity.insert(OnSpawnDeferred {
+                            fs: vec![Box::new(f)],
+                            ready: false,
+                        });
+                    }
+                })
+            })
+        })
+    }
+
+    /// Sync a [`Mutable`] with this element's computed size; use [`Mutable::signal`] to derive a
+    /// reactive size signal.
+    fn size_sync(self, size: Mutable<Vec2>) -> Self {
+        self.on_size_change(move |new_size| size.set_neq(new_size))
+    }
+
+    /// Build on this element with a read only view of its computed size, avoiding the need for a
+    /// separate [`Mutable`] and [`.size_sync`](LayoutAware::size_sync); use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive size signals.
+    fn size_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<Vec2>) -> Self) -> Self {
+        let size = Mutable::new(Vec2::ZERO);
+        f(self.size_sync(size.clone()), size.read_only())
+    }
+}
+
+fn size_change_dispatcher(
+    mut data: Query<(Entity, &ComputedNode, &mut SizeListener), Changed<ComputedNode>>,
+    mut commands: Commands,
+) {
+    for (entity, computed_node, mut listener) in data.iter_mut() {
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        if listener.size != Some(size) {
+            listener.size = Some(size);
+            commands.trigger_targets(SizeChange(size), entity);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn global_rect_change_dispatcher(
+    mut data: Query<
+        (Entity, &ComputedNode, &GlobalTransform, &mut GlobalRectListener),
+        Or<(Changed<ComputedNode>, Changed<GlobalTransform>)>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, computed_node, global_transform, mut listener) in data.iter_mut() {
+        // ui node transforms point to their center, in physical pixels
+        let physical = Rect::from_center_size(global_transform.translation().truncate(), computed_node.size());
+        let scale = computed_node.inverse_scale_factor();
+        let rect = Rect::from_corners(physical.min * scale, physical.max * scale);
+        if listener.rect != Some(rect) {
+            listener.rect = Some(rect);
+            commands.trigger_targets(GlobalRectChange(rect), entity);
+        }
+    }
+}
+
+fn viewport_visibility_change_dispatcher(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut data: Query<(
+        Entity,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&CalculatedClip>,
+        &mut ViewportVisibilityListener,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let window_rect = Rect::from_corners(Vec2::ZERO, window.physical_size().as_vec2());
+    for (entity, computed_node, global_transform, clip_option, mut listener) in data.iter_mut() {
+        // ui node transforms point to their center, in physical pixels
+        let rect = Rect::from_center_size(global_transform.translation().truncate(), computed_node.size());
+        let mut bounds = window_rect;
+        if let Some(clip) = clip_option {
+            bounds = bounds.intersect(clip.clip);
+        }
+        let visible = !rect.intersect(bounds).is_empty() || (rect.is_empty() && bounds.contains(rect.center()));
+        if listener.visible != Some(visible) {
+            listener.visible = Some(visible);
+            commands.trigger_targets(ViewportVisibilityChange(visible), entity);
+        }
+    }
+}
+
+fn ready_deferred_on_spawns(mut data: Query<&mut OnSpawnDeferred>) {
+    for mut on_spawn_deferred in data.iter_mut() {
+        on_spawn_deferred.ready = true;
+    }
+}
+
+fn run_deferred_on_spawns(world: &mut World) {
+    let ready = world
+        .query::<(Entity, &OnSpawnDeferred)>()
+        .iter(world)
+        .filter(|(_, on_spawn_deferred)| on_spawn_deferred.ready)
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    for entity in ready {
+        // an earlier callback may have despawned this entity
+        let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        if let Some(OnSpawnDeferred { fs, .. }) = entity_mut.take::<OnSpawnDeferred>() {
+            for f in fs {
+                f(world, entity);
+            }
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        (
+            size_change_dispatcher
+                .after(UiSystem::Layout)
+                .run_if(any_with_component::<SizeListener>),
+            global_rect_change_dispatcher
+                .after(TransformSystem::TransformPropagate)
+                .run_if(any_with_component::<GlobalRectListener>),
+            viewport_visibility_change_dispatcher
+                .after(update_clipping_system)
+                .run_if(any_with_component::<ViewportVisibilityListener>),
+            ready_deferred_on_spawns
+                .before(UiSystem::Layout)
+                .run_if(any_with_component::<OnSpawnDeferred>),
+            run_deferred_on_spawns
+                .after(UiSystem::Layout)
+                .run_if(any_with_component::<OnSpawnDeferred>),
+        ),
+    );
+}
@@ -13,20 +13,77 @@ use node_builder::init_async_world;
 
 pub mod raw;
 
+pub mod batching;
+pub mod channel;
+pub mod component_signal;
+pub mod computed;
+pub mod event_signal;
+pub mod future_signal;
+pub mod history;
+pub mod query_signal;
+pub mod resource_signal;
+pub mod signal_driver;
+pub mod signal_vec;
+pub mod store;
+pub mod time;
+
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+
+#[cfg(feature = "fetch")]
+pub mod fetch;
+
+#[cfg(feature = "persistence")]
+pub mod persistent;
+
+#[cfg(feature = "trace")]
+pub mod trace;
+
+#[cfg(feature = "profiling")]
+mod profiling;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "ui")] {
         pub mod align;
+        pub mod anchorable;
+        pub mod aspect_ratio;
         mod column;
+        pub mod columns;
         mod el;
         pub mod element;
+        pub mod feedback;
+        pub mod flexible;
+        pub mod focus;
+        pub mod gamepad;
+        pub mod gappable;
+        pub mod gesture;
         pub mod grid;
+        pub mod hotkey;
+        pub mod input_block;
+        pub mod keyboard;
+        pub mod keyboard_event_aware;
+        pub mod kinetic_scroll;
         pub mod pointer_event_aware;
         pub mod global_event_aware;
+        pub mod layout_aware;
         mod row;
+        pub mod scroll_snap;
         pub mod mouse_wheel_scrollable;
+        pub mod portal;
+        pub mod rem;
+        pub mod resizable;
+        pub mod responsive;
+        pub mod showable;
         pub mod sizeable;
+        pub mod spaceable;
+        pub mod spring;
         mod stack;
+        pub mod state;
+        pub mod table_layout;
+        pub mod tween;
         pub mod viewport_mutable;
+        pub mod window;
+        pub mod z_layer;
 
         cfg_if::cfg_if! {
             if #[cfg(feature = "text_input")] {
@@ -43,22 +100,67 @@ mod derive;
 pub mod utils;
 
 /// Includes the plugins and systems required for [haalka](crate) to function.
-pub struct HaalkaPlugin;
+#[derive(Default)]
+pub struct HaalkaPlugin {
+    /// Where [haalka](crate)'s futures, e.g. every element's signal tasks, are polled.
+    pub executor: signal_driver::SignalExecutor,
+}
 
 impl Plugin for HaalkaPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(AsyncEcsPlugin);
+        app.add_plugins((
+            AsyncEcsPlugin,
+            node_builder::plugin,
+            component_signal::plugin,
+            event_signal::plugin,
+            query_signal::plugin,
+            resource_signal::plugin,
+            time::plugin,
+        ));
         #[cfg(feature = "ui")]
         {
             app.add_plugins((
                 pointer_event_aware::plugin,
                 mouse_wheel_scrollable::plugin,
                 viewport_mutable::plugin,
+                responsive::plugin,
+                aspect_ratio::plugin,
+                z_layer::plugin,
+                layout_aware::plugin,
+                window::plugin,
+                anchorable::plugin,
+                table_layout::plugin,
+                scroll_snap::plugin,
+                kinetic_scroll::plugin,
+                rem::plugin,
+                columns::plugin,
+                showable::plugin,
+            ));
+            app.add_plugins((
+                keyboard::plugin,
+                gamepad::plugin,
+                focus::plugin,
+                keyboard_event_aware::plugin,
+                hotkey::plugin,
+                gesture::plugin,
+                input_block::plugin,
+                feedback::plugin,
             ));
         }
         #[cfg(feature = "text_input")]
         app.add_plugins(text_input::plugin);
 
+        #[cfg(feature = "clipboard")]
+        app.add_plugins(clipboard::plugin);
+
+        #[cfg(feature = "persistence")]
+        app.add_plugins(persistent::plugin);
+
+        #[cfg(feature = "trace")]
+        app.add_plugins(trace::plugin);
+
+        signal_driver::plugin(app, self.executor);
+
         app.add_systems(PreStartup, init_async_world);
     }
 }
@@ -67,11 +169,40 @@ impl Plugin for HaalkaPlugin {
 pub mod prelude {
     #[doc(inline)]
     pub use crate::{
+        batching::SignalBatchingPlugin,
+        channel::{channel_signal, channel_signal_vec},
+        component_signal::{component_signal_of, sync_component_of},
+        computed::Computed,
+        event_signal::{event_signal, event_stream},
+        future_signal::{future_signal, FutureState},
+        history::{History, VecHistory},
         node_builder::async_world,
+        query_signal::{query_signal, QuerySignalVec},
         raw::{RawElWrapper, RawElement, RawHaalkaEl, Spawnable},
+        resource_signal::{sync_resource, ResourceSignal, SyncedResource},
+        signal_driver::SignalExecutor,
+        signal_vec::{DiffLogger, DiffLoggerExt, LiveSignalVecExt, PatchSignalVecExt},
+        store::{store, Store, StoreAppExt},
+        time::{after_frames, delta_signal, every, frame_count_signal, next_frame, time_signal, ClockSignalExt},
         HaalkaPlugin,
     };
 
+    #[cfg(feature = "clipboard")]
+    #[doc(inline)]
+    pub use crate::clipboard::{copy, paste, SelectableText};
+
+    #[cfg(feature = "fetch")]
+    #[doc(inline)]
+    pub use crate::fetch::{fetch, fetch_request};
+
+    #[cfg(feature = "persistence")]
+    #[doc(inline)]
+    pub use crate::persistent::Persistent;
+
+    #[cfg(feature = "trace")]
+    #[doc(inline)]
+    pub use crate::trace::{SignalDiagnostics, TraceSignalExt};
+
     #[doc(no_inline)]
     pub use haalka_futures_signals_ext::*;
 
@@ -80,19 +211,63 @@ pub mod prelude {
             #[doc(inline)]
             pub use crate::{
                 align::{Align, Alignable},
+                anchorable::{Anchor, AnchorTarget, Anchorable},
+                aspect_ratio::AspectRatio,
                 column::Column,
+                columns::Columns,
                 el::El,
-                element::{Element, ElementWrapper, Nameable, TypeEraseable, UiRoot, UiRootable},
+                element::{
+                    spawn_ui_root, spawn_ui_root_with_camera, Element, ElementWrapper, Nameable, TypeEraseable,
+                    UiRoot, UiRootable,
+                },
+                flexible::Flexible,
+                feedback::{
+                    ActiveFeedbackProvider, Feedback, FeedbackProvider, FeedbackTrigger, Feedbackable, Rumble,
+                    RumbleFeedbackProvider,
+                },
+                focus::{
+                    FocusNavigationDisabled, FocusOnPressDisabled, FocusRing, FocusSystem, FocusVisible, Focusable,
+                    FocusedElement, KeyboardFocusable,
+                },
+                gamepad::{gamepad_axis_signal, gamepad_button_signal},
+                gappable::Gappable,
+                gesture::{GestureAware, Pinch, Swipe, SwipeDirection, TouchPoint, TouchPointChange, TwoFingerPan},
                 global_event_aware::GlobalEventAware,
                 grid::Grid,
+                hotkey::{Hotkey, HotkeySequence, HOTKEY_SEQUENCE_TIMEOUT},
+                input_block::InputBlock,
+                keyboard::{key_pressed_signal, modifiers_signal, Modifiers},
+                keyboard_event_aware::{FocusedKeyboardInput, KeyboardEventAware},
+                kinetic_scroll::KineticScrollable,
+                layout_aware::LayoutAware,
                 mouse_wheel_scrollable::{
-                    BasicScrollHandler, MouseWheelScrollable, OnHoverMouseWheelScrollable, ScrollDirection,
+                    BasicScrollHandler, MouseWheelScrollable, OnHoverMouseWheelScrollable, ScrollDelta,
+                    ScrollDirection, ScrollPropagation, Scrollbar,
+                },
+                pointer_event_aware::{
+                    CursorIconController, CursorOnHoverDisabled, CursorOnHoverable, DoubleClickSettings, DragData,
+                    PointerEventAware, SetCursor,
                 },
-                pointer_event_aware::{SetCursor, CursorOnHoverDisabled, CursorOnHoverable, PointerEventAware},
+                portal::Portal,
+                rem::{rem_size_signal, Rem, RemSize},
+                resizable::{Resizable, ResizeEdges, Resized},
+                responsive::{breakpoint_signal, responsive, Breakpoint, Breakpoints},
                 row::Row,
+                scroll_snap::{ScrollSnappable, SnapAlign},
+                showable::Showable,
                 sizeable::Sizeable,
+                spaceable::{Edges, Spaceable},
+                spring::spring,
                 stack::Stack,
+                state::state_signal,
+                table_layout::TableLayout,
+                tween::{tween, tween_signal, Tweenable},
                 viewport_mutable::{LimitToBody, ViewportMutable},
+                window::{
+                    cursor_position_signal, scale_factor_signal, ui_scale_sync, window_focused_signal,
+                    window_occluded_signal, window_size_signal,
+                },
+                z_layer::{ZLayer, ZLayerable, ZLayers},
             };
 
             pub use bevy_window::SystemCursorIcon;
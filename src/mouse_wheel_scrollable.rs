@@ -2,21 +2,33 @@
 //! mouse wheel events.
 
 use super::{
-    pointer_event_aware::PointerEventAware,
-    raw::{observe, register_system, utils::remove_system_holder_on_remove},
+    el::El,
+    input_block::InputBlocked,
+    keyboard::{modifiers, Modifiers},
+    pointer_event_aware::{DragData, Hovered, PointerEventAware},
+    raw::{observe, register_system, utils::remove_system_holder_on_remove, DeferredUpdaterAppendDirection},
+    tween::tween_signal,
     utils::{clone, spawn},
-    viewport_mutable::{firstborn, ViewportMutable, ViewportMutation},
+    viewport_mutable::{
+        firstborn, MutableViewport, Scene, Viewport, ViewportAnimation, ViewportMutable, ViewportMutation,
+    },
 };
 use apply::Apply;
 use bevy_app::prelude::*;
+use bevy_color::prelude::*;
 use bevy_ecs::prelude::*;
-use bevy_hierarchy::Children;
+use bevy_hierarchy::prelude::*;
 use bevy_input::{mouse::*, prelude::*};
+use bevy_math::{curve::EaseFunction, Vec2};
+use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
-use bevy_utils::prelude::*;
-use futures_signals::signal::{always, BoxSignal, Mutable, Signal, SignalExt};
+use bevy_utils::{prelude::*, HashMap, HashSet};
+use futures_signals::{
+    map_ref,
+    signal::{always, BoxSignal, Mutable, Signal, SignalExt},
+};
 use haalka_futures_signals_ext::{SignalExtBool, SignalExtExt};
-use std::convert::Into;
+use std::{convert::Into, time::Duration};
 
 /// Marker [`Component`] that disables an element's viewport from reacting to mouse wheel events.
 #[derive(Component, Default)]
@@ -25,6 +37,21 @@ pub struct ScrollDisabled;
 #[derive(Component)]
 struct ScrollEnabled;
 
+/// How [`MouseWheel`] events are shared between nested [`MouseWheelScrollable`] elements; only
+/// the innermost enabled scrollable receives an event unless it propagates it to its nearest
+/// enabled scrollable ancestor. Scrollables which track whether they are hovered, e.g. those using
+/// [`OnHoverMouseWheelScrollable`], are skipped while the pointer is outside them, so the event
+/// starts at the innermost hovered scrollable.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ScrollPropagation {
+    /// Propagate to the ancestor once this element's viewport can no longer scroll in the
+    /// direction of the event, i.e. it has reached its scroll limit.
+    #[default]
+    AtLimit,
+    /// Never propagate to the ancestor, even at the scroll limit.
+    Stop,
+}
+
 /// Enables an element's viewport to be modified and react to mouse wheel events.
 pub trait MouseWheelScrollable: ViewportMutable {
     /// When this element receives a [`MouseWheel`] event, if it does not have a `Disabled`
@@ -99,6 +126,22 @@ pub trait MouseWheelScrollable: ViewportMutable {
         self.on_scroll_disableable::<ScrollDisabled>(handler)
     }
 
+    /// Set whether [`MouseWheel`] events continue to this element's nearest scrollable ancestor
+    /// once its viewport reaches its scroll limit, see [`ScrollPropagation`]; defaults to
+    /// [`ScrollPropagation::AtLimit`].
+    fn scroll_propagation(self, propagation: ScrollPropagation) -> Self {
+        self.update_raw_el(|raw_el| raw_el.insert(propagation))
+    }
+
+    /// Reactively set whether [`MouseWheel`] events continue to this element's nearest scrollable
+    /// ancestor once its viewport reaches its scroll limit, see [`ScrollPropagation`].
+    fn scroll_propagation_signal<S: Signal<Item = ScrollPropagation> + Send + 'static>(
+        self,
+        propagation_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        self.update_raw_el(|raw_el| raw_el.component_signal::<ScrollPropagation, _>(propagation_signal_option))
+    }
+
     /// When this element receives a [`MouseWheel`] event, run a function with the [`MouseWheel`],
     /// reactively controlling whether the handling is disabled with a [`Signal`]. This method can
     /// be called repeatedly to register many such handlers.
@@ -154,26 +197,321 @@ pub trait OnHoverMouseWheelScrollable: MouseWheelScrollable + PointerEventAware
     fn on_scroll_on_hover(self, mut handler: impl FnMut(MouseWheel) + Send + Sync + 'static) -> Self {
         self.on_scroll_with_system_on_hover::<_>(move |In((_, mouse_wheel))| handler(mouse_wheel))
     }
+
+    /// Render a [`Scrollbar`] track and thumb along the edges of this element's [`Viewport`] for
+    /// each of its [`ScrollDirection`]s with overflowing content; dragging the thumb scrolls the
+    /// viewport. Requires [`.mutable_viewport(...)`](ViewportMutable::mutable_viewport).
+    fn scrollbar(self, scrollbar: Scrollbar) -> Self {
+        let location = Mutable::new((Scene::default(), Viewport::default()));
+        let hovered = Mutable::new(false);
+        self.on_viewport_location_change(clone!((location) move |scene, viewport| location.set((scene, viewport))))
+            .hovered_sync(hovered.clone())
+            .update_raw_el(move |raw_el| {
+                // runs after [`ViewportMutable::mutable_viewport`] wraps this element in its [`Viewport`]
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |mut raw_el| {
+                    let axes = match scrollbar.direction {
+                        ScrollDirection::Horizontal => vec![ScrollDirection::Horizontal],
+                        ScrollDirection::Vertical => vec![ScrollDirection::Vertical],
+                        ScrollDirection::Both => vec![ScrollDirection::Vertical, ScrollDirection::Horizontal],
+                    };
+                    for axis in axes {
+                        raw_el = raw_el.child(scrollbar_track(axis, &scrollbar, location.clone(), hovered.clone()));
+                    }
+                    raw_el
+                })
+            })
+    }
+
+    /// When this element receives a [`MouseWheel`] event while it is hovered, run a function with
+    /// the [`ScrollDelta`] and the held [`Modifiers`], e.g. for Ctrl+wheel zooming or Shift+wheel
+    /// horizontal scrolling. Unlike
+    /// [`.on_scroll_on_hover`](OnHoverMouseWheelScrollable::on_scroll_on_hover), this does not
+    /// affect whether the element's viewport scrolls. This method can be called repeatedly to
+    /// register many such handlers.
+    fn on_scroll_with_modifiers(self, mut handler: impl FnMut(ScrollDelta, Modifiers) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| raw_el.insert((PickingBehavior::default(), Hovered(false))))
+            .on_scroll_with_system(
+                move |In((entity, mouse_wheel)): In<(Entity, MouseWheel)>,
+                      hovereds: Query<&Hovered>,
+                      keys: Res<ButtonInput<KeyCode>>| {
+                    if hovereds.get(entity).is_ok_and(|hovered| **hovered) {
+                        handler(ScrollDelta::from(mouse_wheel), modifiers(&keys));
+                    }
+                },
+            )
+    }
 }
 
 impl<T: PointerEventAware + MouseWheelScrollable> OnHoverMouseWheelScrollable for T {}
 
+/// How long a fading [`Scrollbar`] takes to appear or disappear.
+pub const SCROLLBAR_FADE_DURATION: Duration = Duration::from_millis(200);
+
+/// Appearance of the scrollbar rendered by
+/// [`.scrollbar(...)`](OnHoverMouseWheelScrollable::scrollbar).
+#[derive(Clone, Copy, Debug)]
+pub struct Scrollbar {
+    /// Axes to render a scrollbar along.
+    pub direction: ScrollDirection,
+    /// Width of a vertical scrollbar or height of a horizontal one, in logical pixels.
+    pub thickness: f32,
+    #[allow(missing_docs)]
+    pub track_color: Color,
+    #[allow(missing_docs)]
+    pub thumb_color: Color,
+    /// Whether the scrollbar is only shown while the element is hovered or the thumb is dragged,
+    /// fading in and out over [`SCROLLBAR_FADE_DURATION`].
+    pub fade: bool,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self {
+            direction: ScrollDirection::Vertical,
+            thickness: 8.,
+            track_color: Color::srgba(0., 0., 0., 0.1),
+            thumb_color: Color::srgba(0., 0., 0., 0.4),
+            fade: true,
+        }
+    }
+}
+
+fn scrollbar_track(
+    axis: ScrollDirection,
+    &Scrollbar {
+        thickness,
+        track_color,
+        thumb_color,
+        fade,
+        ..
+    }: &Scrollbar,
+    location: Mutable<(Scene, Viewport)>,
+    hovered: Mutable<bool>,
+) -> El<Node> {
+    let vertical = matches!(axis, ScrollDirection::Vertical);
+    let track_hovered = Mutable::new(false);
+    let dragging = Mutable::new(false);
+    let opacity = || {
+        tween_signal(
+            map_ref! {
+                let &hovered = hovered.signal(),
+                let &track_hovered = track_hovered.signal(),
+                let &dragging = dragging.signal() => !fade || hovered || track_hovered || dragging
+            }
+            .map_bool(|| 1., || 0.),
+            SCROLLBAR_FADE_DURATION,
+            EaseFunction::QuadraticInOut,
+        )
+    };
+    let faded = move |color: Color, opacity: f32| BackgroundColor(color.with_alpha(color.alpha() * opacity));
+    let thumb = El::<Node>::new()
+        .with_node(move |mut node| {
+            node.position_type = PositionType::Absolute;
+            if vertical {
+                node.width = Val::Percent(100.);
+            } else {
+                node.height = Val::Percent(100.);
+            }
+        })
+        .on_signal_with_node(location.signal(), move |mut node, (scene, viewport)| {
+            let (scene_length, viewport_length, offset) = if vertical {
+                (scene.height, viewport.height, viewport.y)
+            } else {
+                (scene.width, viewport.width, viewport.x)
+            };
+            if scene_length <= 0. {
+                return;
+            }
+            let (length, start) = (viewport_length / scene_length * 100., offset / scene_length * 100.);
+            if vertical {
+                (node.height, node.top) = (Val::Percent(length), Val::Percent(start));
+            } else {
+                (node.width, node.left) = (Val::Percent(length), Val::Percent(start));
+            }
+        })
+        .background_color_signal(opacity().map(move |opacity| faded(thumb_color, opacity)))
+        .update_raw_el(clone!((dragging) move |raw_el| {
+            raw_el
+                .on_event::<Pointer<DragStart>>(clone!((dragging) move |_| dragging.set_neq(true)))
+                .on_event::<Pointer<DragEnd>>(move |_| dragging.set_neq(false))
+        }))
+        .on_drag_with_system(clone!((location) move |In((thumb, drag)): In<(Entity, DragData)>,
+              parents: Query<&Parent>,
+              children: Query<&Children>,
+              nodes: Query<&Node>,
+              mut commands: Commands| {
+            // the thumb's track is a child of the [`Viewport`], whose firstborn is the [`Scene`]
+            let Some(scene) = parents
+                .iter_ancestors(thumb)
+                .nth(1)
+                .and_then(|viewport| firstborn(viewport, &children).copied())
+            else {
+                return;
+            };
+            let Ok(node) = nodes.get(scene) else { return };
+            let (scene, viewport) = location.get();
+            let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+            // moving the thumb by its track's length scrolls the whole scene
+            let mutation = if vertical {
+                ViewportMutation::y(px(node.top) - drag.delta.y * scene.height / viewport.height.max(1.))
+            } else {
+                ViewportMutation::x(px(node.left) - drag.delta.x * scene.width / viewport.width.max(1.))
+            };
+            commands.trigger_targets(mutation, scene);
+        }));
+    El::<Node>::new()
+        .with_node(move |mut node| {
+            node.position_type = PositionType::Absolute;
+            if vertical {
+                (node.right, node.top, node.bottom) = (Val::ZERO, Val::ZERO, Val::ZERO);
+                node.width = Val::Px(thickness);
+            } else {
+                (node.bottom, node.left, node.right) = (Val::ZERO, Val::ZERO, Val::ZERO);
+                node.height = Val::Px(thickness);
+            }
+        })
+        .on_signal_with_node(location.signal(), move |mut node, (scene, viewport)| {
+            let overflowing = if vertical {
+                scene.height > viewport.height
+            } else {
+                scene.width > viewport.width
+            };
+            node.display = if overflowing { Display::Flex } else { Display::None };
+        })
+        .background_color_signal(opacity().map(move |opacity| faded(track_color, opacity)))
+        .hovered_sync(track_hovered)
+        .child(thumb)
+}
+
+// nearest ancestor of `entity` in `listeners`
+fn scroll_parent(entity: Entity, listeners: &HashSet<Entity>, parents: &Query<&Parent>) -> Option<Entity> {
+    parents
+        .iter_ancestors(entity)
+        .find(|ancestor| listeners.contains(ancestor))
+}
+
+// whether the viewport of `entity` can still move in the direction of `mouse_wheel`; elements
+// without a [`MutableViewport`] always can
+fn can_scroll(
+    entity: Entity,
+    mouse_wheel: &MouseWheel,
+    shift: bool,
+    parents: &Query<&Parent>,
+    nodes: &Query<(&Node, &ComputedNode, Option<&ViewportAnimation>), With<MutableViewport>>,
+    computed_nodes: &Query<&ComputedNode>,
+) -> bool {
+    let Ok((node, computed_node, animation_option)) = nodes.get(entity) else {
+        return true;
+    };
+    let Some(viewport) = parents
+        .get(entity)
+        .ok()
+        .and_then(|parent| computed_nodes.get(parent.get()).ok())
+    else {
+        return true;
+    };
+    let delta = if mouse_wheel.y != 0. {
+        mouse_wheel.y
+    } else {
+        mouse_wheel.x
+    };
+    let overflow = (computed_node.size() - viewport.size()).max(Vec2::ZERO);
+    let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+    // in progress smooth scrolls count as having already arrived, so quickly repeated events
+    // propagate as soon as the limit will be reached
+    let offset = animation_option
+        .map(|animation| animation.to)
+        .unwrap_or_else(|| Vec2::new(px(node.left), px(node.top)));
+    // the axis the event will scroll, horizontal if it's the only one that overflows or if the
+    // event is explicitly horizontal
+    let (offset, overflow) = if overflow.x > 0. && (overflow.y == 0. || shift || mouse_wheel.y == 0.) {
+        (offset.x, overflow.x)
+    } else {
+        (offset.y, overflow.y)
+    };
+    // offsets range from `-overflow` to `0`, and positive deltas move them towards `0`
+    overflow > 0. && if delta > 0. { offset < 0. } else { offset > -overflow }
+}
+
 fn scroll_system(
     mut mouse_wheel_events: EventReader<MouseWheel>,
     scroll_listeners: Query<Entity, With<ScrollEnabled>>,
+    propagations: Query<&ScrollPropagation>,
+    hovereds: Query<&Hovered>,
+    parents: Query<&Parent>,
+    nodes: Query<(&Node, &ComputedNode, Option<&ViewportAnimation>), With<MutableViewport>>,
+    computed_nodes: Query<&ComputedNode>,
+    keys: Res<ButtonInput<KeyCode>>,
+    input_blocked: InputBlocked,
     mut commands: Commands,
 ) {
-    let listeners = scroll_listeners.iter().collect::<Vec<_>>();
+    // listeners which track hover only take part while hovered, so events start at the innermost
+    // hovered listener and bubble out from there
+    let listeners = scroll_listeners
+        .iter()
+        .filter(|&listener| !input_blocked.blocked(listener))
+        .filter(|&listener| hovereds.get(listener).map(|hovered| **hovered).unwrap_or(true))
+        .collect::<HashSet<_>>();
+    let scroll_parents = listeners
+        .iter()
+        .map(|&listener| (listener, scroll_parent(listener, &listeners, &parents)))
+        .collect::<HashMap<_, _>>();
+    // innermost listeners, which receive every event first
+    let leaves = listeners
+        .iter()
+        .copied()
+        .filter(|listener| !scroll_parents.values().any(|parent| parent.as_ref() == Some(listener)))
+        .collect::<Vec<_>>();
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
     for &event in mouse_wheel_events.read() {
-        commands.trigger_targets(event, listeners.clone());
+        let mut targets = HashSet::new();
+        for &leaf in &leaves {
+            let mut current = leaf;
+            loop {
+                if !targets.insert(current) {
+                    break;
+                }
+                if matches!(propagations.get(current), Ok(ScrollPropagation::Stop))
+                    || can_scroll(current, &event, shift, &parents, &nodes, &computed_nodes)
+                {
+                    break;
+                }
+                match scroll_parents.get(&current).copied().flatten() {
+                    Some(parent) => current = parent,
+                    None => break,
+                }
+            }
+        }
+        commands.trigger_targets(event, targets.into_iter().collect::<Vec<_>>());
+    }
+}
+
+/// Amount scrolled by a [`MouseWheel`] event, in the units reported by the device; mouse wheels
+/// usually scroll in lines, while touchpads usually scroll in pixels.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ScrollDelta {
+    /// Number of lines or rows scrolled.
+    Lines(Vec2),
+    /// Number of logical pixels scrolled.
+    Pixels(Vec2),
+}
+
+impl From<MouseWheel> for ScrollDelta {
+    fn from(MouseWheel { unit, x, y, .. }: MouseWheel) -> Self {
+        match unit {
+            MouseScrollUnit::Line => ScrollDelta::Lines(Vec2::new(x, y)),
+            MouseScrollUnit::Pixel => ScrollDelta::Pixels(Vec2::new(x, y)),
+        }
     }
 }
 
 #[allow(missing_docs)]
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ScrollDirection {
     Horizontal,
     Vertical,
+    /// Both axes at once, e.g. with touchpads, while mouse wheels scroll horizontally when shift is
+    /// held.
     Both,
 }
 
@@ -183,6 +521,7 @@ pub enum ScrollDirection {
 pub struct BasicScrollHandler {
     direction: Option<BoxSignal<'static, ScrollDirection>>,
     magnitude: Option<BoxSignal<'static, f32>>,
+    smooth: Option<(Duration, EaseFunction)>,
 }
 
 const DEFAULT_SCROLL_DIRECTION: ScrollDirection = ScrollDirection::Vertical;
@@ -234,6 +573,13 @@ impl BasicScrollHandler {
         self
     }
 
+    /// Animate viewport movement in response to mouse wheel events over `duration`, according to
+    /// the [`EaseFunction`], rather than jumping; successive events extend the animation's target.
+    pub fn smooth(mut self, duration: Duration, easing: EaseFunction) -> Self {
+        self.smooth = Some((duration, easing));
+        self
+    }
+
     // TODO: is there a better return type for this ?
     /// Convert this [`BasicScrollHandler`] into a function that can be passed as a handler to
     /// `on_scroll_...` methods.
@@ -246,6 +592,7 @@ impl BasicScrollHandler {
         let BasicScrollHandler {
             direction: direction_signal_option,
             magnitude: magnitude_signal_option,
+            smooth,
         } = self;
         let direction = Mutable::new(DEFAULT_SCROLL_DIRECTION);
         let magnitude = Mutable::new(DEFAULT_SCROLL_MAGNITUDE);
@@ -269,28 +616,48 @@ impl BasicScrollHandler {
                       nodes: Query<&Node>,
                       keys: Res<ButtonInput<KeyCode>>,
                       mut commands: Commands| {
-            let dy = if mouse_wheel.y.is_sign_negative() { -1. } else { 1. } * magnitude.get();
-            if let Ok(node) = nodes.get(entity) {
-                let direction = direction.get();
-                if matches!(direction, ScrollDirection::Vertical)
-                    || matches!(direction, ScrollDirection::Both)
-                        && !(keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight))
-                {
-                    let top = match node.top {
-                        Val::Px(top) => top,
-                        _ => 0.,
-                    };
-                    commands.trigger_targets(ViewportMutation::y(top + dy), entity);
-                } else if matches!(direction, ScrollDirection::Horizontal)
-                    || matches!(direction, ScrollDirection::Both)
-                        && (keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight))
-                {
-                    let left = match node.left {
-                        Val::Px(left) => left,
-                        _ => 0.,
-                    };
-                    commands.trigger_targets(ViewportMutation::x(left + dy), entity);
+            let Ok(node) = nodes.get(entity) else {
+                return;
+            };
+            let magnitude = magnitude.get();
+            let step = |delta: f32| if delta == 0. { 0. } else { delta.signum() * magnitude };
+            // mouse wheels only report vertical deltas, so they scroll horizontally when that's the
+            // only option or shift is held, while touchpads report both deltas at once
+            let wheel = if mouse_wheel.y != 0. {
+                mouse_wheel.y
+            } else {
+                mouse_wheel.x
+            };
+            let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+            let Vec2 { x: dx, y: dy } = match direction.get() {
+                ScrollDirection::Vertical => Vec2::new(0., step(mouse_wheel.y)),
+                ScrollDirection::Horizontal => Vec2::new(step(wheel), 0.),
+                ScrollDirection::Both if shift => Vec2::new(step(wheel), 0.),
+                ScrollDirection::Both => Vec2::new(step(mouse_wheel.x), step(mouse_wheel.y)),
+            };
+            let mut mutation = ViewportMutation::default();
+            let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+            match smooth {
+                Some((duration, easing)) => {
+                    mutation = mutation.relative().smooth(duration, easing);
+                    if dx != 0. {
+                        mutation = mutation.with_x(dx);
+                    }
+                    if dy != 0. {
+                        mutation = mutation.with_y(dy);
+                    }
                 }
+                None => {
+                    if dx != 0. {
+                        mutation = mutation.with_x(px(node.left) + dx);
+                    }
+                    if dy != 0. {
+                        mutation = mutation.with_y(px(node.top) + dy);
+                    }
+                }
+            }
+            if dx != 0. || dy != 0. {
+                commands.trigger_targets(mutation, entity);
             }
         };
         Box::new(f)
@@ -1,16 +1,25 @@
 //! Low level reactive entity management ported from [Dominator](https://github.com/Pauan/rust-dominator)'s [`DomBuilder`](https://docs.rs/dominator/latest/dominator/struct.DomBuilder.html).
 
-use std::sync::{Arc, Mutex, OnceLock};
+use std::{
+    collections::VecDeque,
+    future::poll_fn,
+    pin::Pin,
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll},
+    time::Duration,
+};
 
 use super::utils::{clone, spawn};
 use apply::Apply;
+use bevy_app::prelude::*;
 use bevy_async_ecs::AsyncWorld;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, world::Command};
 use bevy_hierarchy::prelude::*;
 use bevy_tasks::Task;
-use bevy_utils::prelude::*;
+use bevy_utils::{prelude::*, HashMap, HashSet, Instant};
 use futures_signals::{
-    signal::{Mutable, Signal, SignalExt},
+    signal::{Mutable, MutableSignal, Signal, SignalExt},
+    signal_map::{MapDiff, SignalMap, SignalMapExt},
     signal_vec::{MutableVec, SignalVec, SignalVecExt, VecDiff},
 };
 use haalka_futures_signals_ext::{Future, MutableExt};
@@ -36,8 +45,13 @@ pub(crate) fn init_async_world(world: &mut World) {
 pub struct NodeBuilder {
     #[allow(clippy::type_complexity)]
     on_spawns: Vec<Box<dyn FnOnce(&mut World, Entity) + Send>>,
-    task_wrappers: Vec<Box<dyn FnOnce(Entity) -> Task<()> + Send>>,
-    child_block_populations: MutableVec<usize>,
+    task_wrappers: Vec<Box<dyn FnOnce(Entity) -> TaskFuture + Send>>,
+    paused: Option<Mutable<bool>>,
+    children_spawn_budget: Option<(Duration, Option<Mutable<bool>>)>,
+    #[cfg(feature = "profiling")]
+    profiling_name: Option<std::borrow::Cow<'static, str>>,
+    // number of child blocks, i.e. `.child...` calls, declared so far, see [`ChildOrder`]
+    child_blocks: usize,
 }
 
 impl<T: Bundle> From<T> for NodeBuilder {
@@ -47,6 +61,37 @@ impl<T: Bundle> From<T> for NodeBuilder {
 }
 
 impl NodeBuilder {
+    fn next_child_block(&mut self) -> usize {
+        self.child_blocks += 1;
+        self.child_blocks - 1
+    }
+
+    /// Don't poll this node's reactive tasks, e.g. those of [`.on_signal`](Self::on_signal) and
+    /// [`.child_signal`](Self::child_signal), while `paused` is `true`; since [`Signal`]s only
+    /// output their latest value, changes made while paused are processed once upon resuming.
+    pub fn pause_signals(mut self, paused: Mutable<bool>) -> Self {
+        self.paused = Some(paused);
+        self
+    }
+
+    /// Tag this node's profiling spans with `name`, see [`Nameable::name`](super::element::Nameable::name).
+    #[cfg(feature = "profiling")]
+    pub fn profiling_name(mut self, name: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        self.profiling_name = Some(name.into());
+        self
+    }
+
+    /// Spread spawning the children of [`.children`](Self::children) and
+    /// [`.children_signal_vec`](Self::children_signal_vec) across frames, spending at most about
+    /// `budget` on them per frame, e.g. so a [`VecDiff::Replace`] with thousands of children doesn't
+    /// hitch. Children are still inserted into the hierarchy immediately, as empty entities which are
+    /// filled in once their turn comes. If provided, `spawned` is set to `false` while children are
+    /// waiting to be spawned and to `true` once all of them have been.
+    pub fn children_spawn_budget(mut self, budget: Duration, spawned: impl Into<Option<Mutable<bool>>>) -> Self {
+        self.children_spawn_budget = Some((budget, spawned.into()));
+        self
+    }
+
     /// Run a function with mutable access to the [`World`] and this node's [`Entity`].
     pub fn on_spawn(mut self, on_spawn: impl FnOnce(&mut World, Entity) + Send + 'static) -> Self {
         self.on_spawns.push(Box::new(on_spawn));
@@ -77,7 +122,16 @@ impl NodeBuilder {
         mut f: impl FnMut(Entity, T) -> Fut + Send + 'static,
     ) -> Self {
         self.task_wrappers.push(Box::new(move |entity: Entity| {
-            signal.for_each(move |value| f(entity, value)).apply(spawn)
+            signal
+                .for_each(move |value| {
+                    #[cfg(feature = "trace")]
+                    let start = bevy_utils::Instant::now();
+                    let future = f(entity, value);
+                    #[cfg(feature = "trace")]
+                    let future = super::trace::timed_handler::<T>(start, future);
+                    future
+                })
+                .apply(boxed)
         }));
         self
     }
@@ -85,12 +139,11 @@ impl NodeBuilder {
     // TODO: list out limitations; limitation: if multiple children are added to entity, they must
     // be registered thru this abstraction because of the way siblings are tracked
     /// Declare a static child.
-    pub fn child(self, child: NodeBuilder) -> Self {
-        let block = self.child_block_populations.lock_ref().len();
-        self.child_block_populations.lock_mut().push(1);
-        let offset = offset(block, &self.child_block_populations.lock_ref());
+    pub fn child(mut self, child: NodeBuilder) -> Self {
+        let block = self.next_child_block();
         let on_spawn = move |world: &mut World, parent| {
-            let child_entity = world.spawn_empty().id();
+            let child_entity = world.spawn(ChildOrder { block, index: 0 }).id();
+            let offset = block_offset(world, parent, block);
             if let Ok(ref mut parent) = world.get_entity_mut(parent) {
                 // need to call like this to avoid type ambiguity
                 EntityWorldMut::insert_children(parent, offset, &[child_entity]);
@@ -110,25 +163,23 @@ impl NodeBuilder {
         mut self,
         child_option: impl Signal<Item = impl Into<Option<NodeBuilder>> + Send> + Send + 'static,
     ) -> Self {
-        let block = self.child_block_populations.lock_ref().len();
-        self.child_block_populations.lock_mut().push(0);
-        let child_block_populations = self.child_block_populations.clone();
+        let block = self.next_child_block();
         let task_wrapper = move |entity: Entity| {
             let existing_child_option = Mutable::new(None);
             clone!((entity => parent) async move {
                 child_option.for_each(move |child_option| {
-                    clone!((existing_child_option, child_block_populations) async move {
+                    clone!((existing_child_option) async move {
                         if let Some(child) = child_option.into() {
-                            async_world().apply(move |world: &mut World| {
+                            async_world().apply(profiled("child_signal", move |world: &mut World| {
                                 if let Some(existing_child) = existing_child_option.take() {
                                     if let Ok(entity) = world.get_entity_mut(existing_child) {
                                         // need to call like this to avoid type ambiguity
                                         EntityWorldMut::despawn_recursive(entity);  // removes from parent
                                     }
                                 }
-                                let child_entity = world.spawn_empty().id();
+                                let child_entity = world.spawn(ChildOrder { block, index: 0 }).id();
+                                let offset = block_offset(world, parent, block);
                                 if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                    let offset = offset(block, &child_block_populations.lock_ref());
                                     parent.insert_children(offset, &[child_entity]);
                                     child.spawn_on_entity(world, child_entity);
                                     existing_child_option.set(Some(child_entity));
@@ -137,45 +188,40 @@ impl NodeBuilder {
                                         child.despawn_recursive();
                                     }
                                 }
-                                child_block_populations.lock_mut().set(block, 1);
-                            }).await;
+                            })).await;
                         } else {
-                            async_world().apply(move |world: &mut World| {
+                            async_world().apply(profiled("child_signal", move |world: &mut World| {
                                 if let Some(existing_child) = existing_child_option.take() {
                                     if let Ok(entity) = world.get_entity_mut(existing_child) {
                                         entity.despawn_recursive();
                                     }
                                 }
-                                child_block_populations.lock_mut().set(block, 0);
-                            })
+                            }))
                             .await;
                         }
                     })
                 }).await;
             })
-            .apply(spawn)
+            .apply(boxed)
         };
         self.task_wrappers.push(Box::new(task_wrapper));
         self
     }
 
     /// Declare static children.
-    pub fn children(self, children: impl IntoIterator<Item = NodeBuilder> + Send + 'static) -> Self {
-        let block = self.child_block_populations.lock_ref().len();
+    pub fn children(mut self, children: impl IntoIterator<Item = NodeBuilder> + Send + 'static) -> Self {
+        let block = self.next_child_block();
         let children = children.into_iter().collect::<Vec<_>>();
-        let population = children.len();
-        self.child_block_populations.lock_mut().push(population);
-        let child_block_populations = self.child_block_populations.clone();
-        let offset = offset(block, &child_block_populations.lock_ref());
         let on_spawn = move |world: &mut World, parent: Entity| {
             let mut children_entities = vec![];
-            for _ in 0..children.len() {
-                children_entities.push(world.spawn_empty().id());
+            for index in 0..children.len() {
+                children_entities.push(world.spawn(ChildOrder { block, index }).id());
             }
+            let offset = block_offset(world, parent, block);
             if let Ok(mut parent) = world.get_entity_mut(parent) {
                 parent.insert_children(offset, &children_entities);
                 for (child, child_entity) in children.into_iter().zip(children_entities) {
-                    child.spawn_on_entity(world, child_entity);
+                    spawn_child(world, child, child_entity);
                 }
             } else {
                 // parent despawned during child spawning
@@ -189,182 +235,160 @@ impl NodeBuilder {
         self.on_spawn(on_spawn)
     }
 
-    /// Declare reactive children.
+    /// Declare reactive children. All [`VecDiff`]s output in the same frame are applied together
+    /// with a single [`World`] access. [`VecDiff::UpdateAt`] spawns the new child on the [`Entity`]
+    /// of the one it replaces, rather than despawning it, so its place in the hierarchy and e.g.
+    /// focus are kept.
     pub fn children_signal_vec(
         mut self,
         children_signal_vec: impl SignalVec<Item = NodeBuilder> + Send + 'static,
     ) -> Self {
-        let block = self.child_block_populations.lock_ref().len();
-        self.child_block_populations.lock_mut().push(0);
-        let child_block_populations = self.child_block_populations.clone();
+        let block = self.next_child_block();
         let task_wrapper = move |entity: Entity| {
-            clone!((entity => parent) {
+            clone!((entity => parent) async move {
                 let children_entities = MutableVec::default();
-                children_signal_vec
-                .for_each(clone!((parent, children_entities, child_block_populations) move |diff| {
-                    clone!((parent, children_entities, child_block_populations) async move {
-                        // TODO: unit tests for every branch
+                let mut children_signal_vec = Box::pin(children_signal_vec);
+                let mut done = false;
+                while !done {
+                    // drain every ready diff, so bursts, e.g. many `Replace`s, don't each wait on the world
+                    let diffs = poll_fn(|cx| {
+                        let mut diffs = vec![];
+                        loop {
+                            match children_signal_vec.as_mut().poll_vec_change(cx) {
+                                Poll::Ready(Some(diff)) => diffs.push(diff),
+                                Poll::Ready(None) => {
+                                    done = true;
+                                    return Poll::Ready(diffs);
+                                }
+                                Poll::Pending => {
+                                    return if diffs.is_empty() { Poll::Pending } else { Poll::Ready(diffs) };
+                                }
+                            }
+                        }
+                    })
+                    .await;
+                    if diffs.is_empty() {
+                        continue;
+                    }
+                    async_world().apply(profiled("children_signal_vec", clone!((children_entities) move |world: &mut World| {
+                        for diff in diffs {
+                            apply_child_diff(world, parent, block, &children_entities, diff);
+                        }
+                    })))
+                    .await;
+                }
+            })
+            .apply(boxed)
+        };
+        self.task_wrappers.push(Box::new(task_wrapper));
+        self
+    }
+
+    /// Declare reactive children keyed by the keys of a [`SignalMap`], ordered by key. Each key
+    /// keeps its [`Entity`] until it is removed or its value is updated, regardless of the
+    /// insertion or removal of other keys.
+    pub fn children_signal_map<K: Ord + Send + 'static>(
+        mut self,
+        children_signal_map: impl SignalMap<Key = K, Value = NodeBuilder> + Send + 'static,
+    ) -> Self {
+        let block = self.next_child_block();
+        let task_wrapper = move |entity: Entity| {
+            clone!((entity => parent) {
+                // sorted by key, mirroring the order of the children
+                let children_entities = Arc::new(Mutex::new(Vec::<(K, Entity)>::new()));
+                children_signal_map
+                .for_each(clone!((parent, children_entities) move |diff| {
+                    clone!((parent, children_entities) async move {
                         match diff {
-                            VecDiff::Replace { values: children } => {
-                                async_world().apply(move |world: &mut World| {
-                                    let mut children_lock = children_entities.lock_mut();
-                                    for child in children_lock.drain(..) {
+                            MapDiff::Replace { entries } => {
+                                async_world().apply(profiled("children_signal_map", move |world: &mut World| {
+                                    let mut children_lock = children_entities.lock().unwrap();
+                                    for (_, child) in children_lock.drain(..) {
                                         if let Ok(child) = world.get_entity_mut(child) {
                                             // need to call like this to avoid type ambiguity
                                             EntityWorldMut::despawn_recursive(child);  // removes from parent
                                         }
                                     }
-                                    for _ in 0..children.len() {
-                                        children_lock.push(world.spawn_empty().id());
+                                    let mut entries = entries.into_iter().map(|(key, child)| (key, child, world.spawn_empty().id())).collect::<Vec<_>>();
+                                    entries.sort_by(|(a, ..), (b, ..)| a.cmp(b));
+                                    for (index, &(_, _, child_entity)) in entries.iter().enumerate() {
+                                        world.entity_mut(child_entity).insert(ChildOrder { block, index });
                                     }
+                                    let children = entries.iter().map(|&(_, _, child_entity)| child_entity).collect::<Vec<_>>();
+                                    let offset = block_offset(world, parent, block);
                                     if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                        let offset = offset(block, &child_block_populations.lock_ref());
-                                        parent.insert_children(offset, children_lock.as_slice());
-                                        for (child, child_entity) in children.into_iter().zip(children_lock.iter().copied()) {
+                                        parent.insert_children(offset, &children);
+                                        for (key, child, child_entity) in entries {
                                             child.spawn_on_entity(world, child_entity);
+                                            children_lock.push((key, child_entity));
                                         }
-                                        child_block_populations.lock_mut().set(block, children_lock.len());
                                     } else {  // parent despawned during child spawning
-                                        for entity in children_lock.drain(..) {
+                                        for entity in children {
                                             if let Ok(child) = world.get_entity_mut(entity) {
                                                 child.despawn_recursive();
                                             }
                                         }
                                     }
-                                })
-                                .await;
-                            }
-                            VecDiff::InsertAt { index, value: child } => {
-                                async_world().apply(move |world: &mut World| {
-                                    let child_entity = world.spawn_empty().id();
-                                    if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                        let offset = offset(block, &child_block_populations.lock_ref());
-                                        parent.insert_children(offset + index, &[child_entity]);
-                                        child.spawn_on_entity(world, child_entity);
-                                        let mut children_lock = children_entities.lock_mut();
-                                        children_lock.insert(index, child_entity);
-                                        child_block_populations.lock_mut().set(block, children_lock.len());
-                                    } else {  // parent despawned during child spawning
-                                        if let Ok(child) = world.get_entity_mut(child_entity) {
-                                            child.despawn_recursive();
-                                        }
-                                    }
-                                })
-                                .await;
-                            }
-                            VecDiff::Push { value: child } => {
-                                async_world().apply(move |world: &mut World| {
-                                    let child_entity = world.spawn_empty().id();
-                                    if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                        let mut children_lock = children_entities.lock_mut();
-                                        let offset = offset(block, &child_block_populations.lock_ref());
-                                        parent.insert_children(offset + children_lock.len(), &[child_entity]);
-                                        child.spawn_on_entity(world, child_entity);
-                                        children_lock.push(child_entity);
-                                        child_block_populations.lock_mut().set(block, children_lock.len());
-                                    } else {  // parent despawned during child spawning
-                                        if let Ok(child) = world.get_entity_mut(child_entity) {
-                                            child.despawn_recursive();
-                                        }
-                                    }
-                                })
+                                }))
                                 .await;
                             }
-                            VecDiff::UpdateAt { index, value: node } => {
-                                async_world().apply(move |world: &mut World| {
-                                    if let Some(existing_child) = children_entities.lock_ref().get(index).copied() {
-                                        if let Ok(child) = world.get_entity_mut(existing_child) {
-                                            child.despawn_recursive();  // removes from parent
+                            MapDiff::Insert { key, value: child } | MapDiff::Update { key, value: child } => {
+                                async_world().apply(profiled("children_signal_map", move |world: &mut World| {
+                                    let mut children_lock = children_entities.lock().unwrap();
+                                    let index = match children_lock.binary_search_by(|(existing, _)| existing.cmp(&key)) {
+                                        Ok(index) => {
+                                            let (_, existing_child) = children_lock.remove(index);
+                                            if let Ok(child) = world.get_entity_mut(existing_child) {
+                                                child.despawn_recursive();  // removes from parent
+                                            }
+                                            index
                                         }
-                                    }
-                                    let child_entity = world.spawn_empty().id();
+                                        Err(index) => index,
+                                    };
+                                    let child_entity = world.spawn(ChildOrder { block, index }).id();
+                                    let offset = block_offset(world, parent, block);
                                     if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                        children_entities.lock_mut().set(index, child_entity);
-                                        let offset = offset(block, &child_block_populations.lock_ref());
                                         parent.insert_children(offset + index, &[child_entity]);
-                                        node.spawn_on_entity(world, child_entity);
+                                        child.spawn_on_entity(world, child_entity);
+                                        children_lock.insert(index, (key, child_entity));
+                                        reindex(world, children_lock.iter().map(|&(_, child)| child), index + 1);
                                     } else {  // parent despawned during child spawning
                                         if let Ok(child) = world.get_entity_mut(child_entity) {
                                             child.despawn_recursive();
                                         }
                                     }
-                                })
-                                .await;
-                            }
-                            VecDiff::Move { old_index, new_index } => {
-                                async_world().apply(move |world: &mut World| {
-                                    let mut children_lock = children_entities.lock_mut();
-                                    children_lock.swap(old_index, new_index);
-                                    // porting the swap implementation above
-                                    fn move_from_to(parent: &mut EntityWorldMut, children_entities: &[Entity], old_index: usize, new_index: usize) {
-                                        if old_index != new_index {
-                                            if let Some(old_entity) = children_entities.get(old_index).copied() {
-                                                parent.remove_children(&[old_entity]);
-                                                parent.insert_children(new_index, &[old_entity]);
-                                            }
-                                        }
-                                    }
-                                    fn swap(parent: &mut EntityWorldMut, children_entities: &[Entity], a: usize, b: usize) {
-                                        move_from_to(parent, children_entities, a, b);
-                                        match a.cmp(&b) {
-                                            std::cmp::Ordering::Less => {
-                                                move_from_to(parent, children_entities, b - 1, a);
-                                            }
-                                            std::cmp::Ordering::Greater => {
-                                                move_from_to(parent, children_entities, b + 1, a)
-                                            }
-                                            _ => {}
-                                        }
-                                    }
-                                    if let Ok(mut parent) = world.get_entity_mut(parent) {
-                                        let offset = offset(block, &child_block_populations.lock_ref());
-                                        swap(&mut parent, children_lock.as_slice(), offset + old_index, offset + new_index);
-                                    }
-                                })
+                                }))
                                 .await;
                             }
-                            VecDiff::RemoveAt { index } => {
-                                async_world().apply(move |world: &mut World| {
-                                    let mut children_lock = children_entities.lock_mut();
-                                    if let Some(existing_child) = children_lock.get(index).copied() {
+                            MapDiff::Remove { key } => {
+                                async_world().apply(profiled("children_signal_map", move |world: &mut World| {
+                                    let mut children_lock = children_entities.lock().unwrap();
+                                    if let Ok(index) = children_lock.binary_search_by(|(existing, _)| existing.cmp(&key)) {
+                                        let (_, existing_child) = children_lock.remove(index);
                                         if let Ok(child) = world.get_entity_mut(existing_child) {
                                             child.despawn_recursive();  // removes from parent
                                         }
-                                        children_lock.remove(index);
-                                        child_block_populations.lock_mut().set(block, children_lock.len());
+                                        reindex(world, children_lock.iter().map(|&(_, child)| child), index);
                                     }
-                                })
+                                }))
                                 .await;
                             }
-                            VecDiff::Pop {} => {
-                                async_world().apply(move |world: &mut World| {
-                                    let mut children_lock = children_entities.lock_mut();
-                                    if let Some(child_entity) = children_lock.pop() {
+                            MapDiff::Clear {} => {
+                                async_world().apply(profiled("children_signal_map", move |world: &mut World| {
+                                    let mut children_lock = children_entities.lock().unwrap();
+                                    for (_, child_entity) in children_lock.drain(..) {
                                         if let Ok(child) = world.get_entity_mut(child_entity) {
                                             child.despawn_recursive();
                                         }
-                                        child_block_populations.lock_mut().set(block, children_lock.len());
                                     }
-                                })
-                                .await;
-                            }
-                            VecDiff::Clear {} => {
-                                async_world().apply(move |world: &mut World| {
-                                    let mut children_lock = children_entities.lock_mut();
-                                    for child_entity in children_lock.drain(..) {
-                                        if let Ok(child) = world.get_entity_mut(child_entity) {
-                                            child.despawn_recursive();
-                                        }
-                                    }
-                                    child_block_populations.lock_mut().set(block, children_lock.len());
-                                })
+                                }))
                                 .await;
                             }
                         }
                     })
                 }))
             })
-            .apply(spawn)
+            .apply(boxed)
         };
         self.task_wrappers.push(Box::new(task_wrapper));
         self
@@ -372,18 +396,39 @@ impl NodeBuilder {
 
     /// Spawn a node on an existing [`Entity`].
     pub fn spawn_on_entity(self, world: &mut World, entity: Entity) {
+        #[cfg(feature = "profiling")]
+        let _span = super::profiling::spawn_span(self.profiling_name.as_deref()).entered();
         if let Ok(mut entity) = world.get_entity_mut(entity) {
             let id = entity.id();
-            entity.insert(TaskHolder::new());
+            if self.child_blocks > 0 {
+                entity.insert(ChildBlockPopulations::new(self.child_blocks));
+            }
+            if let Some((budget, spawned)) = self.children_spawn_budget {
+                entity.insert(ChildSpawnBudget {
+                    budget,
+                    spawned,
+                    pending: default(),
+                });
+            }
             for on_spawn in self.on_spawns {
                 on_spawn(world, id);
             }
+            // static nodes, i.e. those without reactive tasks, don't need a [`TaskHolder`]
             if !self.task_wrappers.is_empty() {
                 if let Ok(mut entity) = world.get_entity_mut(id) {
-                    if let Some(task_holder) = entity.get_mut::<TaskHolder>() {
-                        for task_wrapper in self.task_wrappers {
-                            task_holder.hold(task_wrapper(id));
-                        }
+                    let task_holder = entity.entry::<TaskHolder>().or_default();
+                    for task_wrapper in self.task_wrappers {
+                        let future = task_wrapper(id);
+                        #[cfg(feature = "profiling")]
+                        let future = boxed(super::profiling::instrument(future, self.profiling_name.as_deref()));
+                        task_holder.hold(match &self.paused {
+                            Some(paused) => spawn(Pausable {
+                                future,
+                                paused: paused.signal(),
+                                is_paused: false,
+                            }),
+                            None => spawn(future),
+                        });
                     }
                 }
             }
@@ -398,6 +443,49 @@ impl NodeBuilder {
     }
 }
 
+type TaskFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// `command`, applied in a span nested under the span of the reactive task which queued it if the
+/// `profiling` feature is enabled.
+pub(crate) fn profiled(label: &'static str, command: impl Command) -> impl Command {
+    #[cfg(feature = "profiling")]
+    {
+        super::profiling::in_span(label, command)
+    }
+    #[cfg(not(feature = "profiling"))]
+    {
+        let _ = label;
+        command
+    }
+}
+
+fn boxed(future: impl Future<Output = ()> + Send + 'static) -> TaskFuture {
+    Box::pin(future)
+}
+
+/// Polls `future` only while `paused` is `false`, see [`NodeBuilder::pause_signals`].
+struct Pausable {
+    future: TaskFuture,
+    paused: MutableSignal<bool>,
+    is_paused: bool,
+}
+
+impl Future for Pausable {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        // also registers to be woken when `paused` changes
+        while let Poll::Ready(Some(paused)) = Pin::new(&mut self.paused).poll_change(cx) {
+            self.is_paused = paused;
+        }
+        if self.is_paused {
+            Poll::Pending
+        } else {
+            self.future.as_mut().poll(cx)
+        }
+    }
+}
+
 struct TaskWrapper {
     i: usize,
     #[allow(dead_code)]
@@ -409,10 +497,6 @@ struct TaskWrapper {
 pub(crate) struct TaskHolder(Arc<Mutex<Vec<TaskWrapper>>>);
 
 impl TaskHolder {
-    fn new() -> Self {
-        default()
-    }
-
     /// Drop the [`Task`] when it completes or the entity is despawned.
     pub fn hold(&self, task: Task<()>) {
         let tasks = self.0.clone();
@@ -436,6 +520,338 @@ impl TaskHolder {
     }
 }
 
-fn offset(i: usize, child_block_populations: &[usize]) -> usize {
-    child_block_populations[0..i].iter().copied().sum()
+/// Position of a child among those spawned by its parent's [`NodeBuilder`]: the index of the child
+/// block, i.e. the `.child...` call, which spawned it, then its index within that block; children
+/// are kept sorted by it, see [`sort_children`].
+#[derive(Component, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct ChildOrder {
+    block: usize,
+    index: usize,
+}
+
+/// Number of children in each of a node's child blocks, kept as a Fenwick tree so the offset of a
+/// block can be both found and updated in logarithmic time.
+#[derive(Component)]
+struct ChildBlockPopulations(Vec<usize>);
+
+impl ChildBlockPopulations {
+    fn new(blocks: usize) -> Self {
+        Self(vec![0; blocks])
+    }
+
+    fn add(&mut self, block: usize, delta: isize) {
+        if block >= self.0.len() {
+            // rebuild, since the partial sums of the new nodes cover existing blocks
+            let mut populations = (0..self.0.len())
+                .map(|block| self.offset(block + 1) - self.offset(block))
+                .collect::<Vec<_>>();
+            populations.resize(block + 1, 0);
+            *self = Self::new(populations.len());
+            for (block, population) in populations.into_iter().enumerate() {
+                self.add(block, population as isize);
+            }
+        }
+        let mut i = block + 1;
+        while i <= self.0.len() {
+            self.0[i - 1] = self.0[i - 1].wrapping_add_signed(delta);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    // number of children in the blocks before `block`
+    fn offset(&self, block: usize) -> usize {
+        let mut offset = 0;
+        let mut i = block.min(self.0.len());
+        while i > 0 {
+            offset += self.0[i - 1];
+            i -= i & i.wrapping_neg();
+        }
+        offset
+    }
+}
+
+// index in `parent`'s [`Children`] at which the children of `block` start; children not spawned by
+// a [`NodeBuilder`] aren't counted, so they can shift this, in which case [`sort_children`] restores
+// the order
+fn block_offset(world: &World, parent: Entity, block: usize) -> usize {
+    world
+        .get::<ChildBlockPopulations>(parent)
+        .map(|populations| populations.offset(block))
+        .unwrap_or(0)
+}
+
+// sync the index of each of a block's `children` with its position, starting from `from`
+fn reindex(world: &mut World, children: impl IntoIterator<Item = Entity>, from: usize) {
+    for (index, child) in children.into_iter().enumerate().skip(from) {
+        if let Some(mut order) = world.get_mut::<ChildOrder>(child) {
+            if order.index != index {
+                order.index = index;
+            }
+        }
+    }
+}
+
+fn on_child_parent_insert(
+    insert: Trigger<OnInsert, Parent>,
+    children: Query<(&Parent, &ChildOrder)>,
+    mut populations: Query<&mut ChildBlockPopulations>,
+) {
+    if let Ok((parent, &ChildOrder { block, .. })) = children.get(insert.entity()) {
+        if let Ok(mut populations) = populations.get_mut(parent.get()) {
+            populations.add(block, 1);
+        }
+    }
+}
+
+// also runs when the child is despawned or removed from its parent
+fn on_child_parent_replace(
+    replace: Trigger<OnReplace, Parent>,
+    children: Query<(&Parent, &ChildOrder)>,
+    mut populations: Query<&mut ChildBlockPopulations>,
+) {
+    if let Ok((parent, &ChildOrder { block, .. })) = children.get(replace.entity()) {
+        if let Ok(mut populations) = populations.get_mut(parent.get()) {
+            populations.add(block, -1);
+        }
+    }
+}
+
+/// Sort the [`Children`] of nodes whose children were reordered or changed position by their
+/// [`ChildOrder`]; children without one stay after the child preceding them.
+fn sort_children(
+    reordered: Query<&Parent, Changed<ChildOrder>>,
+    changed: Query<Entity, (With<ChildBlockPopulations>, Changed<Children>)>,
+    orders: Query<&ChildOrder>,
+    mut childrens: Query<&mut Children>,
+) {
+    let parents = reordered
+        .iter()
+        .map(Parent::get)
+        .chain(changed.iter())
+        .collect::<HashSet<_>>();
+    for parent in parents {
+        let Ok(children) = childrens.get(parent) else {
+            continue;
+        };
+        let mut order_option = None;
+        let keys = children
+            .iter()
+            .map(|&child| {
+                if let Ok(&order) = orders.get(child) {
+                    order_option = Some(order);
+                }
+                (child, order_option)
+            })
+            .collect::<HashMap<_, _>>();
+        if children.windows(2).all(|pair| keys[&pair[0]] <= keys[&pair[1]]) {
+            continue;
+        }
+        if let Ok(mut children) = childrens.get_mut(parent) {
+            children.sort_by_key(|child| keys[child]);
+        }
+    }
+}
+
+// clear `child` of everything its previous node added, i.e. its descendants, components, and
+// reactive tasks, keeping its place among its siblings, so a new node can be spawned on it
+fn reset_child(world: &mut World, child: Entity) -> bool {
+    let Ok(mut child) = world.get_entity_mut(child) else {
+        return false;
+    };
+    child.despawn_descendants();
+    child.retain::<(Parent, ChildOrder)>();
+    true
+}
+
+/// Children of a node waiting to be spawned, see [`NodeBuilder::children_spawn_budget`].
+#[derive(Component)]
+struct ChildSpawnBudget {
+    budget: Duration,
+    spawned: Option<Mutable<bool>>,
+    pending: Mutex<VecDeque<(NodeBuilder, Entity)>>,
+}
+
+// spawns `child` on `child_entity` now, or queues it if its parent has a [`ChildSpawnBudget`]
+fn spawn_child(world: &mut World, child: NodeBuilder, child_entity: Entity) {
+    let parent_option = world.get::<Parent>(child_entity).map(Parent::get);
+    if let Some(budget) = parent_option.and_then(|parent| world.get::<ChildSpawnBudget>(parent)) {
+        budget.pending.lock().unwrap().push_back((child, child_entity));
+        if let Some(spawned) = &budget.spawned {
+            spawned.set_neq(false);
+        }
+    } else {
+        child.spawn_on_entity(world, child_entity);
+    }
+}
+
+fn spawn_pending_children(world: &mut World) {
+    let parents = world
+        .query::<(Entity, &ChildSpawnBudget)>()
+        .iter(world)
+        .filter(|(_, budget)| !budget.pending.lock().unwrap().is_empty())
+        .map(|(parent, budget)| (parent, budget.budget))
+        .collect::<Vec<_>>();
+    for (parent, budget) in parents {
+        let start = Instant::now();
+        // always spawn at least one child so progress is made regardless of the budget
+        loop {
+            let Some((child, child_entity)) = world
+                .get::<ChildSpawnBudget>(parent)
+                .and_then(|budget| budget.pending.lock().unwrap().pop_front())
+            else {
+                break;
+            };
+            child.spawn_on_entity(world, child_entity);
+            if start.elapsed() >= budget {
+                break;
+            }
+        }
+        if let Some(budget) = world.get::<ChildSpawnBudget>(parent) {
+            if budget.pending.lock().unwrap().is_empty() {
+                if let Some(spawned) = &budget.spawned {
+                    spawned.set_neq(true);
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    #[cfg(feature = "ui")]
+    let sort_children = sort_children.before(bevy_ui::UiSystem::Layout);
+    app.add_observer(on_child_parent_insert)
+        .add_observer(on_child_parent_replace)
+        .add_systems(
+            Update,
+            spawn_pending_children.run_if(any_with_component::<ChildSpawnBudget>),
+        )
+        .add_systems(PostUpdate, sort_children);
+}
+
+// TODO: unit tests for every branch
+fn apply_child_diff(
+    world: &mut World,
+    parent: Entity,
+    block: usize,
+    children_entities: &MutableVec<Entity>,
+    diff: VecDiff<NodeBuilder>,
+) {
+    match diff {
+        VecDiff::Replace { values: children } => {
+            let mut children_lock = children_entities.lock_mut();
+            for child in children_lock.drain(..) {
+                if let Ok(child) = world.get_entity_mut(child) {
+                    // need to call like this to avoid type ambiguity
+                    EntityWorldMut::despawn_recursive(child); // removes from parent
+                }
+            }
+            for index in 0..children.len() {
+                children_lock.push(world.spawn(ChildOrder { block, index }).id());
+            }
+            let offset = block_offset(world, parent, block);
+            if let Ok(mut parent) = world.get_entity_mut(parent) {
+                parent.insert_children(offset, children_lock.as_slice());
+                for (child, child_entity) in children.into_iter().zip(children_lock.iter().copied()) {
+                    spawn_child(world, child, child_entity);
+                }
+            } else {
+                // parent despawned during child spawning
+                for entity in children_lock.drain(..) {
+                    if let Ok(child) = world.get_entity_mut(entity) {
+                        child.despawn_recursive();
+                    }
+                }
+            }
+        }
+        VecDiff::InsertAt { index, value: child } => {
+            let child_entity = world.spawn(ChildOrder { block, index }).id();
+            let offset = block_offset(world, parent, block);
+            if let Ok(mut parent) = world.get_entity_mut(parent) {
+                parent.insert_children(offset + index, &[child_entity]);
+                spawn_child(world, child, child_entity);
+                let mut children_lock = children_entities.lock_mut();
+                children_lock.insert(index, child_entity);
+                reindex(world, children_lock.iter().copied(), index + 1);
+            } else {
+                // parent despawned during child spawning
+                if let Ok(child) = world.get_entity_mut(child_entity) {
+                    child.despawn_recursive();
+                }
+            }
+        }
+        VecDiff::Push { value: child } => {
+            let index = children_entities.lock_ref().len();
+            let child_entity = world.spawn(ChildOrder { block, index }).id();
+            let offset = block_offset(world, parent, block);
+            if let Ok(mut parent) = world.get_entity_mut(parent) {
+                let mut children_lock = children_entities.lock_mut();
+                parent.insert_children(offset + children_lock.len(), &[child_entity]);
+                spawn_child(world, child, child_entity);
+                children_lock.push(child_entity);
+            } else {
+                // parent despawned during child spawning
+                if let Ok(child) = world.get_entity_mut(child_entity) {
+                    child.despawn_recursive();
+                }
+            }
+        }
+        VecDiff::UpdateAt { index, value: node } => {
+            // reuse the existing entity, so e.g. focus and its place in the hierarchy are kept
+            let existing_child_option = children_entities.lock_ref().get(index).copied();
+            if let Some(existing_child) = existing_child_option.filter(|&child| reset_child(world, child)) {
+                spawn_child(world, node, existing_child);
+                return;
+            }
+            let child_entity = world.spawn(ChildOrder { block, index }).id();
+            let offset = block_offset(world, parent, block);
+            if let Ok(mut parent) = world.get_entity_mut(parent) {
+                children_entities.lock_mut().set(index, child_entity);
+                parent.insert_children(offset + index, &[child_entity]);
+                spawn_child(world, node, child_entity);
+            } else {
+                // parent despawned during child spawning
+                if let Ok(child) = world.get_entity_mut(child_entity) {
+                    child.despawn_recursive();
+                }
+            }
+        }
+        VecDiff::Move { old_index, new_index } => {
+            let mut children_lock = children_entities.lock_mut();
+            let child_entity = children_lock.remove(old_index);
+            children_lock.insert(new_index, child_entity);
+            reindex(world, children_lock.iter().copied(), old_index.min(new_index));
+            let offset = block_offset(world, parent, block);
+            if let Ok(mut parent) = world.get_entity_mut(parent) {
+                // need to call like this to avoid type ambiguity
+                EntityWorldMut::remove_children(&mut parent, &[child_entity]);
+                parent.insert_children(offset + new_index, &[child_entity]);
+            }
+        }
+        VecDiff::RemoveAt { index } => {
+            let mut children_lock = children_entities.lock_mut();
+            if let Some(existing_child) = children_lock.get(index).copied() {
+                if let Ok(child) = world.get_entity_mut(existing_child) {
+                    child.despawn_recursive(); // removes from parent
+                }
+                children_lock.remove(index);
+                reindex(world, children_lock.iter().copied(), index);
+            }
+        }
+        VecDiff::Pop {} => {
+            let mut children_lock = children_entities.lock_mut();
+            if let Some(child_entity) = children_lock.pop() {
+                if let Ok(child) = world.get_entity_mut(child_entity) {
+                    child.despawn_recursive();
+                }
+            }
+        }
+        VecDiff::Clear {} => {
+            let mut children_lock = children_entities.lock_mut();
+            for child_entity in children_lock.drain(..) {
+                if let Ok(child) = world.get_entity_mut(child_entity) {
+                    child.despawn_recursive();
+                }
+            }
+        }
+    }
 }
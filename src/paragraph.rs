@@ -1,2 +0,0 @@
-// TODO: signal abstraction over text sections
-
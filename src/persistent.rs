@@ -0,0 +1,180 @@
+//! [`Mutable`]s which survive restarts, see [`Persistent`].
+
+use std::{collections::HashMap, ops::Deref, sync::Mutex, time::Duration};
+
+use apply::Apply;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_log::prelude::*;
+use futures_signals::signal::{Mutable, SignalExt};
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    time::ClockSignalExt,
+    utils::{clone, spawn},
+};
+
+/// How long a [`Persistent`] must stop changing before it is saved.
+pub const PERSISTENT_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// [`Mutable`] which loads its value on creation and saves it whenever it changes, debounced by
+/// [`PERSISTENT_SAVE_DEBOUNCE`], with saves still pending flushed on [`AppExit`]. Values are stored
+/// as JSON in a file named after `key` in a `persistent` directory under the platform's data
+/// directory for the running executable, e.g. `~/.local/share/<executable>/persistent/` on Linux,
+/// or under `key` in localStorage on the web. Ideal for settings menus.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Clone, Serialize, Deserialize)]
+/// struct Settings {
+///     volume: f32,
+/// }
+///
+/// let settings = Persistent::new("settings", Settings { volume: 1. });
+/// El::<Text>::new().text_signal(settings.signal_ref(|Settings { volume }| Text(format!("{volume:.2}"))));
+/// ```
+pub struct Persistent<T>(Mutable<T>);
+
+impl<T: Serialize + DeserializeOwned + Clone + Send + Sync + 'static> Persistent<T> {
+    /// Load the value stored under `key`, falling back to `default` if there is none or it cannot be
+    /// read.
+    pub fn new(key: impl Into<String>, default: T) -> Self {
+        let key = key.into();
+        let value = match load(&key).map(|json| serde_json::from_str(&json)) {
+            Some(Ok(value)) => value,
+            Some(Err(error)) => {
+                warn!("failed to deserialize persistent value '{key}', using default: {error}");
+                default
+            }
+            None => default,
+        };
+        let mutable = Mutable::new(value);
+        // the latest value is staged immediately, so it can be flushed on exit before the debounce
+        // elapses
+        mutable
+            .signal_cloned()
+            .for_each_sync(clone!((key) move |value| {
+                PENDING_SAVES.lock().unwrap().insert(key.clone(), Box::new(clone!((key) move || {
+                    match serde_json::to_string(&value) {
+                        Ok(json) => save(&key, &json),
+                        Err(error) => warn!("failed to serialize persistent value '{key}': {error}"),
+                    }
+                })));
+            }))
+            .apply(spawn)
+            .detach();
+        mutable
+            .signal_ref(|_| ())
+            .debounce(PERSISTENT_SAVE_DEBOUNCE)
+            .for_each_sync(move |_| flush(&key))
+            .apply(spawn)
+            .detach();
+        Self(mutable)
+    }
+}
+
+impl<T> Deref for Persistent<T> {
+    type Target = Mutable<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+type PendingSave = Box<dyn FnOnce() + Send>;
+
+// latest unsaved value of each key
+static PENDING_SAVES: Lazy<Mutex<HashMap<String, PendingSave>>> = Lazy::new(Mutex::default);
+
+fn flush(key: &str) {
+    // release the lock before writing
+    let save_option = PENDING_SAVES.lock().unwrap().remove(key);
+    if let Some(save) = save_option {
+        save();
+    }
+}
+
+fn flush_all() {
+    let saves = std::mem::take(&mut *PENDING_SAVES.lock().unwrap());
+    for save in saves.into_values() {
+        save();
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(target_arch = "wasm32")] {
+        fn local_storage() -> Option<web_sys::Storage> {
+            web_sys::window()?.local_storage().ok()?
+        }
+
+        fn load(key: &str) -> Option<String> {
+            local_storage()?.get_item(key).ok()?
+        }
+
+        fn save(key: &str, json: &str) {
+            if let Some(storage) = local_storage() {
+                if storage.set_item(key, json).is_err() {
+                    warn!("failed to save persistent value '{key}' to localStorage");
+                }
+            }
+        }
+    } else {
+        use std::path::PathBuf;
+
+        static DIRECTORY: Lazy<PathBuf> = Lazy::new(|| {
+            let executable = std::env::current_exe()
+                .ok()
+                .and_then(|path| Some(path.file_stem()?.to_string_lossy().into_owned()))
+                .unwrap_or_else(|| "haalka".to_string());
+            dirs::data_dir()
+                .unwrap_or_default()
+                .join(file_name(&executable))
+                .join("persistent")
+        });
+
+        // 64 bit FNV-1a, which unlike std's hashers is stable across releases, so renamed files
+        // are still found
+        fn fnv1a(bytes: &[u8]) -> u64 {
+            bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            })
+        }
+
+        // keys may contain path separators or characters invalid in file names, so those are
+        // replaced, and a hash of the key keeps distinct keys from sharing a file
+        fn file_name(key: &str) -> String {
+            let sanitized = key
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+                .collect::<String>();
+            if sanitized == key && !key.is_empty() {
+                sanitized
+            } else {
+                format!("{sanitized}-{:016x}", fnv1a(key.as_bytes()))
+            }
+        }
+
+        fn path(key: &str) -> PathBuf {
+            DIRECTORY.join(format!("{}.json", file_name(key)))
+        }
+
+        fn load(key: &str) -> Option<String> {
+            std::fs::read_to_string(path(key)).ok()
+        }
+
+        fn save(key: &str, json: &str) {
+            if let Err(error) = std::fs::create_dir_all(&*DIRECTORY).and_then(|()| std::fs::write(path(key), json)) {
+                warn!("failed to save persistent value '{key}': {error}");
+            }
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Last, flush_all.run_if(on_event::<AppExit>));
+}
@@ -9,6 +9,7 @@ use bevy_derive::*;
 use bevy_ecs::{prelude::*, system::*};
 use bevy_hierarchy::prelude::*;
 use bevy_log::prelude::*;
+use bevy_math::Vec2;
 use bevy_picking::{
     backend::prelude::*,
     focus::{HoverMap, PickingInteraction},
@@ -16,11 +17,14 @@ use bevy_picking::{
     prelude::*,
 };
 use bevy_reflect::prelude::*;
-use bevy_utils::prelude::*;
+use bevy_time::prelude::*;
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{ComputedNode, Node, PositionType, UiScale, Val};
+use bevy_utils::{prelude::*, Instant};
 use bevy_window::{prelude::*, *};
 use bevy_winit::cursor::CursorIcon;
 use enclose::enclose as clone;
-use futures_signals::signal::{always, channel, Mutable, Signal, SignalExt};
+use futures_signals::signal::{always, channel, Mutable, ReadOnlyMutable, Signal, SignalExt};
 use haalka_futures_signals_ext::SignalExtBool;
 
 use super::{
@@ -73,6 +77,168 @@ pub trait PointerEventAware: GlobalEventAware {
         self.on_hovered_change(move |is_hovered| hovered.set_neq(is_hovered))
     }
 
+    /// When this element's hovered state changes and stays changed for `enter_delay` (when becoming
+    /// hovered) or `leave_delay` (when becoming unhovered), run a function with the new hovered
+    /// state; useful for menus and tooltips that shouldn't flicker when the cursor briefly crosses
+    /// an element. A pending delay is cancelled if the hovered state changes back before it
+    /// elapses. This method can be called repeatedly to register many such handlers.
+    fn on_hovered_change_delayed(
+        self,
+        enter_delay: Duration,
+        leave_delay: Duration,
+        mut handler: impl FnMut(bool) + Send + Sync + 'static,
+    ) -> Self {
+        let hovered = Mutable::new(false);
+        let mut last = false;
+        let delayed = hovered.signal().map_future(move |is_hovered| async move {
+            sleep(if is_hovered { enter_delay } else { leave_delay }).await;
+            is_hovered
+        });
+        self.hovered_sync(hovered).update_raw_el(|raw_el| {
+            // the future of a previous hovered state is dropped, cancelling its delay, as soon as the
+            // hovered state changes
+            raw_el.on_signal_sync(delayed, move |_, is_hovered_option| {
+                if let Some(is_hovered) = is_hovered_option {
+                    if is_hovered != last {
+                        last = is_hovered;
+                        handler(is_hovered);
+                    }
+                }
+            })
+        })
+    }
+
+    /// When the position of the cursor relative to this element's top left corner changes while it
+    /// is hovered, run a [`System`] which takes [`In`](`System::In`) this element's [`Entity`] and
+    /// the cursor's logical position, or [`None`] when the element stops being hovered. Useful for
+    /// custom widgets like sliders and color pickers. This method can be called repeatedly to
+    /// register many such handlers.
+    fn on_cursor_position_change_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, Option<Vec2>)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.defer_update(DeferredUpdaterAppendDirection::Back, |raw_el| {
+                let system_holder = Mutable::new(None);
+                raw_el
+                    .insert(PickingBehavior::default())
+                    .insert(Hovered(false))
+                    .insert(CursorPositionListener::default())
+                    .on_spawn(clone!((system_holder) move |world, entity| {
+                        let system = register_system(world, handler);
+                        system_holder.set(Some(system));
+                        observe(world, entity, move |change: Trigger<CursorPositionChange>, mut commands: Commands| {
+                            commands.run_system_with_input(system, (entity, change.event().0));
+                        });
+                    }))
+                    .apply(remove_system_holder_on_remove(system_holder))
+            })
+        })
+    }
+
+    /// When the position of the cursor relative to this element's top left corner changes while it
+    /// is hovered, run a function with the cursor's logical position, or [`None`] when the element
+    /// stops being hovered. This method can be called repeatedly to register many such handlers.
+    fn on_cursor_position_change(self, mut handler: impl FnMut(Option<Vec2>) + Send + Sync + 'static) -> Self {
+        self.on_cursor_position_change_with_system(move |In((_, position))| handler(position))
+    }
+
+    /// Sync a [`Mutable`] with the position of the cursor relative to this element's top left corner
+    /// while it is hovered; use [`Mutable::signal`] to derive a reactive position signal.
+    fn cursor_position_sync(self, position: Mutable<Option<Vec2>>) -> Self {
+        self.on_cursor_position_change(move |new_position| position.set_neq(new_position))
+    }
+
+    /// Build on this element with a read only view of the position of the cursor relative to its
+    /// top left corner while it is hovered, avoiding the need for a separate [`Mutable`] and
+    /// [`.cursor_position_sync`](PointerEventAware::cursor_position_sync); use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive position signals.
+    fn cursor_position_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<Option<Vec2>>) -> Self) -> Self {
+        let position = Mutable::new(None);
+        f(self.cursor_position_sync(position.clone()), position.read_only())
+    }
+
+    /// When the pointer moves while this element is hovered, run a [`System`] which takes
+    /// [`In`](`System::In`) this element's [`Entity`] and the pointer's position normalized to this
+    /// element's rect, i.e. `(0, 0)` at its top left corner and `(1, 1)` at its bottom right corner.
+    /// Useful for color pickers, scrubbing bars, and custom sliders. This method can be called
+    /// repeatedly to register many such handlers.
+    fn on_pointer_move_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, Vec2)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        let system_holder = Mutable::new(None);
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .on_spawn(clone!((system_holder) move |world, _| {
+                    system_holder.set(Some(register_system(world, handler)));
+                }))
+                .apply(remove_system_holder_on_remove(system_holder.clone()))
+        })
+        .on_cursor_position_change_with_system(
+            move |In((entity, position_option)): In<(Entity, Option<Vec2>)>,
+                  computed_nodes: Query<&ComputedNode>,
+                  mut system: Local<Option<SystemId<In<(Entity, Vec2)>>>>,
+                  mut commands: Commands| {
+                let Some(position) = position_option else {
+                    return;
+                };
+                let Ok(computed_node) = computed_nodes.get(entity) else {
+                    return;
+                };
+                let size = computed_node.size() * computed_node.inverse_scale_factor();
+                if size.cmple(Vec2::ZERO).any() {
+                    return;
+                }
+                // only pay the read locking cost once
+                let &mut system = system.get_or_insert_with(|| system_holder.get().unwrap());
+                commands.run_system_with_input(system, (entity, (position / size).clamp(Vec2::ZERO, Vec2::ONE)));
+            },
+        )
+    }
+
+    /// When the pointer moves while this element is hovered, run a function with the pointer's
+    /// position normalized to this element's rect, i.e. `(0, 0)` at its top left corner and `(1, 1)`
+    /// at its bottom right corner. This method can be called repeatedly to register many such
+    /// handlers.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let value = Mutable::new(0.);
+    /// El::<Node>::new()
+    ///     .with_node(|mut node| node.width = Val::Px(200.))
+    ///     .on_pointer_move(move |local| value.set_neq(local.x));
+    /// ```
+    fn on_pointer_move(self, mut handler: impl FnMut(Vec2) + Send + Sync + 'static) -> Self {
+        self.on_pointer_move_with_system(move |In((_, position))| handler(position))
+    }
+
+    /// Build on this element with a read only view of its hovered state, avoiding the need for a
+    /// separate [`Mutable`] and [`.hovered_sync`](PointerEventAware::hovered_sync); use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive hovered signals.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// El::<Node>::new().hovered_signal(|el, hovered| {
+    ///     el.background_color_signal(
+    ///         hovered
+    ///             .signal()
+    ///             .map_bool(|| Color::WHITE, || Color::BLACK)
+    ///             .map(BackgroundColor),
+    ///     )
+    /// });
+    /// ```
+    fn hovered_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<bool>) -> Self) -> Self {
+        let hovered = Mutable::new(false);
+        f(self.hovered_sync(hovered.clone()), hovered.read_only())
+    }
+
     /// Run a [`System`] when this element is clicked.
     fn on_click_with_system<Marker>(
         self,
@@ -94,6 +260,32 @@ pub trait PointerEventAware: GlobalEventAware {
         })
     }
 
+    /// Run a function when this element is left clicked twice within
+    /// [`DoubleClickSettings::interval`], with the pointer moving no more than
+    /// [`DoubleClickSettings::slop`] between the clicks. A third click starts a new double click
+    /// rather than completing another one.
+    fn on_double_click(self, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        let mut last_click_option: Option<(Instant, Vec2)> = None;
+        self.on_click_with_system(
+            move |In((_, click)): In<(_, Pointer<Click>)>, settings: Res<DoubleClickSettings>| {
+                if !matches!(click.button, PointerButton::Primary) {
+                    return;
+                }
+                let now = Instant::now();
+                let position = click.pointer_location.position;
+                match last_click_option.take() {
+                    Some((last_time, last_position))
+                        if now.duration_since(last_time) <= settings.interval
+                            && position.distance(last_position) <= settings.slop =>
+                    {
+                        handler()
+                    }
+                    _ => last_click_option = Some((now, position)),
+                }
+            },
+        )
+    }
+
     /// Run a function when this element is left clicked, reactively controlling whether the click
     /// bubbles up the hierarchy with a [`Signal`].
     fn on_click_propagation_stoppable(
@@ -121,15 +313,30 @@ pub trait PointerEventAware: GlobalEventAware {
         self.on_click_propagation_stoppable(handler, always(true))
     }
 
-    /// Run a function when this element is right clicked.
-    fn on_right_click(self, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+    /// Run a function when this element is clicked with `button`.
+    fn on_click_with_button(self, button: PointerButton, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
         self.on_click_with_system(move |In((_, event)): In<(_, Pointer<Click>)>| {
-            if matches!(event.button, PointerButton::Secondary) {
+            if event.button == button {
                 handler()
             }
         })
     }
 
+    /// Run a function with the [`PointerButton`] this element is clicked with, whichever it is.
+    fn on_any_click(self, mut handler: impl FnMut(PointerButton) + Send + Sync + 'static) -> Self {
+        self.on_click_with_system(move |In((_, event)): In<(_, Pointer<Click>)>| handler(event.button))
+    }
+
+    /// Run a function when this element is right clicked.
+    fn on_right_click(self, handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_click_with_button(PointerButton::Secondary, handler)
+    }
+
+    /// Run a function when this element is middle clicked.
+    fn on_middle_click(self, handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_click_with_button(PointerButton::Middle, handler)
+    }
+
     /// When a [`Pointer<Click>`] is received outside this [`Element`](super::element::Element)
     /// or its descendents, run a [`System`] that takes [`In`](`System::In`) this element's
     /// [`Entity`] and the [`Pointer<Click>`]. Requires the [`UiRoot`] [`Resource`] to exist in the
@@ -366,18 +573,267 @@ pub trait PointerEventAware: GlobalEventAware {
         self.on_pressing_throttled(handler, move || sleep(duration))
     }
 
+    /// When this element is pressed, run a [`System`] which takes [`In`](`System::In`) this
+    /// element's [`Entity`], then, like a held key, run it again after `initial_delay` and every
+    /// `interval` thereafter until it is released; useful for increment spinners and scrollbar
+    /// arrows.
+    fn on_pressing_with_system_with_repeat<Marker>(
+        self,
+        initial_delay: Duration,
+        interval: Duration,
+        handler: impl IntoSystem<In<Entity>, (), Marker> + Send + 'static,
+    ) -> Self {
+        let repeating = Mutable::new(false);
+        // reset outside of the pressed handlers, which don't see releases while throttled
+        self.update_raw_el(clone!((repeating) move |raw_el| {
+            raw_el.observe(move |up: Trigger<Pointer<Up>>| {
+                if matches!(up.event().button, PointerButton::Primary) {
+                    repeating.set_neq(false);
+                }
+            })
+        }))
+        .on_hovered_change(clone!((repeating) move |hovered| {
+            if !hovered {
+                repeating.set_neq(false);
+            }
+        }))
+        .on_pressing_with_system_throttled(handler, move || {
+            let repeated = repeating.replace(true);
+            sleep(if repeated { interval } else { initial_delay })
+        })
+    }
+
+    /// When this element is pressed, run a function, then, like a held key, run it again after
+    /// `initial_delay` and every `interval` thereafter until it is released.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let count = Mutable::new(0);
+    /// El::<Node>::new().on_pressing_with_repeat(Duration::from_millis(400), Duration::from_millis(50), move || {
+    ///     *count.lock_mut() += 1
+    /// });
+    /// ```
+    fn on_pressing_with_repeat(
+        self,
+        initial_delay: Duration,
+        interval: Duration,
+        mut handler: impl FnMut() + Send + Sync + 'static,
+    ) -> Self {
+        self.on_pressing_with_system_with_repeat(initial_delay, interval, move |_: In<_>| handler())
+    }
+
+    /// Once this element has been pressed continuously for `duration`, run a [`System`] which
+    /// takes [`In`](`System::In`) this element's [`Entity`], syncing `progress` with the fraction
+    /// of `duration` held so far, from `0` to `1`; releasing before completion resets `progress`
+    /// without running the `handler`. Useful for destructive actions, e.g. "hold to delete save".
+    fn hold_confirm_sync_with_system<Marker>(
+        self,
+        duration: Duration,
+        progress: Mutable<f32>,
+        handler: impl IntoSystem<In<Entity>, (), Marker> + Send + 'static,
+    ) -> Self {
+        let system_holder = Mutable::new(None);
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .on_spawn(clone!((system_holder) move |world, _| {
+                    system_holder.set(Some(register_system(world, handler)));
+                }))
+                .apply(remove_system_holder_on_remove(system_holder.clone()))
+        })
+        .on_pressed_with_system_blockable::<_, PressHandlingBlocked>(
+            move |In((entity, pressed)): In<(Entity, bool)>,
+                  time: Res<Time>,
+                  mut held: Local<Duration>,
+                  mut confirmed: Local<bool>,
+                  mut system: Local<Option<SystemId<In<Entity>>>>,
+                  mut commands: Commands| {
+                if !pressed {
+                    *held = Duration::ZERO;
+                    *confirmed = false;
+                    progress.set_neq(0.);
+                    return;
+                }
+                if *confirmed {
+                    return;
+                }
+                *held += time.delta();
+                let fraction = (held.as_secs_f32() / duration.as_secs_f32().max(f32::EPSILON)).min(1.);
+                progress.set_neq(fraction);
+                if fraction >= 1. {
+                    *confirmed = true;
+                    // only pay the read locking cost once
+                    let &mut system = system.get_or_insert_with(|| system_holder.get().unwrap());
+                    commands.run_system_with_input(system, entity);
+                }
+            },
+        )
+    }
+
+    /// Once this element has been pressed continuously for `duration`, run a function; releasing
+    /// before completion cancels it. See
+    /// [`.hold_confirm_signal`](PointerEventAware::hold_confirm_signal) for displaying progress.
+    fn on_hold_confirm(self, duration: Duration, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.hold_confirm_sync_with_system(duration, Mutable::new(0.), move |_: In<_>| handler())
+    }
+
+    /// Once this element has been pressed continuously for `duration`, run a function, building on
+    /// this element with a read only view of the fraction of `duration` held so far, from `0` to
+    /// `1`; releasing before completion resets the progress without running the `handler`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// El::<Node>::new().hold_confirm_signal(
+    ///     Duration::from_secs(2),
+    ///     || println!("save deleted"),
+    ///     |el, progress| {
+    ///         el.child(El::<Node>::new().on_signal_with_node(progress.signal(), |mut node, progress| {
+    ///             node.width = Val::Percent(progress * 100.)
+    ///         }))
+    ///     },
+    /// );
+    /// ```
+    fn hold_confirm_signal(
+        self,
+        duration: Duration,
+        mut handler: impl FnMut() + Send + Sync + 'static,
+        f: impl FnOnce(Self, ReadOnlyMutable<f32>) -> Self,
+    ) -> Self {
+        let progress = Mutable::new(0.);
+        f(
+            self.hold_confirm_sync_with_system(duration, progress.clone(), move |_: In<_>| handler()),
+            progress.read_only(),
+        )
+    }
+
     /// Sync a [`Mutable`] with this element's pressed state.
     fn pressed_sync(self, pressed: Mutable<bool>) -> Self {
         self.on_pressed_change(move |cur| pressed.set_neq(cur))
     }
+
+    /// Build on this element with a read only view of its pressed state, avoiding the need for a
+    /// separate [`Mutable`] and [`.pressed_sync`](PointerEventAware::pressed_sync); use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive pressed signals.
+    fn pressed_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<bool>) -> Self) -> Self {
+        let pressed = Mutable::new(false);
+        f(self.pressed_sync(pressed.clone()), pressed.read_only())
+    }
+
+    /// While this element is dragged, run a [`System`] which takes [`In`](`System::In`) this
+    /// element's [`Entity`] and the [`DragData`] of each pointer movement. This method can be called
+    /// repeatedly to register many such handlers.
+    fn on_drag_with_system<Marker>(
+        self,
+        handler: impl IntoSystem<In<(Entity, DragData)>, (), Marker> + Send + 'static,
+    ) -> Self {
+        let system_holder = Mutable::new(None);
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert(PickingBehavior::default())
+                .on_spawn(clone!((system_holder) move |world, _| {
+                    system_holder.set(Some(register_system(world, handler)));
+                }))
+                .apply(remove_system_holder_on_remove(system_holder.clone()))
+                .on_event_with_system::<Pointer<Drag>, _>(
+                    move |In((entity, drag)): In<(Entity, Pointer<Drag>)>,
+                          mut system: Local<Option<SystemId<In<(Entity, DragData)>>>>,
+                          mut commands: Commands| {
+                        // only pay the read locking cost once
+                        let &mut system = system.get_or_insert_with(|| system_holder.get().unwrap());
+                        commands.run_system_with_input(system, (entity, DragData::from(drag.event)));
+                    },
+                )
+        })
+    }
+
+    /// While this element is dragged, run a function with the [`DragData`] of each pointer
+    /// movement. This method can be called repeatedly to register many such handlers.
+    fn on_drag(self, mut handler: impl FnMut(DragData) + Send + Sync + 'static) -> Self {
+        self.on_drag_with_system(move |In((_, drag))| handler(drag))
+    }
+
+    /// Move this element with the primary pointer button by updating its [`Node::left`] and
+    /// [`Node::top`], making it absolutely positioned; useful for floating panels and slider
+    /// thumbs. Movement starts from the current [`Val::Px`] offsets, treating other [`Val`]s as
+    /// `0`. Drags do not bubble past this element, so draggable children do not also move their
+    /// draggable ancestors.
+    fn draggable_position(self) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert(PickingBehavior::default())
+                .with_component::<Node>(|mut node| node.position_type = PositionType::Absolute)
+                .on_event_with_system_stop_propagation::<Pointer<Drag>, _>(
+                    |In((entity, drag)): In<(Entity, Pointer<Drag>)>,
+                     mut nodes: Query<&mut Node>,
+                     ui_scale_option: Option<Res<UiScale>>| {
+                        if !matches!(drag.button, PointerButton::Primary) {
+                            return;
+                        }
+                        let Ok(mut node) = nodes.get_mut(entity) else {
+                            return;
+                        };
+                        let delta = drag.delta / ui_scale_option.map(|ui_scale| ui_scale.0).unwrap_or(1.);
+                        let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+                        node.left = Val::Px(px(node.left) + delta.x);
+                        node.top = Val::Px(px(node.top) + delta.y);
+                    },
+                )
+        })
+    }
+}
+
+/// A pointer movement while dragging an element, see
+/// [`PointerEventAware::on_drag`].
+#[derive(Clone, Copy, Debug)]
+pub struct DragData {
+    /// Movement since the last [`DragData`], in logical pixels.
+    pub delta: Vec2,
+    /// Movement since the drag started, in logical pixels.
+    pub total: Vec2,
+    /// Button held during the drag.
+    pub button: PointerButton,
+}
+
+impl From<Drag> for DragData {
+    fn from(
+        Drag {
+            button,
+            distance,
+            delta,
+        }: Drag,
+    ) -> Self {
+        Self {
+            delta,
+            total: distance,
+            button,
+        }
+    }
 }
 
 #[derive(Component, Deref, DerefMut)]
-struct Hovered(bool);
+pub(crate) struct Hovered(pub(crate) bool);
 
 #[derive(Component, Default)]
 struct PressHandlingBlocked;
 
+/// Tracks the last dispatched cursor position of an element listening to cursor position changes.
+#[derive(Component, Default)]
+struct CursorPositionListener {
+    position: Option<Vec2>,
+}
+
+#[derive(Event)]
+struct CursorPositionChange(Option<Vec2>);
+
 /// Fires when a the pointer crosses into the bounds of the `target` entity, ignoring children.
 #[derive(Clone, PartialEq, Debug, Reflect)]
 pub struct Enter {
@@ -442,6 +898,34 @@ fn update_hover_states(
     }
 }
 
+fn cursor_position_dispatcher(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut data: Query<(
+        Entity,
+        &Hovered,
+        &ComputedNode,
+        &GlobalTransform,
+        &mut CursorPositionListener,
+    )>,
+    mut commands: Commands,
+) {
+    let cursor_option = windows.get_single().ok().and_then(Window::physical_cursor_position);
+    for (entity, hovered, computed_node, global_transform, mut listener) in data.iter_mut() {
+        let position = match cursor_option {
+            Some(cursor) if **hovered => {
+                // ui node transforms point to their center, in physical pixels
+                let top_left = global_transform.translation().truncate() - computed_node.size() / 2.;
+                Some((cursor - top_left) * computed_node.inverse_scale_factor())
+            }
+            _ => None,
+        };
+        if listener.position != position {
+            listener.position = position;
+            commands.trigger_targets(CursorPositionChange(position), entity);
+        }
+    }
+}
+
 #[derive(Component)]
 struct Pressable;
 
@@ -485,6 +969,25 @@ struct CursorOver;
 #[derive(Component, Default)]
 struct CursorDisabled;
 
+/// Configures what counts as a double click for
+/// [`PointerEventAware::on_double_click`].
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct DoubleClickSettings {
+    /// Maximum time between the two clicks.
+    pub interval: Duration,
+    /// Maximum distance, in logical pixels, the pointer can move between the two clicks.
+    pub slop: f32,
+}
+
+impl Default for DoubleClickSettings {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            slop: 4.,
+        }
+    }
+}
+
 /// When this [`Resource`] exists in the [`World`], [`CursorOnHoverable`]
 /// [`Element`]s will not trigger updates to the window's cursor when they
 /// receive a [`Pointer<Over>`] event. When this [`Resource`] is removed, the last
@@ -506,7 +1009,8 @@ pub struct CursorOnHover(Option<CursorIcon>);
 /// receives an [`Pointer<Over>`] event.
 pub trait CursorOnHoverable: PointerEventAware {
     /// When this [`Element`] receives a [`Pointer<Over>`] event, set the window's cursor to
-    /// [`Some`] [`CursorIcon`] in the [`CursorOnHover`] [`Component`] or hide it if [`None`].
+    /// [`Some`] [`CursorIcon`] in the [`CursorOnHover`] [`Component`] or hide it if [`None`],
+    /// restoring the default cursor when it receives a [`Pointer<Out>`] event while not disabled.
     /// If the [`Pointer`] is [`Over`] this element when it is disabled with a `Disabled`
     /// [`Component`], another [`Pointer<Over>`] event will be sent up the hierarchy to trigger
     /// any handlers whose propagation was previously stopped by this [`Element`].
@@ -604,17 +1108,33 @@ pub trait CursorOnHoverable: PointerEventAware {
                         }
                     },
                 )
-                .on_event_with_system_stop_propagation::<Pointer<Out>, _>(|In((entity, _)), mut commands: Commands| {
-                    if let Some(mut entity) = commands.get_entity(entity) {
-                        entity.remove::<CursorOver>();
-                    }
-                })
+                .on_event_with_system_stop_propagation::<Pointer<Out>, _>(
+                    |In((entity, _)),
+                     disabled: Query<&Disabled>,
+                     cursor_over_disabled_option: Option<Res<CursorOnHoverDisabled>>,
+                     mut commands: Commands| {
+                        if let Some(mut entity) = commands.get_entity(entity) {
+                            entity.remove::<CursorOver>();
+                        }
+                        // restore the default cursor; `Out`s are sent before `Over`s, so if the
+                        // pointer moved onto another element which sets the cursor, it still wins
+                        if disabled.contains(entity).not() {
+                            let cursor_option = Some(CursorIcon::System(SystemCursorIcon::Default));
+                            if cursor_over_disabled_option.is_none() {
+                                commands.trigger(SetCursor(cursor_option));
+                            } else {
+                                commands.insert_resource(QueuedCursor(cursor_option));
+                            }
+                        }
+                    },
+                )
         })
     }
 
     /// When this [`Element`](super::element::Element) receives a [`Pointer<Over>`] event, set the
     /// window's cursor to [`Some`] [`CursorIcon`] in the [`CursorOnHover`] [`Component`] or
-    /// hide it if [`None`].
+    /// hide it if [`None`], restoring the default cursor when it receives a [`Pointer<Out>`] event.
+    /// Requests made to the [`CursorIconController`] take precedence.
     fn cursor(self, cursor_option: impl Into<Option<CursorIcon>>) -> Self {
         self.cursor_disableable::<CursorDisabled>(cursor_option)
     }
@@ -679,6 +1199,36 @@ pub trait CursorOnHoverable: PointerEventAware {
     ) -> Self {
         self.cursor_signal_disableable_signal(always(cursor_option.into()), disabled)
     }
+
+    /// While this element exists, request that the app wide cursor be set to the [`CursorIcon`]
+    /// output by the [`Signal`], with `priority`, see [`CursorIconController`]; outputting [`None`]
+    /// withdraws the request. Useful for overriding hover cursors, e.g. with
+    /// [`SystemCursorIcon::Grabbing`] during drags.
+    fn cursor_icon_signal(
+        self,
+        priority: i32,
+        icon_option_signal: impl Signal<Item = impl Into<Option<CursorIcon>>> + Send + 'static,
+    ) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .on_signal_one_shot(
+                    icon_option_signal.map(Into::into),
+                    move |In((entity, icon_option)): In<(Entity, Option<CursorIcon>)>,
+                          mut controller: ResMut<CursorIconController>| {
+                        if let Some(icon) = icon_option {
+                            controller.request(entity, priority, Some(icon));
+                        } else {
+                            controller.release(entity);
+                        }
+                    },
+                )
+                .on_remove(|world, entity| {
+                    if let Some(mut controller) = world.get_resource_mut::<CursorIconController>() {
+                        controller.release(entity);
+                    }
+                })
+        })
+    }
 }
 
 /// [`Event`] consumed by a global [`Observer`] to set the window's [`CursorIcon`]; the cursor will
@@ -699,37 +1249,133 @@ fn consume_queued_cursor(queued_cursor: Option<Res<QueuedCursor>>, mut commands:
     }
 }
 
+struct CursorIconRequest {
+    key: Entity,
+    priority: i32,
+    icon_option: Option<CursorIcon>,
+}
+
+/// Priority stack of requests for the app wide cursor, e.g. from
+/// [`CursorOnHoverable::cursor_icon_signal`]. While there are any requests, the window's cursor is
+/// set to the highest priority one, the most recent winning ties, overriding the cursors set by
+/// [`CursorOnHoverable`] elements; once there are none, the latest such cursor is restored.
+#[derive(Resource, Default)]
+pub struct CursorIconController {
+    requests: Vec<CursorIconRequest>,
+    /// The cursor from before the first request, or the latest cursor set by [`SetCursor`] while
+    /// overridden by a request; restored once all requests are released.
+    overridden: Option<Option<CursorIcon>>,
+}
+
+impl CursorIconController {
+    /// Request the app wide cursor be set to [`Some`] [`CursorIcon`], or hidden if [`None`], with
+    /// `priority`, replacing any previous request under `key`.
+    pub fn request(&mut self, key: Entity, priority: i32, icon_option: Option<CursorIcon>) {
+        self.requests.retain(|request| request.key != key);
+        self.requests.push(CursorIconRequest {
+            key,
+            priority,
+            icon_option,
+        });
+    }
+
+    /// Withdraw the request under `key`, if any.
+    pub fn release(&mut self, key: Entity) {
+        self.requests.retain(|request| request.key != key);
+    }
+
+    /// The winning request's cursor, if there are any requests.
+    pub fn current(&self) -> Option<&Option<CursorIcon>> {
+        self.requests
+            .iter()
+            // `max_by_key` returns the last of equal maximums
+            .max_by_key(|request| request.priority)
+            .map(|request| &request.icon_option)
+    }
+}
+
+fn set_window_cursor(entity: Entity, window: &mut Window, icon_option: Option<CursorIcon>, commands: &mut Commands) {
+    if let Some(icon) = icon_option {
+        if let Some(mut window) = commands.get_entity(entity) {
+            window.try_insert(icon);
+        }
+        window.cursor_options.visible = true;
+    } else {
+        window.cursor_options.visible = false;
+    }
+}
+
 // TODO: add support for multiple windows
 fn cursor_setter(
     event: Trigger<SetCursor>,
     mut windows: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    controller_option: Option<ResMut<CursorIconController>>,
     mut commands: Commands,
 ) {
+    let SetCursor(icon_option) = event.event();
+    if let Some(mut controller) = controller_option {
+        if controller.current().is_some() {
+            controller.bypass_change_detection().overridden = Some(icon_option.clone());
+            return;
+        }
+    }
     if let Ok((entity, mut window)) = windows.get_single_mut() {
-        let SetCursor(icon_option) = event.event();
-        if let Some(icon) = icon_option.clone() {
-            if let Some(mut window) = commands.get_entity(entity) {
-                window.try_insert(icon);
+        set_window_cursor(entity, &mut window, icon_option.clone(), &mut commands);
+    }
+}
+
+fn apply_cursor_icon_controller(
+    mut controller: ResMut<CursorIconController>,
+    mut windows: Query<(Entity, &mut Window, Option<&CursorIcon>), With<PrimaryWindow>>,
+    mut commands: Commands,
+) {
+    let controller = controller.bypass_change_detection();
+    let Ok((entity, mut window, cursor_icon_option)) = windows.get_single_mut() else {
+        return;
+    };
+    let icon_option = match controller.current() {
+        Some(icon_option) => {
+            let icon_option = icon_option.clone();
+            // the first request saves the cursor it overrides, to be restored once all are released
+            if controller.overridden.is_none() {
+                controller.overridden = Some(
+                    window
+                        .cursor_options
+                        .visible
+                        .then(|| cursor_icon_option.cloned().unwrap_or_default()),
+                );
             }
-            window.cursor_options.visible = true;
-        } else {
-            window.cursor_options.visible = false;
+            icon_option
         }
-    }
+        None => match controller.overridden.take() {
+            Some(icon_option) => icon_option,
+            // no request was ever applied
+            None => return,
+        },
+    };
+    set_window_cursor(entity, &mut window, icon_option, &mut commands);
 }
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_event::<SetCursor>().add_observer(cursor_setter).add_systems(
-        Update,
-        (
-            pressable_system.run_if(any_with_component::<Pressable>),
-            update_hover_states.run_if(
-                any_with_component::<Hovered>
-                    // TODO: apparently this updates every frame no matter what, if so, remove this condition
-                    // TODO: remove when native `Enter` and `Leave` available
-                    .and(resource_exists_and_changed::<HoverMap>),
+    app.add_event::<SetCursor>()
+        .init_resource::<CursorIconController>()
+        .init_resource::<DoubleClickSettings>()
+        .add_observer(cursor_setter)
+        .add_systems(
+            Update,
+            (
+                pressable_system.run_if(any_with_component::<Pressable>),
+                update_hover_states.run_if(
+                    any_with_component::<Hovered>
+                        // TODO: apparently this updates every frame no matter what, if so, remove this condition
+                        // TODO: remove when native `Enter` and `Leave` available
+                        .and(resource_exists_and_changed::<HoverMap>),
+                ),
+                consume_queued_cursor.run_if(resource_removed::<CursorOnHoverDisabled>),
+                apply_cursor_icon_controller.run_if(resource_changed::<CursorIconController>),
+                cursor_position_dispatcher
+                    .after(update_hover_states)
+                    .run_if(any_with_component::<CursorPositionListener>),
             ),
-            consume_queued_cursor.run_if(resource_removed::<CursorOnHoverDisabled>),
-        ),
-    );
+        );
 }
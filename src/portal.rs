@@ -0,0 +1,122 @@
+//! Element which logically belongs to its position in the element tree, but spawns its contents
+//! under a different parent, see [`Portal`].
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Mutable, Signal};
+
+use super::{
+    anchorable::Anchorable,
+    el::El,
+    element::{ElementWrapper, IntoOptionElement, Nameable, UiRoot},
+    flexible::Flexible,
+    global_event_aware::GlobalEventAware,
+    layout_aware::LayoutAware,
+    pointer_event_aware::{CursorOnHoverable, PointerEventAware},
+    raw::{RawElWrapper, Spawnable},
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
+    sizeable::Sizeable,
+    spaceable::Spaceable,
+    utils::clone,
+    z_layer::ZLayerable,
+};
+
+/// [`Element`](super::element::Element) whose contents are spawned under a different parent, by
+/// default the [`UiRoot`], while remaining tied to the lifetime of its position in the element
+/// tree, i.e. its contents are despawned when it is. Useful for menus, tooltips, drag ghosts,
+/// etc. which must escape the layout and clipping of their logical parent.
+///
+/// A [`Portal`]'s contents are held in an absolutely positioned host node, to which all of the
+/// [`Portal`]'s abilities (e.g. [`Sizeable`]) apply, while a hidden placeholder node is spawned in
+/// its logical position.
+pub struct Portal {
+    el: El<Node>,
+    target: Option<Entity>,
+}
+
+impl Default for Portal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementWrapper for Portal {
+    type EL = El<Node>;
+    fn element_mut(&mut self) -> &mut Self::EL {
+        &mut self.el
+    }
+
+    fn into_el(self) -> Self::EL {
+        let Self { el: host, target } = self;
+        let host_entity = Mutable::new(None);
+        El::<Node>::new()
+            .with_node(|mut node| node.display = Display::None)
+            .update_raw_el(|raw_el| {
+                raw_el
+                    .on_spawn(clone!((host_entity) move |world, _| {
+                        let target = target.or_else(|| world.get_resource::<UiRoot>().map(|&UiRoot(ui_root)| ui_root));
+                        let host = host.spawn(world);
+                        if let Some(mut target) = target.and_then(|target| world.get_entity_mut(target).ok()) {
+                            target.add_child(host);
+                        }
+                        host_entity.set(Some(host));
+                    }))
+                    .on_remove(move |mut world, _| {
+                        if let Some(host) = host_entity.get() {
+                            if let Some(entity) = world.commands().get_entity(host) {
+                                entity.despawn_recursive();
+                            }
+                        }
+                    })
+            })
+    }
+}
+
+impl Anchorable for Portal {}
+impl CursorOnHoverable for Portal {}
+impl Flexible for Portal {}
+impl GlobalEventAware for Portal {}
+impl LayoutAware for Portal {}
+impl Nameable for Portal {}
+impl PointerEventAware for Portal {}
+impl ScrollSnappable for Portal {}
+impl Showable for Portal {}
+impl Sizeable for Portal {}
+impl Spaceable for Portal {}
+impl ZLayerable for Portal {}
+
+impl Portal {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {
+            el: El::<Node>::new().with_node(|mut node| node.position_type = PositionType::Absolute),
+            target: None,
+        }
+    }
+
+    /// Spawn this [`Portal`]'s contents under the `target` [`Entity`] instead of the [`UiRoot`].
+    pub fn target(mut self, target_option: impl Into<Option<Entity>>) -> Self {
+        if let Some(target) = target_option.into() {
+            self.target = Some(target);
+        }
+        self
+    }
+
+    /// Declare a static child of this [`Portal`]'s host node.
+    pub fn child<IOE: IntoOptionElement>(mut self, child_option: IOE) -> Self {
+        self.el = self.el.child(child_option);
+        self
+    }
+
+    /// Declare a reactive child of this [`Portal`]'s host node. When the [`Signal`] outputs
+    /// [`None`], the child is removed.
+    pub fn child_signal<IOE: IntoOptionElement + 'static, S: Signal<Item = IOE> + Send + 'static>(
+        mut self,
+        child_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        self.el = self.el.child_signal(child_option_signal_option);
+        self
+    }
+}
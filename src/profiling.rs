@@ -0,0 +1,27 @@
+//! [`tracing`](bevy_utils::tracing) spans around element spawning, signal processing, and the
+//! resulting [`World`] updates, tagged with the element's
+//! [`.name`](super::element::Nameable::name) if it has one, so profilers like Tracy show where UI
+//! time goes.
+
+use std::future::Future;
+
+use bevy_ecs::{prelude::*, world::Command};
+use bevy_utils::tracing::{info_span, Instrument, Span};
+
+/// Span around spawning an element.
+pub(crate) fn spawn_span(name: Option<&str>) -> Span {
+    info_span!("haalka::spawn", name)
+}
+
+/// Run an element's reactive task, e.g. one processing a [`Signal`](futures_signals::signal::Signal)
+/// or [`VecDiff`](futures_signals::signal_vec::VecDiff)s, in a span.
+pub(crate) fn instrument<F: Future>(future: F, name: Option<&str>) -> impl Future<Output = F::Output> {
+    future.instrument(info_span!("haalka::signal", name))
+}
+
+/// Apply `command` in a span nested under the span of the reactive task which queued it, see
+/// [`instrument`].
+pub(crate) fn in_span(label: &'static str, command: impl Command) -> impl Command {
+    let span = info_span!("haalka::apply", label);
+    move |world: &mut World| span.in_scope(|| command.apply(world))
+}
@@ -0,0 +1,114 @@
+//! Reactive access to the results of Bevy [`Query`]s, see [`query_signal`].
+
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    query::{QueryFilter, ROQueryItem, ReadOnlyQueryData},
+};
+use futures_signals::signal_vec::{MutableSignalVec, MutableVec, SignalVec, SignalVecExt, VecDiff};
+use once_cell::sync::Lazy;
+
+/// Returns whether the syncer should be kept.
+type QuerySyncer = Box<dyn FnMut(&mut World) -> bool + Send>;
+
+static QUERY_SYNCERS: Lazy<Mutex<Vec<QuerySyncer>>> = Lazy::new(Mutex::default);
+
+/// [`SignalVec`] returned by [`query_signal`]; the query stops being polled once this is dropped.
+pub struct QuerySignalVec<T> {
+    signal_vec: MutableSignalVec<T>,
+    _alive: Arc<()>,
+}
+
+impl<T: Clone> SignalVec for QuerySignalVec<T> {
+    type Item = T;
+
+    fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+        self.get_mut().signal_vec.poll_vec_change_unpin(cx)
+    }
+}
+
+/// Update `vec` to match `new` with a minimal number of diffs for the common case of a few
+/// contiguous insertions or removals.
+fn patch<T: Clone + PartialEq>(vec: &MutableVec<T>, new: Vec<T>) {
+    let mut lock = vec.lock_mut();
+    if lock.as_slice() == new.as_slice() {
+        return;
+    }
+    let prefix = lock.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = lock[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for _ in prefix..lock.len() - suffix {
+        lock.remove(prefix);
+    }
+    for (i, value) in new[prefix..new.len() - suffix].iter().enumerate() {
+        lock.insert_cloned(prefix + i, value.clone());
+    }
+}
+
+/// Outputs the `map`ped results of the [`Query`] with data `D` and filter `F`, in iteration order;
+/// the query is polled every frame and only the differences are forwarded, so lists of world
+/// entities, e.g. players, quests, or nearby items, can be fed directly to
+/// [`.children_signal_vec`](super::raw::RawHaalkaEl::children_signal_vec).
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// Column::<Node>::new().items_signal_vec(
+///     query_signal::<&Name, With<Player>, _, _>(|name| name.to_string())
+///         .map(|name| El::<Text>::new().text(Text(name))),
+/// );
+/// ```
+pub fn query_signal<
+    D: ReadOnlyQueryData + 'static,
+    F: QueryFilter + 'static,
+    T: Clone + PartialEq + Send + Sync + 'static,
+    M: for<'w> FnMut(ROQueryItem<'w, D>) -> T + Send + 'static,
+>(
+    mut map: M,
+) -> QuerySignalVec<T> {
+    let vec = MutableVec::new();
+    let alive = Arc::new(());
+    let weak: Weak<()> = Arc::downgrade(&alive);
+    let mut query_state_option: Option<QueryState<D, F>> = None;
+    QUERY_SYNCERS.lock().unwrap().push(Box::new({
+        let vec = vec.clone();
+        move |world| {
+            if weak.upgrade().is_none() {
+                return false;
+            }
+            let query_state = query_state_option.get_or_insert_with(|| QueryState::new(world));
+            patch(&vec, query_state.iter(world).map(&mut map).collect());
+            true
+        }
+    }));
+    QuerySignalVec {
+        signal_vec: vec.signal_vec_cloned(),
+        _alive: alive,
+    }
+}
+
+fn sync_query_signals(world: &mut World) {
+    QUERY_SYNCERS.lock().unwrap().retain_mut(|sync| sync(world));
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Last,
+        sync_query_signals.run_if(|| !QUERY_SYNCERS.lock().unwrap().is_empty()),
+    );
+}
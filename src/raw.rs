@@ -4,11 +4,15 @@
 //! [`System`]s all using a declarative builder pattern/[fluent interface](https://en.wikipedia.org/wiki/Fluent_interface).
 //! Port of [MoonZoon](https://github.com/MoonZoon/MoonZoon)'s [`raw_el`](https://github.com/MoonZoon/MoonZoon/tree/fc73b0d90bf39be72e70fdcab4f319ea5b8e6cfc/crates/zoon/src/element/raw_el).
 
-use std::{future::Future, marker::PhantomData, mem};
+use std::{future::Future, marker::PhantomData, mem, time::Duration};
 
 use super::{
-    node_builder::{async_world, NodeBuilder, TaskHolder},
+    batching::BatchedUpdates,
+    component_signal::sync_component_of,
+    future_signal::{future_signal, FutureState},
+    node_builder::{async_world, profiled, NodeBuilder, TaskHolder},
     raw::utils::remove_system_holder_on_remove,
+    time::time_signal,
 };
 use apply::Apply;
 use bevy_ecs::{component::*, prelude::*, system::*, world::*};
@@ -18,6 +22,7 @@ use bevy_utils::prelude::*;
 use enclose::enclose as clone;
 use futures_signals::{
     signal::{Mutable, Signal, SignalExt},
+    signal_map::{SignalMap, SignalMapExt},
     signal_vec::{SignalVec, SignalVecExt},
 };
 use haalka_futures_signals_ext::SignalExtBool;
@@ -115,7 +120,7 @@ impl RawHaalkaEl {
     /// Consume this [`RawHaalkaEl`], running its deferred updaters and returning the underlying
     /// [`NodeBuilder`].
     pub fn into_node_builder(mut self) -> NodeBuilder {
-        let deferred_updaters = self.deferred_updaters.drain(..).collect::<Vec<_>>();
+        let deferred_updaters = mem::take(&mut self.deferred_updaters);
         let mut self_ = self;
         for updater in deferred_updaters {
             self_ = updater(self_);
@@ -213,9 +218,36 @@ impl RawHaalkaEl {
         })
     }
 
+    /// Don't process this element's signals while `paused` is `true`, see
+    /// [`NodeBuilder::pause_signals`].
+    pub fn pause_signals(self, paused: Mutable<bool>) -> Self {
+        self.update_node_builder(|node_builder| node_builder.pause_signals(paused))
+    }
+
+    /// Spread spawning this element's children across frames, spending at most about `budget` on
+    /// them per frame, see [`NodeBuilder::children_spawn_budget`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let spawned = Mutable::new(false);
+    /// Column::<Node>::new()
+    ///     .update_raw_el(|raw_el| raw_el.children_spawn_budget(Duration::from_millis(4), spawned.clone()))
+    ///     .items((0..2000).map(|i| El::<Text>::new().text(Text(i.to_string()))));
+    /// ```
+    pub fn children_spawn_budget(self, budget: Duration, spawned: impl Into<Option<Mutable<bool>>>) -> Self {
+        let spawned = spawned.into();
+        self.update_node_builder(|node_builder| node_builder.children_spawn_budget(budget, spawned))
+    }
+
     /// Drop the [`Task`] when it completes or the entity is despawned.
     pub fn hold_tasks(self, tasks: impl IntoIterator<Item = Task<()>> + Send + 'static) -> Self {
-        self.with_component::<TaskHolder>(|task_holder| {
+        self.with_entity(|mut entity| {
+            let task_holder = entity.entry::<TaskHolder>().or_default();
             for task in tasks.into_iter() {
                 task_holder.hold(task);
             }
@@ -256,6 +288,61 @@ impl RawHaalkaEl {
         })
     }
 
+    /// Run a function with this element's [`Entity`] every `period` after it is spawned, according
+    /// to the default [`Time`](bevy_time::Time) clock; stops when this element is despawned. The
+    /// function is run at most once per frame, so a zero `period` runs it every frame.
+    pub fn interval(self, period: Duration, mut f: impl FnMut(Entity) + Send + 'static) -> Self {
+        // a zero period would divide by zero, the smallest nonzero one ticks every frame instead
+        let period = period.max(Duration::from_nanos(1));
+        let mut start_option = None;
+        self.on_signal_sync(
+            time_signal()
+                .map(move |elapsed| {
+                    let start = *start_option.get_or_insert(elapsed);
+                    ((elapsed - start).as_secs_f64() / period.as_secs_f64()) as u64
+                })
+                .dedupe(),
+            move |entity, periods| {
+                if periods > 0 {
+                    f(entity)
+                }
+            },
+        )
+    }
+
+    /// Run a function with this element's [`Entity`] once `duration` has passed after it is spawned,
+    /// according to the default [`Time`](bevy_time::Time) clock; if this element is despawned
+    /// before then, the function is never run. A zero `duration` runs the function on the first
+    /// frame after this element is spawned.
+    pub fn timeout(self, duration: Duration, f: impl FnOnce(Entity) + Send + 'static) -> Self {
+        let mut start_option = None;
+        let mut f_option = Some(f);
+        self.on_signal_sync(
+            time_signal()
+                .map(move |elapsed| elapsed - *start_option.get_or_insert(elapsed) >= duration)
+                .dedupe()
+                .stop_if(|&done| done),
+            move |entity, done| {
+                if done {
+                    if let Some(f) = f_option.take() {
+                        f(entity)
+                    }
+                }
+            },
+        )
+    }
+
+    /// Run `future` on the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool), running a
+    /// function with this element's [`Entity`] and its progress, see [`future_signal`]; if this
+    /// element is despawned before `future` completes, it is cancelled.
+    pub fn bind_future<T: Clone + Send + Sync + 'static, E: Clone + Send + Sync + 'static>(
+        self,
+        future: impl Future<Output = Result<T, E>> + Send + 'static,
+        f: impl FnMut(Entity, FutureState<T, E>) + Send + 'static,
+    ) -> Self {
+        self.on_signal_sync(future_signal(future), f)
+    }
+
     /// Reactively run a [`System`] which takes [`In`](`System::In`) this element's [`Entity`] and
     /// the output of the [`Signal`].
     pub fn on_signal_one_shot<T: Send + 'static, Marker>(
@@ -270,10 +357,13 @@ impl RawHaalkaEl {
         .on_signal(
             signal,
             clone!((system_holder) move |entity, input| {
-                async_world().apply(RunSystemWithInput::new_with_input(
-                    // TODO: would caching this in a Local via SystemState be better/faster ?
-                    system_holder.get().unwrap(),
-                    (entity, input),
+                async_world().apply(profiled(
+                    "on_signal_one_shot",
+                    RunSystemWithInput::new_with_input(
+                        // TODO: would caching this in a Local via SystemState be better/faster ?
+                        system_holder.get().unwrap(),
+                        (entity, input),
+                    ),
                 ))
             }),
         )
@@ -386,22 +476,75 @@ impl RawHaalkaEl {
     /// Reactively set this element's `C` [`Component`]. If the [`Signal`] outputs [`None`], the `C`
     /// [`Component`] is removed.
     pub fn component_signal<C: Component, S: Signal<Item = impl Into<Option<C>>> + Send + 'static>(
-        mut self,
+        self,
         component_option_signal_option: impl Into<Option<S>>,
     ) -> Self {
-        if let Some(component_option_signal) = component_option_signal_option.into() {
-            // TODO: need partial_eq derivations for all the node related components to minimize updates
-            // with .dedupe
-            self = self.on_signal_with_entity::<Option<C>>(
-                component_option_signal.map(|into_component_option| into_component_option.into()),
-                move |mut entity, component_option| {
-                    if let Some(component) = component_option {
+        self.component_signal_with(component_option_signal_option, |entity, component_option| {
+            if let Some(component) = component_option {
+                entity.insert(component);
+            } else {
+                entity.remove::<C>();
+            }
+        })
+    }
+
+    /// Reactively set this element's `C` [`Component`], skipping outputs equal to its current `C`
+    /// [`Component`] so that signals which repeat values don't touch it, e.g. triggering change
+    /// detection. If the [`Signal`] outputs [`None`], the `C` [`Component`] is removed.
+    pub fn component_signal_dedupe<
+        C: Component + PartialEq,
+        S: Signal<Item = impl Into<Option<C>>> + Send + 'static,
+    >(
+        self,
+        component_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        self.component_signal_with(
+            component_option_signal_option,
+            |entity, component_option| match component_option {
+                Some(component) => {
+                    if entity.get::<C>() != Some(&component) {
                         entity.insert(component);
-                    } else {
+                    }
+                }
+                None => {
+                    if entity.contains::<C>() {
                         entity.remove::<C>();
                     }
-                },
-            );
+                }
+            },
+        )
+    }
+
+    fn component_signal_with<C: Component, S: Signal<Item = impl Into<Option<C>>> + Send + 'static>(
+        mut self,
+        component_option_signal_option: impl Into<Option<S>>,
+        f: fn(&mut EntityWorldMut, Option<C>),
+    ) -> Self {
+        if let Some(component_option_signal) = component_option_signal_option.into() {
+            // whether the [`SignalBatchingPlugin`](super::batching::SignalBatchingPlugin) was added
+            // is only known once spawned
+            let batched_updates_holder = Mutable::new(None);
+            self = self
+                .on_spawn(clone!((batched_updates_holder) move |world, _| {
+                    batched_updates_holder.set(world.get_resource::<BatchedUpdates>().cloned());
+                }))
+                .on_signal(
+                    component_option_signal.map(|into_component_option| into_component_option.into()),
+                    move |entity, component_option: Option<C>| {
+                        let update = move |world: &mut World| {
+                            if let Ok(mut entity) = world.get_entity_mut(entity) {
+                                f(&mut entity, component_option);
+                            }
+                        };
+                        let batched_updates_option = batched_updates_holder.get_cloned();
+                        async move {
+                            match batched_updates_option {
+                                Some(batched_updates) => batched_updates.batch::<C>(entity, update),
+                                None => async_world().apply(profiled("component_signal", update)).await,
+                            }
+                        }
+                    },
+                );
         }
         self
     }
@@ -427,6 +570,12 @@ impl RawHaalkaEl {
         )
     }
 
+    /// Keep `mutable` and this element's `C` [`Component`] in sync in both directions, see
+    /// [`sync_component_of`].
+    pub fn sync_component<C: Component + Clone + PartialEq>(self, mutable: Mutable<C>) -> Self {
+        self.on_spawn(move |_, entity| sync_component_of(entity, mutable))
+    }
+
     /// Reactively send an [`Event`] based on this element's [`Entity`] and the output of the
     /// [`Signal`].
     pub fn on_signal_send_event<T, E: Event>(
@@ -690,6 +839,19 @@ impl RawHaalkaEl {
             )
         })
     }
+
+    /// Declare reactive children keyed by the keys of a [`SignalMap`], ordered by key; each key
+    /// keeps its child until it is removed or its value is updated.
+    pub fn children_signal_map<K: Ord + Send + 'static, IRE: IntoRawElement>(
+        self,
+        children_signal_map: impl SignalMap<Key = K, Value = IRE> + Send + 'static,
+    ) -> Self {
+        self.update_node_builder(|node_builder| {
+            node_builder.children_signal_map(
+                children_signal_map.map_value(|child| child.into_raw_element().into_raw().into_node_builder()),
+            )
+        })
+    }
 }
 
 #[allow(clippy::type_complexity)]
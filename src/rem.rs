@@ -0,0 +1,100 @@
+//! Lengths relative to a global, reactive base size, see [`Rem`] and [`RemSize`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+use once_cell::sync::Lazy;
+
+use super::spaceable::Edges;
+
+/// [`Resource`] specifying the logical pixel length of one [`Rem`]; change it to rescale every
+/// [`Rem`] length in the UI at once, e.g. for accessibility.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct RemSize(pub f32);
+
+/// Default logical pixel length of one [`Rem`].
+pub const DEFAULT_REM_SIZE: f32 = 16.;
+
+impl Default for RemSize {
+    fn default() -> Self {
+        Self(DEFAULT_REM_SIZE)
+    }
+}
+
+static REM_SIZE: Lazy<Mutable<f32>> = Lazy::new(|| Mutable::new(DEFAULT_REM_SIZE));
+
+/// Logical pixel length of one [`Rem`], as specified by the [`RemSize`] [`Resource`].
+pub fn rem_size_signal() -> impl Signal<Item = f32> + Send + 'static {
+    REM_SIZE.signal()
+}
+
+/// Length in multiples of the [`RemSize`]; use [`Rem::signal`] with the `_signal` variant of a
+/// setter to keep the length in sync with the [`RemSize`]. Static setters, e.g.
+/// [`.width`](super::sizeable::Sizeable::width), also accept a [`Rem`] directly, which is converted
+/// with [`Rem::snapshot_val`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// El::<Node>::new()
+///     .min_width(Rem(10.))
+///     .width_signal(Rem(20.).signal())
+///     .height_signal(Rem(2.5).signal())
+///     .padding_signal(Rem(1.).signal().map(Edges::from));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rem(pub f32);
+
+impl Rem {
+    /// This length in logical pixels, as of the current [`RemSize`].
+    pub fn px(self) -> f32 {
+        self.0 * REM_SIZE.get()
+    }
+
+    /// [`Val`] of this length as of the current [`RemSize`]; a snapshot which does not track later
+    /// changes to the [`RemSize`], see [`Rem::signal`].
+    pub fn snapshot_val(self) -> Val {
+        Val::Px(self.px())
+    }
+
+    /// Reactive [`Val`] of this length, updated whenever the [`RemSize`] changes.
+    pub fn signal(self) -> impl Signal<Item = Val> + Send + 'static {
+        rem_size_signal().map(move |rem_size| Val::Px(self.0 * rem_size))
+    }
+}
+
+impl From<Rem> for Val {
+    fn from(rem: Rem) -> Self {
+        rem.snapshot_val()
+    }
+}
+
+impl From<Rem> for Option<Val> {
+    fn from(rem: Rem) -> Self {
+        Some(rem.into())
+    }
+}
+
+impl From<Rem> for Edges {
+    fn from(rem: Rem) -> Self {
+        Val::from(rem).into()
+    }
+}
+
+impl From<Rem> for Option<Edges> {
+    fn from(rem: Rem) -> Self {
+        Some(rem.into())
+    }
+}
+
+fn sync_rem_size(rem_size: Res<RemSize>) {
+    REM_SIZE.set_neq(rem_size.0);
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RemSize>()
+        .add_systems(PreUpdate, sync_rem_size.run_if(resource_changed::<RemSize>));
+}
@@ -0,0 +1,217 @@
+//! Resizing elements by dragging their edges and corners, see [`Resizable`].
+
+use std::ops::BitOr;
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_math::{IVec2, Vec2};
+use bevy_picking::prelude::*;
+use bevy_ui::{prelude::*, ComputedNode, UiScale};
+use bevy_window::SystemCursorIcon;
+use bevy_winit::cursor::CursorIcon;
+
+use super::{el::El, pointer_event_aware::CursorOnHoverable, raw::RawElWrapper};
+
+/// Thickness, in logical pixels, of the invisible grab zones added by [`Resizable::resizable`];
+/// they straddle the element's border.
+pub const RESIZE_HANDLE_THICKNESS: f32 = 8.;
+
+/// Set of element edges which can be dragged to resize it; corners between two selected edges can
+/// also be dragged. Combine with `|`, e.g. `ResizeEdges::RIGHT | ResizeEdges::BOTTOM`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub struct ResizeEdges {
+    pub top: bool,
+    pub bottom: bool,
+    pub left: bool,
+    pub right: bool,
+}
+
+#[allow(missing_docs)]
+impl ResizeEdges {
+    pub const TOP: Self = Self {
+        top: true,
+        bottom: false,
+        left: false,
+        right: false,
+    };
+    pub const BOTTOM: Self = Self {
+        top: false,
+        bottom: true,
+        left: false,
+        right: false,
+    };
+    pub const LEFT: Self = Self {
+        top: false,
+        bottom: false,
+        left: true,
+        right: false,
+    };
+    pub const RIGHT: Self = Self {
+        top: false,
+        bottom: false,
+        left: false,
+        right: true,
+    };
+    pub const ALL: Self = Self {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+    };
+}
+
+impl BitOr for ResizeEdges {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            top: self.top || other.top,
+            bottom: self.bottom || other.bottom,
+            left: self.left || other.left,
+            right: self.right || other.right,
+        }
+    }
+}
+
+/// Sent to a [`Resizable`] element with its new size, in logical pixels, whenever it is resized by
+/// dragging one of its grab zones.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Resized(pub Vec2);
+
+/// Minimum and maximum size, in logical pixels, of a [`Resizable`] element.
+#[derive(Component, Clone, Copy)]
+struct ResizeConstraints {
+    min: Vec2,
+    max: Vec2,
+}
+
+/// Which edges a grab zone moves; `-1` for the left or top edge, `1` for the right or bottom edge.
+#[derive(Component, Clone, Copy)]
+struct ResizeHandle(IVec2);
+
+fn handle(direction: IVec2) -> El<Node> {
+    let thickness = Val::Px(RESIZE_HANDLE_THICKNESS);
+    let offset = Val::Px(-RESIZE_HANDLE_THICKNESS / 2.);
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        ..Node::default()
+    };
+    match direction.x {
+        -1 => (node.left, node.width) = (offset, thickness),
+        1 => (node.right, node.width) = (offset, thickness),
+        _ => (node.left, node.right) = (Val::ZERO, Val::ZERO),
+    }
+    match direction.y {
+        -1 => (node.top, node.height) = (offset, thickness),
+        1 => (node.bottom, node.height) = (offset, thickness),
+        _ => (node.top, node.bottom) = (Val::ZERO, Val::ZERO),
+    }
+    let cursor = match (direction.x, direction.y) {
+        (_, 0) => SystemCursorIcon::EwResize,
+        (0, _) => SystemCursorIcon::NsResize,
+        (x, y) if x == y => SystemCursorIcon::NwseResize,
+        _ => SystemCursorIcon::NeswResize,
+    };
+    El::<Node>::from(node)
+        .update_raw_el(|raw_el| {
+            raw_el
+                // corners above edges above content
+                .insert((
+                    ResizeHandle(direction),
+                    PickingBehavior::default(),
+                    ZIndex(if direction.x != 0 && direction.y != 0 { 2 } else { 1 }),
+                ))
+                .on_event_with_system_stop_propagation::<Pointer<Drag>, _>(resize)
+        })
+        .cursor(CursorIcon::System(cursor))
+}
+
+fn resize(
+    In((entity, drag)): In<(Entity, Pointer<Drag>)>,
+    handles: Query<(&ResizeHandle, &Parent)>,
+    mut nodes: Query<(&mut Node, &ComputedNode, &ResizeConstraints)>,
+    ui_scale_option: Option<Res<UiScale>>,
+    mut commands: Commands,
+) {
+    if !matches!(drag.button, PointerButton::Primary) {
+        return;
+    }
+    let Ok((&ResizeHandle(direction), parent)) = handles.get(entity) else {
+        return;
+    };
+    let Ok((mut node, computed_node, constraints)) = nodes.get_mut(parent.get()) else {
+        return;
+    };
+    let current = computed_node.size() * computed_node.inverse_scale_factor();
+    let delta = drag.delta / ui_scale_option.map(|ui_scale| ui_scale.0).unwrap_or(1.) * direction.as_vec2();
+    let new = (current + delta).clamp(constraints.min, constraints.max);
+    let applied = new - current;
+    if applied == Vec2::ZERO {
+        return;
+    }
+    if direction.x != 0 {
+        node.width = Val::Px(new.x);
+    }
+    if direction.y != 0 {
+        node.height = Val::Px(new.y);
+    }
+    // keep the opposite edge in place when dragging the left or top edge of an absolutely
+    // positioned element
+    if node.position_type == PositionType::Absolute {
+        let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+        if direction.x == -1 {
+            node.left = Val::Px(px(node.left) - applied.x);
+        }
+        if direction.y == -1 {
+            node.top = Val::Px(px(node.top) - applied.y);
+        }
+    }
+    commands.trigger_targets(Resized(new), parent.get());
+}
+
+/// Enables resizing an element by dragging its edges and corners.
+pub trait Resizable: RawElWrapper {
+    /// Add invisible grab zones along `edges`, and the corners between them, which update this
+    /// element's [`Node::width`] and [`Node::height`] when dragged with the primary pointer button.
+    fn resizable(self, edges: ResizeEdges) -> Self {
+        self.resizable_constrained(edges, Vec2::ZERO, Vec2::INFINITY)
+    }
+
+    /// Add invisible grab zones along `edges`, and the corners between them, which update this
+    /// element's [`Node::width`] and [`Node::height`] when dragged with the primary pointer button,
+    /// keeping its size, in logical pixels, between `min` and `max`.
+    fn resizable_constrained(self, edges: ResizeEdges, min: Vec2, max: Vec2) -> Self {
+        let mut directions = vec![];
+        let xs = [(edges.left, -1), (edges.right, 1)];
+        let ys = [(edges.top, -1), (edges.bottom, 1)];
+        for (selected, x) in xs {
+            if selected {
+                directions.push(IVec2::new(x, 0));
+            }
+        }
+        for (selected, y) in ys {
+            if selected {
+                directions.push(IVec2::new(0, y));
+            }
+        }
+        for (x_selected, x) in xs {
+            for (y_selected, y) in ys {
+                if x_selected && y_selected {
+                    directions.push(IVec2::new(x, y));
+                }
+            }
+        }
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .insert((ResizeConstraints { min, max }, PickingBehavior::default()))
+                .children(directions.into_iter().map(handle))
+        })
+    }
+
+    /// When this element is resized by dragging one of its grab zones, run a function with its new
+    /// size, in logical pixels. This method can be called repeatedly to register many such handlers.
+    fn on_resize(self, mut handler: impl FnMut(Vec2) + Send + Sync + 'static) -> Self {
+        self.update_raw_el(|raw_el| raw_el.on_event::<Resized>(move |Resized(size)| handler(size)))
+    }
+}
@@ -0,0 +1,224 @@
+//! Reactive access to ordinary Bevy [`Resource`]s, see [`ResourceSignal`] and [`sync_resource`].
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    marker::PhantomData,
+    ops::Deref,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll},
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{component::Tick, prelude::*};
+use futures_signals::signal::{Mutable, MutableSignalCloned, Signal, SignalExt};
+use once_cell::sync::Lazy;
+
+struct ResourceSyncer {
+    mutable: Box<dyn Any + Send + Sync>,
+    sync: Box<dyn FnMut(&World, Tick, Tick) + Send>,
+    last_run: Tick,
+    /// Dead once every [`ResourceMutableSignal`] of `mutable` is dropped.
+    alive: Weak<()>,
+}
+
+static RESOURCE_SYNCERS: Lazy<Mutex<HashMap<TypeId, ResourceSyncer>>> = Lazy::new(Mutex::default);
+
+struct TwoWayResourceSyncer {
+    sync: Box<dyn FnMut(&mut World, Tick, Tick) + Send>,
+    last_run: Tick,
+    /// Dead once every [`SyncedResource`] of the synced [`Mutable`] is dropped.
+    alive: Weak<()>,
+}
+
+/// At most one per [`Resource`], see [`sync_resource`].
+static TWO_WAY_RESOURCE_SYNCERS: Lazy<Mutex<HashMap<TypeId, TwoWayResourceSyncer>>> = Lazy::new(Mutex::default);
+
+/// Adapter for binding UI to an ordinary [`Resource`] without hand written sync systems; the
+/// resource is cloned into the signal every frame that it is changed.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Score(u32);
+///
+/// El::<Text>::new().text_signal(
+///     ResourceSignal::<Score>::signal()
+///         .map(|score_option| Text(score_option.map(|Score(score)| score).unwrap_or_default().to_string())),
+/// );
+/// ```
+pub struct ResourceSignal<R>(PhantomData<R>);
+
+/// [`Signal`] returned by [`resource_mutable_signal`]; the [`Resource`] stops being tracked once all
+/// of these for the same [`Resource`] and `map` are dropped.
+pub(crate) struct ResourceMutableSignal<T> {
+    signal: MutableSignalCloned<Option<T>>,
+    _alive: Arc<()>,
+}
+
+impl<T: Clone> Signal for ResourceMutableSignal<T> {
+    type Item = Option<T>;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.get_mut().signal.poll_change_unpin(cx)
+    }
+}
+
+/// Outputs the `map`ped [`Resource`] `R` whenever `R` changes; there is only one [`Mutable`] per `R`
+/// and `T` pair, so `map` should be the same for all callers.
+pub(crate) fn resource_mutable_signal<R: Resource, T: Clone + Send + Sync + 'static>(
+    map: fn(&R) -> T,
+) -> ResourceMutableSignal<T> {
+    let mut syncers = RESOURCE_SYNCERS.lock().unwrap();
+    let key = TypeId::of::<(R, T)>();
+    if let Some(alive) = syncers.get(&key).and_then(|syncer| syncer.alive.upgrade()) {
+        let mutable = syncers[&key].mutable.downcast_ref::<Mutable<Option<T>>>().unwrap();
+        return ResourceMutableSignal {
+            signal: mutable.signal_cloned(),
+            _alive: alive,
+        };
+    }
+    let mutable = Mutable::new(None);
+    let alive = Arc::new(());
+    syncers.insert(
+        key,
+        ResourceSyncer {
+            mutable: Box::new(mutable.clone()),
+            sync: Box::new({
+                let mutable = mutable.clone();
+                move |world, last_run, this_run| {
+                    if world
+                        .get_resource_change_ticks::<R>()
+                        .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+                    {
+                        mutable.set(world.get_resource::<R>().map(map));
+                    }
+                }
+            }),
+            last_run: Tick::new(0),
+            alive: Arc::downgrade(&alive),
+        },
+    );
+    ResourceMutableSignal {
+        signal: mutable.signal_cloned(),
+        _alive: alive,
+    }
+}
+
+impl<R: Resource + Clone> ResourceSignal<R> {
+    /// Outputs a clone of the [`Resource`] `R` whenever it changes, or [`None`] if `R` has not
+    /// been inserted yet.
+    pub fn signal() -> impl Signal<Item = Option<R>> + Send + 'static {
+        resource_mutable_signal::<R, R>(R::clone)
+    }
+}
+
+/// [`Mutable`] kept in sync with a [`Resource`] by [`sync_resource`]; syncing stops once it and all
+/// its clones are dropped, so hold on to it, e.g. by moving it into the signals which use it.
+#[derive(Clone)]
+pub struct SyncedResource<R> {
+    mutable: Mutable<R>,
+    _alive: Arc<()>,
+}
+
+impl<R> Deref for SyncedResource<R> {
+    type Target = Mutable<R>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mutable
+    }
+}
+
+/// Keep `mutable` and the [`Resource`] `R` in sync in both directions, replacing hand written sync
+/// systems, for as long as the returned [`SyncedResource`] or one of its clones is alive. If `R`
+/// already exists, `mutable` is initialized from it, otherwise `R` is inserted with the value of
+/// `mutable`; once synced, `R` is not reinserted if it is removed, and syncing resumes if it is
+/// inserted again. Values are only forwarded when they differ from the last synced value, so
+/// changes never echo back and forth; if both sides change in the same frame, `R` wins. Only the
+/// latest `mutable` synced with `R` is kept in sync.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Resource, Clone, PartialEq)]
+/// struct Volume(f32);
+///
+/// let volume = sync_resource(Mutable::new(Volume(1.)));
+/// El::<Text>::new().text_signal(volume.signal_ref(|Volume(volume)| Text(format!("{volume:.2}"))));
+/// ```
+pub fn sync_resource<R: Resource + Clone + PartialEq>(mutable: Mutable<R>) -> SyncedResource<R> {
+    let alive = Arc::new(());
+    let mut last_option: Option<R> = None;
+    TWO_WAY_RESOURCE_SYNCERS.lock().unwrap().insert(
+        TypeId::of::<R>(),
+        TwoWayResourceSyncer {
+            sync: Box::new({
+                let mutable = mutable.clone();
+                move |world, last_run, this_run| {
+                    let Some(ticks) = world.get_resource_change_ticks::<R>() else {
+                        // only inserted by the first sync, so removing `R` is respected
+                        if last_option.is_none() {
+                            let value = mutable.get_cloned();
+                            last_option = Some(value.clone());
+                            world.insert_resource(value);
+                        }
+                        return;
+                    };
+                    if ticks.is_changed(last_run, this_run) {
+                        let resource = world.resource::<R>();
+                        if last_option.as_ref() != Some(resource) {
+                            last_option = Some(resource.clone());
+                            mutable.set(resource.clone());
+                            return;
+                        }
+                    }
+                    let value = mutable.get_cloned();
+                    if last_option.as_ref() != Some(&value) {
+                        last_option = Some(value.clone());
+                        world.insert_resource(value);
+                    }
+                }
+            }),
+            last_run: Tick::new(0),
+            alive: Arc::downgrade(&alive),
+        },
+    );
+    SyncedResource { mutable, _alive: alive }
+}
+
+fn sync_resource_signals(world: &mut World) {
+    let this_run = world.change_tick();
+    let mut syncers = RESOURCE_SYNCERS.lock().unwrap();
+    syncers.retain(|_, syncer| syncer.alive.strong_count() > 0);
+    for syncer in syncers.values_mut() {
+        (syncer.sync)(world, syncer.last_run, this_run);
+        syncer.last_run = this_run;
+    }
+}
+
+fn sync_resources(world: &mut World) {
+    let this_run = world.change_tick();
+    let mut syncers = TWO_WAY_RESOURCE_SYNCERS.lock().unwrap();
+    syncers.retain(|_, syncer| syncer.alive.strong_count() > 0);
+    for syncer in syncers.values_mut() {
+        (syncer.sync)(world, syncer.last_run, this_run);
+        syncer.last_run = this_run;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Last,
+        (
+            sync_resources.run_if(|| !TWO_WAY_RESOURCE_SYNCERS.lock().unwrap().is_empty()),
+            sync_resource_signals.run_if(|| !RESOURCE_SYNCERS.lock().unwrap().is_empty()),
+        )
+            .chain(),
+    );
+}
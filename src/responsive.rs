@@ -0,0 +1,117 @@
+//! Semantics for reactively switching between layouts depending on the width of the primary
+//! window, see [`Breakpoints`] and [`breakpoint_signal`].
+//!
+//! # Example
+//! ```
+//! use bevy::prelude::*;
+//! use haalka::prelude::*;
+//!
+//! El::<Node>::new()
+//!     .on_signal_with_node(breakpoint_signal(), |mut node, breakpoint| {
+//!         node.padding = UiRect::all(Val::Px(breakpoint.select(5., 10., 20.)));
+//!     })
+//!     .child_signal(responsive(
+//!         || El::<Text>::new().text(Text::new("phone")),
+//!         || El::<Text>::new().text(Text::new("tablet")),
+//!         || El::<Text>::new().text(Text::new("desktop")),
+//!     ));
+//! ```
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_window::{PrimaryWindow, Window, WindowResized};
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+use once_cell::sync::Lazy;
+
+/// Coarse classification of the primary window's width, see [`Breakpoints`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    #[allow(missing_docs)]
+    Phone,
+    #[allow(missing_docs)]
+    Tablet,
+    #[allow(missing_docs)]
+    #[default]
+    Desktop,
+}
+
+impl Breakpoint {
+    /// Pick the value corresponding to this [`Breakpoint`].
+    pub fn select<T>(self, phone: T, tablet: T, desktop: T) -> T {
+        match self {
+            Breakpoint::Phone => phone,
+            Breakpoint::Tablet => tablet,
+            Breakpoint::Desktop => desktop,
+        }
+    }
+}
+
+/// [`Resource`] specifying the logical primary window widths at which the current [`Breakpoint`]
+/// changes; widths below [`tablet`](Breakpoints::tablet) are [`Breakpoint::Phone`], widths below
+/// [`desktop`](Breakpoints::desktop) are [`Breakpoint::Tablet`], and all other widths are
+/// [`Breakpoint::Desktop`].
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct Breakpoints {
+    /// Minimum width of [`Breakpoint::Tablet`].
+    pub tablet: f32,
+    /// Minimum width of [`Breakpoint::Desktop`].
+    pub desktop: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            tablet: 600.,
+            desktop: 1024.,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// The [`Breakpoint`] corresponding to the given logical width.
+    pub fn breakpoint(&self, width: f32) -> Breakpoint {
+        if width < self.tablet {
+            Breakpoint::Phone
+        } else if width < self.desktop {
+            Breakpoint::Tablet
+        } else {
+            Breakpoint::Desktop
+        }
+    }
+}
+
+static BREAKPOINT: Lazy<Mutable<Breakpoint>> = Lazy::new(Mutable::default);
+
+/// Current [`Breakpoint`] of the primary window, as determined by the [`Breakpoints`]
+/// [`Resource`]. Only outputs when the [`Breakpoint`] changes, not on every resize.
+pub fn breakpoint_signal() -> impl Signal<Item = Breakpoint> + Send + 'static {
+    BREAKPOINT.signal()
+}
+
+/// Reactively switch between values, e.g. [`Element`](super::element::Element)s, depending on the
+/// current [`Breakpoint`]; the corresponding function is called each time the [`Breakpoint`]
+/// changes.
+pub fn responsive<T>(
+    mut phone: impl FnMut() -> T + Send + 'static,
+    mut tablet: impl FnMut() -> T + Send + 'static,
+    mut desktop: impl FnMut() -> T + Send + 'static,
+) -> impl Signal<Item = T> + Send + 'static {
+    breakpoint_signal().map(move |breakpoint| match breakpoint {
+        Breakpoint::Phone => phone(),
+        Breakpoint::Tablet => tablet(),
+        Breakpoint::Desktop => desktop(),
+    })
+}
+
+fn update_breakpoint(windows: Query<&Window, With<PrimaryWindow>>, breakpoints: Res<Breakpoints>) {
+    if let Ok(window) = windows.get_single() {
+        BREAKPOINT.set_neq(breakpoints.breakpoint(window.width()));
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Breakpoints>().add_systems(
+        Update,
+        update_breakpoint.run_if(resource_changed::<Breakpoints>.or(on_event::<WindowResized>)),
+    );
+}
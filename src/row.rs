@@ -3,18 +3,33 @@ use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
 use futures_signals::{
     signal::{Signal, SignalExt},
+    signal_map::{SignalMap, SignalMapExt},
     signal_vec::{SignalVec, SignalVecExt},
 };
 
 use super::{
     align::{AddRemove, AlignHolder, Alignable, Aligner, Alignment, ChildAlignable},
-    element::{IntoOptionElement, Nameable, UiRootable},
+    anchorable::Anchorable,
+    element::{IntoElement, IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gappable::Gappable,
+    gesture::GestureAware,
     global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    kinetic_scroll::KineticScrollable,
+    layout_aware::LayoutAware,
     mouse_wheel_scrollable::MouseWheelScrollable,
     pointer_event_aware::{CursorOnHoverable, PointerEventAware},
     raw::{RawElWrapper, RawHaalkaEl},
+    resizable::Resizable,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
     sizeable::Sizeable,
+    spaceable::Spaceable,
     viewport_mutable::ViewportMutable,
+    z_layer::ZLayerable,
 };
 
 /// [`Element`](super::element::Element) with horizontally stacked children. Port of [MoonZoon](https://github.com/MoonZoon/MoonZoon)'s [`Row`](https://github.com/MoonZoon/MoonZoon/blob/main/crates/zoon/src/element/row.rs).
@@ -63,14 +78,28 @@ impl<NodeType: Bundle> RawElWrapper for Row<NodeType> {
     }
 }
 
+impl<NodeType: Bundle> Anchorable for Row<NodeType> {}
 impl<NodeType: Bundle> CursorOnHoverable for Row<NodeType> {}
+impl<NodeType: Bundle> Feedbackable for Row<NodeType> {}
+impl<NodeType: Bundle> Flexible for Row<NodeType> {}
+impl<NodeType: Bundle> Gappable for Row<NodeType> {}
+impl<NodeType: Bundle> GestureAware for Row<NodeType> {}
 impl<NodeType: Bundle> GlobalEventAware for Row<NodeType> {}
+impl<NodeType: Bundle> KeyboardEventAware for Row<NodeType> {}
+impl<NodeType: Bundle> KeyboardFocusable for Row<NodeType> {}
+impl<NodeType: Bundle> KineticScrollable for Row<NodeType> {}
+impl<NodeType: Bundle> LayoutAware for Row<NodeType> {}
 impl<NodeType: Bundle> Nameable for Row<NodeType> {}
 impl<NodeType: Bundle> PointerEventAware for Row<NodeType> {}
 impl<NodeType: Bundle> MouseWheelScrollable for Row<NodeType> {}
+impl<NodeType: Bundle> Resizable for Row<NodeType> {}
+impl<NodeType: Bundle> ScrollSnappable for Row<NodeType> {}
+impl<NodeType: Bundle> Showable for Row<NodeType> {}
 impl<NodeType: Bundle> Sizeable for Row<NodeType> {}
+impl<NodeType: Bundle> Spaceable for Row<NodeType> {}
 impl<NodeType: Bundle> UiRootable for Row<NodeType> {}
 impl<NodeType: Bundle> ViewportMutable for Row<NodeType> {}
+impl<NodeType: Bundle> ZLayerable for Row<NodeType> {}
 
 impl<NodeType: Bundle> Row<NodeType> {
     /// Declare a static horizontally stacked child.
@@ -138,6 +167,25 @@ impl<NodeType: Bundle> Row<NodeType> {
         self
     }
 
+    /// Declare reactive horizontally stacked children keyed by the keys of the [`SignalMap`],
+    /// ordered by key; each key keeps its child until it is removed or its value is updated.
+    pub fn items_signal_map<
+        K: Ord + Send + 'static,
+        IE: IntoElement + 'static,
+        S: SignalMap<Key = K, Value = IE> + Send + 'static,
+    >(
+        mut self,
+        items_signal_map_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(items_signal_map) = items_signal_map_option.into() {
+            let apply_alignment = self.apply_alignment_wrapper();
+            self.raw_el = self.raw_el.children_signal_map(
+                items_signal_map.map_value(move |item| Self::align_child(item.into_element(), apply_alignment)),
+            );
+        }
+        self
+    }
+
     /// When the width of the row exceeds the width of its parent, wrap the row's children to the
     /// next line, recursively.
     pub fn multiline(mut self) -> Self {
@@ -197,6 +245,12 @@ impl<NodeType: Bundle> Alignable for Row<NodeType> {
                     AddRemove::Remove => AlignItems::DEFAULT,
                 }
             }
+            Alignment::Baseline => {
+                node.align_items = match action {
+                    AddRemove::Add => AlignItems::Baseline,
+                    AddRemove::Remove => AlignItems::DEFAULT,
+                }
+            }
         }
     }
 }
@@ -240,6 +294,12 @@ impl<NodeType: Bundle> ChildAlignable for Row<NodeType> {
                     AddRemove::Remove => AlignSelf::DEFAULT,
                 }
             }
+            Alignment::Baseline => {
+                node.align_self = match action {
+                    AddRemove::Add => AlignSelf::Baseline,
+                    AddRemove::Remove => AlignSelf::DEFAULT,
+                }
+            }
         }
     }
 }
@@ -0,0 +1,159 @@
+//! Semantics for settling [mutable viewport](super::viewport_mutable::ViewportMutable)s on child
+//! boundaries after they are scrolled, e.g. for paged lists and carousels, see [`ScrollSnappable`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_math::prelude::*;
+use bevy_transform::prelude::*;
+use bevy_ui::prelude::*;
+
+use super::{
+    anchorable::logical_rect,
+    mouse_wheel_scrollable::ScrollDirection,
+    raw::{DeferredUpdaterAppendDirection, RawElWrapper},
+    viewport_mutable::{MutableViewport, ViewportMutation},
+};
+
+/// Which part of a snap point child is aligned with the viewport when a scroll snapping
+/// viewport settles on it.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum SnapAlign {
+    Start,
+    Center,
+    End,
+}
+
+/// Number of consecutive frames a scroll snapping viewport must be left unmoved before it is
+/// snapped.
+pub const SCROLL_SNAP_SETTLE_FRAMES: u32 = 6;
+
+#[derive(Component)]
+struct ScrollSnap {
+    direction: ScrollDirection,
+    last_position: Vec2,
+    still_frames: u32,
+}
+
+/// Enables [mutable viewport](super::viewport_mutable::ViewportMutable)s to settle on the
+/// boundaries of their [`SnapAlign`]ed children after scrolling.
+pub trait ScrollSnappable: RawElWrapper {
+    /// Snap this element's viewport to its nearest [`SnapAlign`]ed child along the `direction`
+    /// once it has stopped moving for [`SCROLL_SNAP_SETTLE_FRAMES`].
+    ///
+    /// # Notes
+    /// Requires [`.mutable_viewport(...)`](super::viewport_mutable::ViewportMutable::mutable_viewport).
+    fn scroll_snap(self, direction: ScrollDirection) -> Self {
+        self.update_raw_el(|raw_el| {
+            raw_el.insert(ScrollSnap {
+                direction,
+                last_position: Vec2::ZERO,
+                still_frames: 0,
+            })
+        })
+    }
+
+    /// Mark this element as a snap point of its parent's scroll snapping viewport, aligning its
+    /// [`SnapAlign`] side with the viewport.
+    fn snap_align(mut self, snap_align_option: impl Into<Option<SnapAlign>>) -> Self {
+        if let Some(snap_align) = snap_align_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.insert(snap_align)
+                })
+            });
+        }
+        self
+    }
+}
+
+fn px(val: Val) -> f32 {
+    if let Val::Px(px) = val {
+        px
+    } else {
+        0.
+    }
+}
+
+fn nearest_snap(position: f32, candidates: impl Iterator<Item = f32>) -> Option<f32> {
+    candidates.min_by(|a, b| (a - position).abs().total_cmp(&(b - position).abs()))
+}
+
+#[allow(clippy::type_complexity)]
+fn settle_scroll_snaps(
+    mut scenes: Query<(Entity, &mut ScrollSnap, &Node, &Children, &Parent), With<MutableViewport>>,
+    rects: Query<(&ComputedNode, &GlobalTransform)>,
+    snap_aligns: Query<&SnapAlign>,
+    mut commands: Commands,
+) {
+    for (entity, mut scroll_snap, node, children, parent) in scenes.iter_mut() {
+        // viewport offsets are the negation of the scene's offsets
+        let position = -Vec2::new(px(node.left), px(node.top));
+        if position != scroll_snap.last_position {
+            scroll_snap.last_position = position;
+            scroll_snap.still_frames = 0;
+            continue;
+        }
+        // already settled
+        if scroll_snap.still_frames == SCROLL_SNAP_SETTLE_FRAMES {
+            continue;
+        }
+        scroll_snap.still_frames += 1;
+        if scroll_snap.still_frames < SCROLL_SNAP_SETTLE_FRAMES {
+            continue;
+        }
+        let (Ok(scene), Ok(viewport)) = (
+            rects.get(entity).map(|(c, g)| logical_rect(c, g)),
+            rects.get(parent.get()).map(|(c, g)| logical_rect(c, g)),
+        ) else {
+            continue;
+        };
+        // snap point candidates in scene space, i.e. viewport positions
+        let snaps = children
+            .iter()
+            .filter_map(|&child| {
+                snap_aligns
+                    .get(child)
+                    .ok()
+                    .zip(rects.get(child).ok().map(|(c, g)| logical_rect(c, g)))
+            })
+            .map(|(snap_align, child)| {
+                let (min, max) = (child.min - scene.min, child.max - scene.min);
+                match snap_align {
+                    SnapAlign::Start => min,
+                    SnapAlign::Center => (min + max - viewport.size()) / 2.,
+                    SnapAlign::End => max - viewport.size(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut mutation = ViewportMutation::default();
+        let mut snapped = false;
+        if matches!(
+            scroll_snap.direction,
+            ScrollDirection::Horizontal | ScrollDirection::Both
+        ) {
+            if let Some(x) = nearest_snap(position.x, snaps.iter().map(|snap| snap.x)) {
+                if (x - position.x).abs() > 0.5 {
+                    mutation = mutation.with_x(-x);
+                    snapped = true;
+                }
+            }
+        }
+        if matches!(scroll_snap.direction, ScrollDirection::Vertical | ScrollDirection::Both) {
+            if let Some(y) = nearest_snap(position.y, snaps.iter().map(|snap| snap.y)) {
+                if (y - position.y).abs() > 0.5 {
+                    mutation = mutation.with_y(-y);
+                    snapped = true;
+                }
+            }
+        }
+        if snapped {
+            commands.trigger_targets(mutation, entity);
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, settle_scroll_snaps.run_if(any_with_component::<ScrollSnap>));
+}
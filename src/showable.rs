@@ -0,0 +1,217 @@
+//! Semantics for cheaply hiding and showing elements without despawning them, see [`Showable`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_render::prelude::*;
+use bevy_state::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+
+use super::{
+    raw::{DeferredUpdaterAppendDirection, RawElWrapper, RawHaalkaEl},
+    state::state_signal,
+};
+
+/// Holds the [`Display`] an element had before it was hidden with [`Showable`].
+#[derive(Component)]
+struct HiddenDisplay(Display);
+
+fn set_shown(entity: &mut EntityWorldMut, show: bool) {
+    let Some(display) = entity.get::<Node>().map(|node| node.display) else {
+        return;
+    };
+    if show {
+        if display == Display::None {
+            let display = entity
+                .take::<HiddenDisplay>()
+                .map(|HiddenDisplay(display)| display)
+                .unwrap_or_default();
+            if let Some(mut node) = entity.get_mut::<Node>() {
+                node.display = display;
+            }
+        }
+    } else if display != Display::None {
+        entity.insert(HiddenDisplay(display));
+        if let Some(mut node) = entity.get_mut::<Node>() {
+            node.display = Display::None;
+        }
+    }
+}
+
+/// Reactively show or hide `raw_el`, see [`Showable::show_signal`].
+pub(crate) fn raw_show_signal(
+    raw_el: RawHaalkaEl,
+    show_signal: impl Signal<Item = bool> + Send + 'static,
+) -> RawHaalkaEl {
+    raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+        raw_el.on_signal_with_entity(show_signal, |mut entity, show| set_shown(&mut entity, show))
+    })
+}
+
+/// [`Mutable`]s set to `true` the first time their element is shown, see [`on_first_shown`].
+#[derive(Component, Default)]
+struct ShownListeners(Vec<Mutable<bool>>);
+
+/// Set `shown` to `true` the first time `raw_el` is shown, i.e. neither it nor any of its
+/// ancestors have [`Display::None`] or resolve to [`Visibility::Hidden`].
+pub(crate) fn on_first_shown(raw_el: RawHaalkaEl, shown: Mutable<bool>) -> RawHaalkaEl {
+    raw_el.on_spawn(move |world, entity| {
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.entry::<ShownListeners>().or_default().0.push(shown);
+        }
+    })
+}
+
+fn is_shown(entity: Entity, nodes: &Query<(Option<&Node>, Option<&Visibility>)>, parents: &Query<&Parent>) -> bool {
+    let mut visibility_resolved = false;
+    for entity in std::iter::once(entity).chain(parents.iter_ancestors(entity)) {
+        let Ok((node_option, visibility_option)) = nodes.get(entity) else {
+            continue;
+        };
+        if node_option.is_some_and(|node| node.display == Display::None) {
+            return false;
+        }
+        if !visibility_resolved {
+            match visibility_option {
+                Some(Visibility::Hidden) => return false,
+                Some(Visibility::Visible) => visibility_resolved = true,
+                _ => (),
+            }
+        }
+    }
+    true
+}
+
+/// Whether an element is paused by [`Showable::pause_signals_when_hidden`].
+#[derive(Component)]
+struct PauseWhenHidden(Mutable<bool>);
+
+fn pause_hidden(
+    pause_when_hiddens: Query<(Entity, &PauseWhenHidden)>,
+    nodes: Query<(Option<&Node>, Option<&Visibility>)>,
+    parents: Query<&Parent>,
+) {
+    for (entity, PauseWhenHidden(paused)) in pause_when_hiddens.iter() {
+        paused.set_neq(!is_shown(entity, &nodes, &parents));
+    }
+}
+
+fn notify_shown(
+    mut shown_listeners: Query<(Entity, &mut ShownListeners)>,
+    nodes: Query<(Option<&Node>, Option<&Visibility>)>,
+    parents: Query<&Parent>,
+    mut commands: Commands,
+) {
+    for (entity, mut shown_listeners) in shown_listeners.iter_mut() {
+        if is_shown(entity, &nodes, &parents) {
+            for shown in shown_listeners.0.drain(..) {
+                shown.set(true);
+            }
+            commands.entity(entity).remove::<ShownListeners>();
+        }
+    }
+}
+
+/// Enables an element to be hidden and shown without despawning it or its children, with
+/// consideration for any potential [haalka](crate) managed wrapper nodes.
+///
+/// This is a cheaper alternative to swapping elements in and out with
+/// [`.child_signal`](super::el::El::child_signal) for frequently toggled content, at the cost of
+/// keeping the hidden element's entities (and their reactivity) alive.
+pub trait Showable: RawElWrapper {
+    /// Show or hide this element by toggling its [`Display`] between [`Display::None`] and
+    /// whatever it was before it was hidden; hidden elements take up no space in the layout.
+    fn show(mut self, show_option: impl Into<Option<bool>>) -> Self {
+        if let Some(show) = show_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_entity(move |mut entity| set_shown(&mut entity, show))
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively show or hide this element, see [`.show`](Showable::show).
+    fn show_signal<S: Signal<Item = bool> + Send + 'static>(
+        mut self,
+        show_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(show_signal) = show_signal_option.into() {
+            self = self.update_raw_el(|raw_el| raw_show_signal(raw_el, show_signal));
+        }
+        self
+    }
+
+    /// Only show this element, see [`.show`](Showable::show), while the app is in the [`State`]
+    /// `state`, e.g. for menus and HUDs which are only relevant to certain screens.
+    fn show_in_state<S: States>(self, state: S) -> Self {
+        self.show_signal(state_signal::<S>().map(move |current_option| current_option.as_ref() == Some(&state)))
+    }
+
+    /// Don't process this element's signals, e.g. those of its
+    /// [`.on_signal...`](super::raw::RawHaalkaEl::on_signal) and `.child...` methods, while it is
+    /// hidden, i.e. it or any of its ancestors have [`Display::None`] or resolve to
+    /// [`Visibility::Hidden`], so hidden menus don't spend time processing bindings nobody can see.
+    /// Upon being shown, only the latest output of each [`Signal`] is processed.
+    ///
+    /// # Notes
+    /// Only pauses the signals declared on this element itself, not those of its children; tasks
+    /// added with [`.hold_tasks`](super::raw::RawHaalkaEl::hold_tasks) are also not paused.
+    fn pause_signals_when_hidden(self) -> Self {
+        self.update_raw_el(|raw_el| {
+            let paused = Mutable::new(false);
+            raw_el.pause_signals(paused.clone()).insert(PauseWhenHidden(paused))
+        })
+    }
+
+    /// Make this element visible or invisible by toggling its [`Visibility`] between
+    /// [`Visibility::Inherited`] and [`Visibility::Hidden`]; unlike [`.show`](Showable::show),
+    /// invisible elements still take up space in the layout.
+    fn visible(mut self, visible_option: impl Into<Option<bool>>) -> Self {
+        if let Some(visible) = visible_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.insert(if visible {
+                        Visibility::Inherited
+                    } else {
+                        Visibility::Hidden
+                    })
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively make this element visible or invisible, see [`.visible`](Showable::visible).
+    fn visible_signal<S: Signal<Item = bool> + Send + 'static>(
+        mut self,
+        visible_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(visible_signal) = visible_signal_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<bool, Visibility>(visible_signal, |mut visibility, visible| {
+                        *visibility = if visible {
+                            Visibility::Inherited
+                        } else {
+                            Visibility::Hidden
+                        };
+                    })
+                })
+            });
+        }
+        self
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            notify_shown.run_if(any_with_component::<ShownListeners>),
+            pause_hidden.run_if(any_with_component::<PauseWhenHidden>),
+        ),
+    );
+}
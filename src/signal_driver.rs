@@ -0,0 +1,145 @@
+//! Configurable polling of all [haalka](crate) futures by an executor owned by the [`App`], either
+//! driven by a few long-lived tasks or local to the main thread, see [`SignalExecutor`].
+
+use std::{cell::RefCell, future::Future, rc::Rc, sync::Arc};
+
+use async_executor::{Executor, LocalExecutor};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_tasks::{IoTaskPool, Task};
+
+/// Where [haalka](crate) polls the futures spawned with [`spawn`](super::utils::spawn), e.g. every
+/// element's signal [`Task`]s, see [`HaalkaPlugin::executor`](super::HaalkaPlugin::executor).
+#[derive(Clone, Copy, Default, Debug)]
+pub enum SignalExecutor {
+    /// Schedule each future as its own [`IoTaskPool`] task.
+    #[default]
+    TaskPool,
+    /// Poll all futures on an executor owned by the [`App`] and driven by this many long-lived
+    /// [`IoTaskPool`] tasks, rather than scheduling each future as its own task; reduces task pool
+    /// churn for UIs with thousands of bindings.
+    Driven(usize),
+    /// Poll all futures on a [`LocalExecutor`] owned by the [`App`], until none can make progress,
+    /// on the main thread in [`PreUpdate`] and again in [`PostUpdate`] before UI layout. Futures
+    /// never move between threads, so those spawned with
+    /// [`spawn_local`](super::utils::spawn_local) needn't be [`Send`], which suits wasm and other
+    /// single threaded targets, and those woken by [`Mutable`](futures_signals::signal::Mutable)
+    /// changes made in [`Update`] are polled within the same frame.
+    ///
+    /// # Notes
+    /// Futures spawned off the main thread, e.g. while building elements in a system which doesn't
+    /// have exclusive [`World`] access, are still scheduled on the [`IoTaskPool`].
+    Local,
+}
+
+#[derive(Resource)]
+struct DrivenExecutor(Arc<Executor<'static>>);
+
+// non send resource
+struct MainThreadExecutor(Rc<LocalExecutor<'static>>);
+
+// the executor of the [`App`] which last ran on this thread, see [`enter`]
+#[derive(Clone)]
+enum Spawner {
+    Driven(Arc<Executor<'static>>),
+    Local(Rc<LocalExecutor<'static>>),
+}
+
+thread_local! {
+    static SPAWNER: RefCell<Option<Spawner>> = const { RefCell::new(None) };
+}
+
+// guards against futures which always wake themselves hanging the frame
+const MAX_TICKS: usize = 100_000;
+
+pub(super) fn plugin(app: &mut App, executor: SignalExecutor) {
+    match executor {
+        SignalExecutor::TaskPool => (),
+        SignalExecutor::Driven(drivers) => {
+            let executor = Arc::new(Executor::new());
+            app.insert_resource(DrivenExecutor(executor.clone()))
+                .add_systems(PreStartup, move || {
+                    for _ in 0..drivers.max(1) {
+                        let executor = executor.clone();
+                        IoTaskPool::get()
+                            .spawn(async move { executor.run(std::future::pending::<()>()).await })
+                            .detach();
+                    }
+                });
+        }
+        SignalExecutor::Local => {
+            #[cfg(feature = "ui")]
+            let tick_post_update = tick_main_thread_executor.before(bevy_ui::UiSystem::Layout);
+            #[cfg(not(feature = "ui"))]
+            let tick_post_update = tick_main_thread_executor;
+            app.insert_non_send_resource(MainThreadExecutor(Rc::new(LocalExecutor::new())))
+                .add_systems(PreUpdate, tick_main_thread_executor)
+                .add_systems(PostUpdate, tick_post_update);
+        }
+    }
+    // futures spawned before the app runs, e.g. by other plugins, also go to its executor
+    enter(app.world_mut());
+    app.add_systems(PreStartup, enter).add_systems(First, enter);
+}
+
+// make this world's executor the one futures spawned on this thread go to, so apps sharing a thread
+// each use their own; exclusive, so it runs on the main thread
+fn enter(world: &mut World) {
+    let spawner = match world.get_resource::<DrivenExecutor>() {
+        Some(DrivenExecutor(executor)) => Some(Spawner::Driven(executor.clone())),
+        None => world
+            .get_non_send_resource::<MainThreadExecutor>()
+            .map(|MainThreadExecutor(executor)| Spawner::Local(executor.clone())),
+    };
+    SPAWNER.with(|current| *current.borrow_mut() = spawner);
+}
+
+fn tick_main_thread_executor(executor: NonSend<MainThreadExecutor>) {
+    for _ in 0..MAX_TICKS {
+        if !executor.0.try_tick() {
+            break;
+        }
+    }
+}
+
+fn current_spawner() -> Option<Spawner> {
+    SPAWNER.with(|current| current.borrow().clone())
+}
+
+fn into_task<T: 'static>(task: async_executor::Task<T>) -> Task<T> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Task::new(task)
+    }
+    // bevy's wasm tasks can't wrap an executor's task, so await it from one instead
+    #[cfg(target_arch = "wasm32")]
+    {
+        IoTaskPool::get().spawn(task)
+    }
+}
+
+/// Spawn `future` onto the executor of the [`App`] running on this thread, see
+/// [`SignalExecutor`], or onto the [`IoTaskPool`] if there isn't one.
+pub(crate) fn spawn_driven<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> Task<T> {
+    match current_spawner() {
+        Some(Spawner::Driven(executor)) => into_task(executor.spawn(future)),
+        Some(Spawner::Local(executor)) => into_task(executor.spawn(future)),
+        None => IoTaskPool::get().spawn(future),
+    }
+}
+
+/// Spawn `future`, which needn't be [`Send`], onto the [`LocalExecutor`] of the [`App`] running on
+/// this thread, see [`SignalExecutor::Local`]; on wasm, falls back to the [`IoTaskPool`].
+///
+/// # Panics
+/// If not on wasm and this thread isn't running an [`App`] with [`SignalExecutor::Local`], e.g. off
+/// the main thread.
+pub(crate) fn spawn_local_driven<T: 'static>(future: impl Future<Output = T> + 'static) -> Task<T> {
+    match current_spawner() {
+        Some(Spawner::Local(executor)) => into_task(executor.spawn(future)),
+        #[cfg(target_arch = "wasm32")]
+        _ => IoTaskPool::get().spawn(future),
+        #[cfg(not(target_arch = "wasm32"))]
+        _ => panic!("`spawn_local` requires the main thread of an `App` using `SignalExecutor::Local`"),
+    }
+}
@@ -0,0 +1,383 @@
+//! [`SignalVec`] helpers whose behavior is itself reactive, see [`LiveSignalVecExt`], in place
+//! patching of mapped values, see [`PatchSignalVecExt`], and debugging utilities, see
+//! [`DiffLogger`].
+
+use std::{
+    cmp::Ordering,
+    collections::VecDeque,
+    fmt::Debug,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bevy_log::prelude::*;
+
+use futures_signals::{
+    map_ref,
+    signal::{Mutable, ReadOnlyMutable, Signal},
+    signal_vec::{SignalVec, SignalVecExt, VecDiff},
+};
+
+use super::time::{elapsed, frame_count};
+
+/// Minimal [`VecDiff`]s turning `old` into `new` for the common case of a few contiguous
+/// insertions, removals, or moves.
+pub(crate) fn diff<T: Clone + PartialEq>(old: &[T], new: &[T]) -> Vec<VecDiff<T>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let removals = old.len() - prefix - suffix;
+    let insertions = &new[prefix..new.len() - suffix];
+    // replacing everything is cheaper to apply than many individual changes
+    if removals + insertions.len() > new.len() {
+        return vec![VecDiff::Replace { values: new.to_vec() }];
+    }
+    let mut diffs = Vec::with_capacity(removals + insertions.len());
+    diffs.extend((0..removals).map(|_| VecDiff::RemoveAt { index: prefix }));
+    diffs.extend(insertions.iter().enumerate().map(|(i, value)| VecDiff::InsertAt {
+        index: prefix + i,
+        value: value.clone(),
+    }));
+    diffs
+}
+
+/// [`SignalVec`] which forwards the contents output by a [`Signal`] of [`Vec`]s as minimal
+/// [`VecDiff`]s.
+pub struct DiffedSignalVec<T, S> {
+    signal: Option<Pin<Box<S>>>,
+    previous: Option<Vec<T>>,
+    queue: VecDeque<VecDiff<T>>,
+}
+
+// fields are never structurally pinned
+impl<T, S> Unpin for DiffedSignalVec<T, S> {}
+
+impl<T: Clone + PartialEq, S: Signal<Item = Vec<T>>> DiffedSignalVec<T, S> {
+    /// Forward the contents output by `signal` as minimal [`VecDiff`]s.
+    pub fn new(signal: S) -> Self {
+        Self {
+            signal: Some(Box::pin(signal)),
+            previous: None,
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq, S: Signal<Item = Vec<T>>> SignalVec for DiffedSignalVec<T, S> {
+    type Item = T;
+
+    fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(diff) = this.queue.pop_front() {
+                return Poll::Ready(Some(diff));
+            }
+            let Some(signal) = this.signal.as_mut() else {
+                return Poll::Ready(None);
+            };
+            match signal.as_mut().poll_change(cx) {
+                Poll::Ready(Some(new)) => {
+                    match &this.previous {
+                        Some(previous) => this.queue.extend(diff(previous, &new)),
+                        None => this.queue.push_back(VecDiff::Replace { values: new.clone() }),
+                    }
+                    this.previous = Some(new);
+                }
+                Poll::Ready(None) => this.signal = None,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+type BoxedVecSignal<T> = Pin<Box<dyn Signal<Item = Vec<T>> + Send>>;
+
+/// [`SignalVec`] helpers whose comparators and predicates are themselves [`Signal`]s, e.g. the
+/// current sort column of a table; when either the contents or the comparator/predicate change,
+/// only minimal [`VecDiff`]s are output.
+pub trait LiveSignalVecExt: SignalVec + Send + Sized + 'static
+where
+    Self::Item: Clone + PartialEq + Send,
+{
+    /// Sort the contents with the latest comparator output by `cmp_signal`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let scores = MutableVec::new_with_values(vec![3, 1, 2]);
+    /// let descending = Mutable::new(false);
+    /// Column::<Node>::new().items_signal_vec(
+    ///     scores
+    ///         .signal_vec()
+    ///         .sort_by_signal(descending.signal().map(|descending| {
+    ///             move |a: &u32, b: &u32| if descending { b.cmp(a) } else { a.cmp(b) }
+    ///         }))
+    ///         .map(|score| El::<Text>::new().text(Text(score.to_string()))),
+    /// );
+    /// ```
+    fn sort_by_signal<F: Fn(&Self::Item, &Self::Item) -> Ordering + Send + 'static>(
+        self,
+        cmp_signal: impl Signal<Item = F> + Send + 'static,
+    ) -> DiffedSignalVec<Self::Item, BoxedVecSignal<Self::Item>> {
+        let signal: BoxedVecSignal<Self::Item> = Box::pin(map_ref! {
+            let values = self.to_signal_cloned(),
+            let cmp = cmp_signal => {
+                let mut values = values.clone();
+                values.sort_by(cmp);
+                values
+            }
+        });
+        DiffedSignalVec::new(signal)
+    }
+
+    /// Retain only the contents for which the latest predicate output by `predicate_signal` returns
+    /// `true`.
+    fn filter_signal<P: Fn(&Self::Item) -> bool + Send + 'static>(
+        self,
+        predicate_signal: impl Signal<Item = P> + Send + 'static,
+    ) -> DiffedSignalVec<Self::Item, BoxedVecSignal<Self::Item>> {
+        let signal: BoxedVecSignal<Self::Item> = Box::pin(map_ref! {
+            let values = self.to_signal_cloned(),
+            let predicate = predicate_signal => {
+                values.iter().filter(|value| predicate(value)).cloned().collect::<Vec<_>>()
+            }
+        });
+        DiffedSignalVec::new(signal)
+    }
+}
+
+impl<S: SignalVec + Send + 'static> LiveSignalVecExt for S where S::Item: Clone + PartialEq + Send {}
+
+/// A [`VecDiff`] recorded by a [`DiffLogger`].
+#[derive(Clone, Debug)]
+pub struct DiffLogEntry {
+    /// Frame during which the diff was output.
+    pub frame: u64,
+    /// Time elapsed since the app started, according to the default
+    /// [`Time`](bevy_time::Time) clock, when the diff was output.
+    pub elapsed: Duration,
+    /// [`Debug`] representation of the diff.
+    pub diff: String,
+    /// Length of the vec after the diff was applied.
+    pub len: usize,
+}
+
+/// Records the [`VecDiff`]s output by [`SignalVec`]s passed through
+/// [`.log_diffs`](DiffLoggerExt::log_diffs), e.g. for diagnosing the order of children from a
+/// [`.children_signal_vec`](super::raw::RawHaalkaEl::children_signal_vec); each diff is also
+/// logged at the debug level.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let items = MutableVec::new_with_values(vec![1, 2, 3]);
+/// let logger = DiffLogger::new("items");
+/// Column::<Node>::new().items_signal_vec(
+///     items
+///         .signal_vec()
+///         .log_diffs(&logger)
+///         .map(|item| El::<Text>::new().text(Text(item.to_string()))),
+/// );
+/// // later
+/// for entry in logger.entries() {
+///     println!("{entry:?}");
+/// }
+/// ```
+#[derive(Clone)]
+pub struct DiffLogger {
+    label: Arc<str>,
+    entries: Arc<Mutex<Vec<DiffLogEntry>>>,
+}
+
+impl DiffLogger {
+    /// Create a logger whose log messages are prefixed with `label`.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into().into(),
+            entries: Arc::default(),
+        }
+    }
+
+    /// The diffs recorded so far, in the order they were output.
+    pub fn entries(&self) -> Vec<DiffLogEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Forget the diffs recorded so far.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn record<T: Debug>(&self, diff: &VecDiff<T>, len: usize) {
+        let entry = DiffLogEntry {
+            frame: frame_count(),
+            elapsed: elapsed(),
+            diff: format!("{diff:?}"),
+            len,
+        };
+        debug!("{} (frame {}): {} -> len {}", self.label, entry.frame, entry.diff, len);
+        self.entries.lock().unwrap().push(entry);
+    }
+}
+
+/// [`SignalVec`] returned by [`DiffLoggerExt::log_diffs`].
+pub struct LoggedSignalVec<S> {
+    signal_vec: Pin<Box<S>>,
+    logger: DiffLogger,
+    len: usize,
+}
+
+// fields are never structurally pinned
+impl<S> Unpin for LoggedSignalVec<S> {}
+
+impl<S: SignalVec> SignalVec for LoggedSignalVec<S>
+where
+    S::Item: Debug,
+{
+    type Item = S::Item;
+
+    fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+        let this = self.get_mut();
+        let poll = this.signal_vec.as_mut().poll_vec_change(cx);
+        if let Poll::Ready(Some(diff)) = &poll {
+            this.len = match diff {
+                VecDiff::Replace { values } => values.len(),
+                VecDiff::InsertAt { .. } | VecDiff::Push { .. } => this.len + 1,
+                VecDiff::RemoveAt { .. } | VecDiff::Pop {} => this.len.saturating_sub(1),
+                VecDiff::Clear {} => 0,
+                VecDiff::UpdateAt { .. } | VecDiff::Move { .. } => this.len,
+            };
+            this.logger.record(diff, this.len);
+        }
+        poll
+    }
+}
+
+/// Recording of the [`VecDiff`]s output by [`SignalVec`]s, see [`DiffLogger`].
+pub trait DiffLoggerExt: SignalVec + Sized {
+    /// Record every [`VecDiff`] output by this signal vec with `logger`.
+    fn log_diffs(self, logger: &DiffLogger) -> LoggedSignalVec<Self> {
+        LoggedSignalVec {
+            signal_vec: Box::pin(self),
+            logger: logger.clone(),
+            len: 0,
+        }
+    }
+}
+
+impl<S: SignalVec> DiffLoggerExt for S {}
+
+/// [`SignalVec`] returned by [`PatchSignalVecExt::map_patched`].
+pub struct PatchedSignalVec<S: SignalVec, F> {
+    signal_vec: Pin<Box<S>>,
+    mutables: Vec<Mutable<S::Item>>,
+    f: F,
+}
+
+// fields are never structurally pinned
+impl<S: SignalVec, F> Unpin for PatchedSignalVec<S, F> {}
+
+impl<S: SignalVec, O, F: FnMut(ReadOnlyMutable<S::Item>) -> O> SignalVec for PatchedSignalVec<S, F> {
+    type Item = O;
+
+    fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+        let this = self.get_mut();
+        loop {
+            let diff = match this.signal_vec.as_mut().poll_vec_change(cx) {
+                Poll::Ready(Some(diff)) => diff,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            let diff = match diff {
+                VecDiff::Replace { values } => {
+                    this.mutables = values.into_iter().map(Mutable::new).collect();
+                    VecDiff::Replace {
+                        values: this
+                            .mutables
+                            .iter()
+                            .map(|mutable| (this.f)(mutable.read_only()))
+                            .collect(),
+                    }
+                }
+                VecDiff::InsertAt { index, value } => {
+                    let mutable = Mutable::new(value);
+                    let value = (this.f)(mutable.read_only());
+                    this.mutables.insert(index, mutable);
+                    VecDiff::InsertAt { index, value }
+                }
+                VecDiff::Push { value } => {
+                    let mutable = Mutable::new(value);
+                    let value = (this.f)(mutable.read_only());
+                    this.mutables.push(mutable);
+                    VecDiff::Push { value }
+                }
+                // patched in place, so nothing is output
+                VecDiff::UpdateAt { index, value } => {
+                    if let Some(mutable) = this.mutables.get(index) {
+                        mutable.set(value);
+                    }
+                    continue;
+                }
+                VecDiff::Move { old_index, new_index } => {
+                    let mutable = this.mutables.remove(old_index);
+                    this.mutables.insert(new_index, mutable);
+                    VecDiff::Move { old_index, new_index }
+                }
+                VecDiff::RemoveAt { index } => {
+                    this.mutables.remove(index);
+                    VecDiff::RemoveAt { index }
+                }
+                VecDiff::Pop {} => {
+                    this.mutables.pop();
+                    VecDiff::Pop {}
+                }
+                VecDiff::Clear {} => {
+                    this.mutables.clear();
+                    VecDiff::Clear {}
+                }
+            };
+            return Poll::Ready(Some(diff));
+        }
+    }
+}
+
+/// Mapping of [`SignalVec`]s whose updated values patch existing children in place, see
+/// [`PatchSignalVecExt::map_patched`].
+pub trait PatchSignalVecExt: SignalVec + Sized {
+    /// Map each value to, e.g., a child element, from a read only view of the value; rather than
+    /// replacing the mapped value, [`VecDiff::UpdateAt`]s set the view, so a child which derives
+    /// its reactive bindings from the view keeps its [`Entity`](bevy_ecs::entity::Entity), and with
+    /// it its focus, scroll state, and animations, without respawning or relayout.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let names = MutableVec::new_with_values(vec!["alice".to_string(), "bob".to_string()]);
+    /// Column::<Node>::new().items_signal_vec(names.signal_vec_cloned().map_patched(|name| {
+    ///     El::<Text>::new().text_signal(name.signal_cloned().map(Text))
+    /// }));
+    /// // patches the existing text rather than respawning it
+    /// names.lock_mut().set_cloned(1, "carol".to_string());
+    /// ```
+    fn map_patched<O, F: FnMut(ReadOnlyMutable<Self::Item>) -> O>(self, f: F) -> PatchedSignalVec<Self, F> {
+        PatchedSignalVec {
+            signal_vec: Box::pin(self),
+            mutables: Vec::new(),
+            f,
+        }
+    }
+}
+
+impl<S: SignalVec> PatchSignalVecExt for S {}
@@ -59,7 +59,7 @@ pub trait Sizeable: RawElWrapper {
 
     /// Reactively set the width of this element. If the signal outputs [`None`] the width is set to
     /// [`Val::Auto`].
-    fn width_signal<S: Signal<Item = Val> + Send + 'static>(
+    fn width_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
         mut self,
         width_option_signal_option: impl Into<Option<S>>,
     ) -> Self {
@@ -76,4 +76,132 @@ pub trait Sizeable: RawElWrapper {
         }
         self
     }
+
+    /// Set the minimum height of this element.
+    fn min_height(mut self, min_height_option: impl Into<Option<Val>>) -> Self {
+        if let Some(min_height) = min_height_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.min_height = min_height)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the minimum height of this element. If the signal outputs [`None`] the minimum height is
+    /// set to [`Val::Auto`].
+    fn min_height_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        min_height_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(min_height_option_signal) = min_height_option_signal_option.into() {
+            let min_height_option_signal = min_height_option_signal.map(|min_height_option| min_height_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Val>, Node>(
+                        min_height_option_signal,
+                        move |mut node, min_height_option| node.min_height = min_height_option.unwrap_or(Val::Auto),
+                    )
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the maximum height of this element.
+    fn max_height(mut self, max_height_option: impl Into<Option<Val>>) -> Self {
+        if let Some(max_height) = max_height_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.max_height = max_height)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the maximum height of this element. If the signal outputs [`None`] the maximum height is
+    /// set to [`Val::Auto`].
+    fn max_height_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        max_height_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(max_height_option_signal) = max_height_option_signal_option.into() {
+            let max_height_option_signal = max_height_option_signal.map(|max_height_option| max_height_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Val>, Node>(
+                        max_height_option_signal,
+                        move |mut node, max_height_option| node.max_height = max_height_option.unwrap_or(Val::Auto),
+                    )
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the minimum width of this element.
+    fn min_width(mut self, min_width_option: impl Into<Option<Val>>) -> Self {
+        if let Some(min_width) = min_width_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.min_width = min_width)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the minimum width of this element. If the signal outputs [`None`] the minimum width is
+    /// set to [`Val::Auto`].
+    fn min_width_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        min_width_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(min_width_option_signal) = min_width_option_signal_option.into() {
+            let min_width_option_signal = min_width_option_signal.map(|min_width_option| min_width_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Val>, Node>(
+                        min_width_option_signal,
+                        move |mut node, min_width_option| node.min_width = min_width_option.unwrap_or(Val::Auto),
+                    )
+                })
+            });
+        }
+        self
+    }
+
+    /// Set the maximum width of this element.
+    fn max_width(mut self, max_width_option: impl Into<Option<Val>>) -> Self {
+        if let Some(max_width) = max_width_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.max_width = max_width)
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the maximum width of this element. If the signal outputs [`None`] the maximum width is
+    /// set to [`Val::Auto`].
+    fn max_width_signal<S: Signal<Item = impl Into<Option<Val>>> + Send + 'static>(
+        mut self,
+        max_width_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(max_width_option_signal) = max_width_option_signal_option.into() {
+            let max_width_option_signal = max_width_option_signal.map(|max_width_option| max_width_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Val>, Node>(
+                        max_width_option_signal,
+                        move |mut node, max_width_option| node.max_width = max_width_option.unwrap_or(Val::Auto),
+                    )
+                })
+            });
+        }
+        self
+    }
 }
@@ -0,0 +1,155 @@
+//! Semantics for managing elements' static or reactive padding and margin, integrated with the
+//! wrapper elements that [haalka](crate) employs, see [`Spaceable`].
+
+use super::raw::{DeferredUpdaterAppendDirection, RawElWrapper};
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+/// Composable pixel edge insets, convertible to a [`UiRect`].
+///
+/// # Example
+/// ```
+/// use haalka::prelude::*;
+///
+/// // 8 pixels on the left, right, and bottom, 4 pixels on the top
+/// let edges = Edges::all(8.).top(4.);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Edges {
+    rect: UiRect,
+}
+
+#[allow(missing_docs)]
+impl Edges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn all(pixels: f32) -> Self {
+        Self {
+            rect: UiRect::all(Val::Px(pixels)),
+        }
+    }
+
+    pub fn axes(x: f32, y: f32) -> Self {
+        Self::new().x(x).y(y)
+    }
+
+    pub fn top(mut self, pixels: f32) -> Self {
+        self.rect.top = Val::Px(pixels);
+        self
+    }
+
+    pub fn bottom(mut self, pixels: f32) -> Self {
+        self.rect.bottom = Val::Px(pixels);
+        self
+    }
+
+    pub fn left(mut self, pixels: f32) -> Self {
+        self.rect.left = Val::Px(pixels);
+        self
+    }
+
+    pub fn right(mut self, pixels: f32) -> Self {
+        self.rect.right = Val::Px(pixels);
+        self
+    }
+
+    pub fn x(self, pixels: f32) -> Self {
+        self.left(pixels).right(pixels)
+    }
+
+    pub fn y(self, pixels: f32) -> Self {
+        self.top(pixels).bottom(pixels)
+    }
+}
+
+impl From<UiRect> for Edges {
+    fn from(rect: UiRect) -> Self {
+        Self { rect }
+    }
+}
+
+impl From<Val> for Edges {
+    fn from(val: Val) -> Self {
+        UiRect::all(val).into()
+    }
+}
+
+impl From<Edges> for UiRect {
+    fn from(edges: Edges) -> Self {
+        edges.rect
+    }
+}
+
+/// Enables an element to have a static or reactive padding and margin, with consideration for any
+/// potential [haalka](crate) managed wrapper nodes.
+///
+/// Padding is applied to the element's body, while margin is applied to its outermost wrapper, so
+/// for example, the margin of a [mutable viewport](super::viewport_mutable::ViewportMutable) is
+/// applied outside of its viewport rather than inside of its scrollable content.
+pub trait Spaceable: RawElWrapper {
+    /// Set the padding of this element.
+    fn padding(mut self, padding_option: impl Into<Option<Edges>>) -> Self {
+        if let Some(padding) = padding_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.with_component::<Node>(move |mut node| node.padding = padding.into())
+            });
+        }
+        self
+    }
+
+    /// Reactively set the padding of this element. If the signal outputs [`None`] the padding is
+    /// set to [`UiRect::DEFAULT`].
+    fn padding_signal<S: Signal<Item = impl Into<Option<Edges>>> + Send + 'static>(
+        mut self,
+        padding_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(padding_option_signal) = padding_option_signal_option.into() {
+            let padding_option_signal = padding_option_signal.map(|padding_option| padding_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_with_component::<Option<Edges>, Node>(
+                    padding_option_signal,
+                    |mut node, padding_option| {
+                        node.padding = padding_option.map(Into::into).unwrap_or(UiRect::DEFAULT)
+                    },
+                )
+            });
+        }
+        self
+    }
+
+    /// Set the margin of this element.
+    fn margin(mut self, margin_option: impl Into<Option<Edges>>) -> Self {
+        if let Some(margin) = margin_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.with_component::<Node>(move |mut node| node.margin = margin.into())
+                })
+            });
+        }
+        self
+    }
+
+    /// Reactively set the margin of this element. If the signal outputs [`None`] the margin is set
+    /// to [`UiRect::DEFAULT`].
+    fn margin_signal<S: Signal<Item = impl Into<Option<Edges>>> + Send + 'static>(
+        mut self,
+        margin_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(margin_option_signal) = margin_option_signal_option.into() {
+            let margin_option_signal = margin_option_signal.map(|margin_option| margin_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_component::<Option<Edges>, Node>(
+                        margin_option_signal,
+                        |mut node, margin_option| {
+                            node.margin = margin_option.map(Into::into).unwrap_or(UiRect::DEFAULT)
+                        },
+                    )
+                })
+            });
+        }
+        self
+    }
+}
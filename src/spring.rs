@@ -0,0 +1,67 @@
+//! Physically based smoothing of numeric signals, see [`spring`].
+
+use std::time::Duration;
+
+use bevy_math::NormedVectorSpace;
+use futures_signals::{
+    map_ref,
+    signal::{Signal, SignalExt},
+};
+
+use super::time::time_signal;
+
+/// Maximum simulated time step, in seconds; longer frames are split into multiple steps to keep
+/// stiff springs stable.
+const MAX_SPRING_STEP: f32 = 1. / 120.;
+
+/// Below this distance from the target and speed, a spring snaps to its target and comes to rest.
+const SPRING_REST_THRESHOLD: f32 = 1e-3;
+
+/// Smooth the outputs of the `target_signal` by attaching them to a spring, stepped once per frame
+/// on the Bevy clock; the first output of the `target_signal` is output as is. The spring pulls
+/// with a force proportional to the `stiffness` and its velocity is resisted proportionally to the
+/// `damping`; a `damping` of `2. * stiffness.sqrt()` is critically damped, i.e. reaches the target
+/// as fast as possible without overshooting, lower values overshoot and bounce, and higher values
+/// approach the target more slowly.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let open = Mutable::new(false);
+/// El::<Node>::new().width_signal(
+///     spring(open.signal().map_bool(|| 300., || 0.), 200., 2. * 200f32.sqrt()).map(Val::Px),
+/// );
+/// ```
+pub fn spring<T: NormedVectorSpace + PartialEq + Send + Sync + 'static>(
+    target_signal: impl Signal<Item = T> + Send + 'static,
+    stiffness: f32,
+    damping: f32,
+) -> impl Signal<Item = T> + Send + 'static {
+    let mut state_option: Option<(T, T, Duration)> = None;
+    map_ref! {
+        let target = target_signal,
+        let elapsed = time_signal() => (*target, *elapsed)
+    }
+    .map(move |(target, elapsed)| {
+        let (position, velocity, last_elapsed) = state_option.get_or_insert((target, T::ZERO, elapsed));
+        // step by the time since the last step rather than the frame delta, so target changes
+        // mid frame don't step the spring again
+        let mut remaining = elapsed.saturating_sub(*last_elapsed).as_secs_f32();
+        *last_elapsed = elapsed;
+        while remaining > 0. {
+            let dt = remaining.min(MAX_SPRING_STEP);
+            let acceleration = (target - *position) * stiffness - *velocity * damping;
+            *velocity = *velocity + acceleration * dt;
+            *position = *position + *velocity * dt;
+            remaining -= dt;
+        }
+        if (target - *position).norm() < SPRING_REST_THRESHOLD && velocity.norm() < SPRING_REST_THRESHOLD {
+            *position = target;
+            *velocity = T::ZERO;
+        }
+        *position
+    })
+    .dedupe()
+}
@@ -3,19 +3,33 @@ use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
 use futures_signals::{
     signal::{Signal, SignalExt},
+    signal_map::{SignalMap, SignalMapExt},
     signal_vec::{SignalVec, SignalVecExt},
 };
 
 use super::{
     align::{AddRemove, AlignHolder, Alignable, Aligner, Alignment, ChildAlignable},
-    element::{IntoOptionElement, Nameable, UiRootable},
+    anchorable::Anchorable,
+    element::{IntoElement, IntoOptionElement, Nameable, UiRootable},
+    feedback::Feedbackable,
+    flexible::Flexible,
+    focus::KeyboardFocusable,
+    gesture::GestureAware,
     global_event_aware::GlobalEventAware,
+    keyboard_event_aware::KeyboardEventAware,
+    kinetic_scroll::KineticScrollable,
+    layout_aware::LayoutAware,
     mouse_wheel_scrollable::MouseWheelScrollable,
     pointer_event_aware::{CursorOnHoverable, PointerEventAware},
     raw::{RawElWrapper, RawHaalkaEl},
+    resizable::Resizable,
     row::Row,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
     sizeable::Sizeable,
+    spaceable::Spaceable,
     viewport_mutable::ViewportMutable,
+    z_layer::ZLayerable,
 };
 
 /// [`Element`](super::element::Element) with children stacked on directly on top of each other (e.g. along the z-axis), with siblings ordered youngest to oldest, top to bottom. Port of [MoonZoon](https://github.com/MoonZoon/MoonZoon)'s [`Stack`](https://github.com/MoonZoon/MoonZoon/blob/main/crates/zoon/src/element/stack.rs).
@@ -66,14 +80,27 @@ impl<NodeType: Bundle> RawElWrapper for Stack<NodeType> {
     }
 }
 
+impl<NodeType: Bundle> Anchorable for Stack<NodeType> {}
 impl<NodeType: Bundle> CursorOnHoverable for Stack<NodeType> {}
+impl<NodeType: Bundle> Feedbackable for Stack<NodeType> {}
+impl<NodeType: Bundle> Flexible for Stack<NodeType> {}
+impl<NodeType: Bundle> GestureAware for Stack<NodeType> {}
 impl<NodeType: Bundle> GlobalEventAware for Stack<NodeType> {}
+impl<NodeType: Bundle> KeyboardEventAware for Stack<NodeType> {}
+impl<NodeType: Bundle> KeyboardFocusable for Stack<NodeType> {}
+impl<NodeType: Bundle> KineticScrollable for Stack<NodeType> {}
+impl<NodeType: Bundle> LayoutAware for Stack<NodeType> {}
 impl<NodeType: Bundle> Nameable for Stack<NodeType> {}
 impl<NodeType: Bundle> PointerEventAware for Stack<NodeType> {}
 impl<NodeType: Bundle> MouseWheelScrollable for Stack<NodeType> {}
+impl<NodeType: Bundle> Resizable for Stack<NodeType> {}
+impl<NodeType: Bundle> ScrollSnappable for Stack<NodeType> {}
+impl<NodeType: Bundle> Showable for Stack<NodeType> {}
 impl<NodeType: Bundle> Sizeable for Stack<NodeType> {}
+impl<NodeType: Bundle> Spaceable for Stack<NodeType> {}
 impl<NodeType: Bundle> UiRootable for Stack<NodeType> {}
 impl<NodeType: Bundle> ViewportMutable for Stack<NodeType> {}
+impl<NodeType: Bundle> ZLayerable for Stack<NodeType> {}
 
 impl<NodeType: Bundle> Stack<NodeType> {
     /// Declare a static z-axis stacked child, e.g. subsequent calls to [`.layer`][Stack::layer]s
@@ -143,6 +170,25 @@ impl<NodeType: Bundle> Stack<NodeType> {
         }
         self
     }
+
+    /// Declare reactive z-axis stacked children keyed by the keys of the [`SignalMap`],
+    /// ordered by key; each key keeps its child until it is removed or its value is updated.
+    pub fn layers_signal_map<
+        K: Ord + Send + 'static,
+        IE: IntoElement + 'static,
+        S: SignalMap<Key = K, Value = IE> + Send + 'static,
+    >(
+        mut self,
+        layers_signal_map_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(layers_signal_map) = layers_signal_map_option.into() {
+            let apply_alignment = self.apply_alignment_wrapper();
+            self.raw_el = self.raw_el.children_signal_map(
+                layers_signal_map.map_value(move |layer| Self::align_child(layer.into_element(), apply_alignment)),
+            );
+        }
+        self
+    }
 }
 
 impl<NodeType: Bundle> Alignable for Stack<NodeType> {
@@ -203,6 +249,7 @@ impl<NodeType: Bundle> ChildAlignable for Stack<NodeType> {
                     AddRemove::Remove => AlignSelf::DEFAULT,
                 }
             }
+            Alignment::Baseline => {}
         }
     }
 }
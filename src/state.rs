@@ -0,0 +1,27 @@
+//! Reactive access to the app's [`State`]s, see [`state_signal`].
+
+use bevy_state::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+use super::resource_signal::resource_mutable_signal;
+
+/// Outputs the current [`State`] `S` whenever it transitions, or [`None`] if `S` has not been
+/// initialized.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(States, Clone, PartialEq, Eq, Hash, Debug, Default)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// El::<Text>::new().text_signal(state_signal::<GameState>().map(|state_option| Text(format!("{state_option:?}"))));
+/// ```
+pub fn state_signal<S: States>() -> impl Signal<Item = Option<S>> + Send + 'static {
+    resource_mutable_signal::<State<S>, S>(|state| state.get().clone()).dedupe_cloned()
+}
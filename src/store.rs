@@ -0,0 +1,78 @@
+//! Typed global groups of reactive state, see [`Store`].
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Mutex,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use once_cell::sync::Lazy;
+
+static STORES: Lazy<Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = Lazy::new(Mutex::default);
+
+/// A group of related [`Mutable`](futures_signals::signal::Mutable)s, or other cheaply cloneable
+/// reactive state, with a single global instance accessible from anywhere via [`store`];
+/// formalizes the `static Lazy<Mutable<...>>` pattern. Register it with
+/// [`StoreAppExt::register_store`] to initialize it from the [`World`].
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Difficulty(u32);
+///
+/// #[derive(Default, Clone)]
+/// struct Settings {
+///     difficulty: Mutable<u32>,
+///     muted: Mutable<bool>,
+/// }
+///
+/// impl Store for Settings {
+///     fn init(&self, world: &mut World) {
+///         if let Some(difficulty) = world.get_resource::<Difficulty>() {
+///             self.difficulty.set(difficulty.0);
+///         }
+///     }
+/// }
+///
+/// App::new().register_store::<Settings>();
+/// El::<Text>::new().text_signal(store::<Settings>().difficulty.signal_ref(|difficulty| Text(difficulty.to_string())));
+/// ```
+pub trait Store: Default + Clone + Send + Sync + 'static {
+    /// Initialize this store from the [`World`]; run once in [`PostStartup`] for stores registered
+    /// with [`StoreAppExt::register_store`].
+    fn init(&self, _world: &mut World) {}
+}
+
+/// The global instance of the [`Store`] `S`, created with [`Default`] on first access.
+pub fn store<S: Store>() -> S {
+    if let Some(store) = STORES.lock().unwrap().get(&TypeId::of::<S>()) {
+        return store.downcast_ref::<S>().unwrap().clone();
+    }
+    // built without holding the lock, so a [`Default`] impl can access other stores
+    let default = S::default();
+    STORES
+        .lock()
+        .unwrap()
+        .entry(TypeId::of::<S>())
+        .or_insert_with(|| Box::new(default))
+        .downcast_ref::<S>()
+        .unwrap()
+        .clone()
+}
+
+/// Registration of [`Store`]s with an [`App`].
+pub trait StoreAppExt {
+    /// Initialize the [`Store`] `S` from the [`World`] in [`PostStartup`], see [`Store::init`].
+    fn register_store<S: Store>(&mut self) -> &mut Self;
+}
+
+impl StoreAppExt for App {
+    fn register_store<S: Store>(&mut self) -> &mut Self {
+        self.add_systems(PostStartup, |world: &mut World| store::<S>().init(world))
+    }
+}
@@ -0,0 +1,223 @@
+//! Rows of cells whose widths are synchronized across rows, see [`TableLayout`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::{
+    signal::Mutable,
+    signal_vec::{SignalVec, SignalVecExt},
+};
+
+use super::{
+    column::Column,
+    el::El,
+    element::{ElementWrapper, IntoOptionElement, Nameable, UiRootable},
+    flexible::Flexible,
+    gappable::Gappable,
+    global_event_aware::GlobalEventAware,
+    layout_aware::LayoutAware,
+    pointer_event_aware::{CursorOnHoverable, PointerEventAware},
+    raw::RawElWrapper,
+    row::Row,
+    scroll_snap::ScrollSnappable,
+    showable::Showable,
+    sizeable::Sizeable,
+    spaceable::Spaceable,
+    viewport_mutable::ViewportMutable,
+};
+
+/// Vertically stacked rows of cells, where each column of cells is as wide as its widest cell,
+/// e.g. for forms and key/value panels. Rows may have different numbers of cells.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// TableLayout::new()
+///     .column_gap(Val::Px(10.))
+///     .row([
+///         El::<Text>::new().text(Text::new("name")),
+///         El::<Text>::new().text(Text::new("haalka")),
+///     ])
+///     .row([
+///         El::<Text>::new().text(Text::new("description")),
+///         El::<Text>::new().text(Text::new("ergonomic reactive Bevy UI library")),
+///     ]);
+/// ```
+pub struct TableLayout {
+    el: Column<Node>,
+    column_gap: Mutable<Val>,
+}
+
+impl Default for TableLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementWrapper for TableLayout {
+    type EL = Column<Node>;
+    fn element_mut(&mut self) -> &mut Self::EL {
+        &mut self.el
+    }
+}
+
+impl CursorOnHoverable for TableLayout {}
+impl Flexible for TableLayout {}
+impl GlobalEventAware for TableLayout {}
+impl LayoutAware for TableLayout {}
+impl Nameable for TableLayout {}
+impl PointerEventAware for TableLayout {}
+impl ScrollSnappable for TableLayout {}
+impl Showable for TableLayout {}
+impl Sizeable for TableLayout {}
+impl Spaceable for TableLayout {}
+impl UiRootable for TableLayout {}
+impl ViewportMutable for TableLayout {}
+
+#[derive(Component)]
+struct TableLayoutMarker;
+
+/// Index of the column a cell belongs to.
+#[derive(Component)]
+struct TableCell(usize);
+
+impl TableLayout {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {
+            el: Column::<Node>::new().update_raw_el(|raw_el| raw_el.insert(TableLayoutMarker)),
+            column_gap: Mutable::new(Val::ZERO),
+        }
+    }
+
+    /// Set the vertical spacing between rows.
+    pub fn row_gap(mut self, row_gap_option: impl Into<Option<Val>>) -> Self {
+        self.el = self.el.row_gap(row_gap_option);
+        self
+    }
+
+    /// Set the horizontal spacing between columns.
+    pub fn column_gap(self, column_gap_option: impl Into<Option<Val>>) -> Self {
+        if let Some(column_gap) = column_gap_option.into() {
+            self.column_gap.set(column_gap);
+        }
+        self
+    }
+
+    fn row_element<IOE: IntoOptionElement + 'static, I: IntoIterator<Item = IOE>>(
+        cells: I,
+        column_gap: &Mutable<Val>,
+    ) -> Row<Node>
+    where
+        I::IntoIter: Send + 'static,
+    {
+        Row::<Node>::new()
+            .column_gap_signal(column_gap.signal())
+            .items(cells.into_iter().enumerate().map(|(i, cell_option)| {
+                El::<Node>::new()
+                    .update_raw_el(|raw_el| raw_el.insert(TableCell(i)))
+                    .child(cell_option)
+            }))
+    }
+
+    /// Declare a static row of cells.
+    pub fn row<IOE: IntoOptionElement + 'static, I: IntoIterator<Item = IOE>>(
+        mut self,
+        cells_options_option: impl Into<Option<I>>,
+    ) -> Self
+    where
+        I::IntoIter: Send + 'static,
+    {
+        if let Some(cells_options) = cells_options_option.into() {
+            self.el = self.el.item(Self::row_element(cells_options, &self.column_gap));
+        }
+        self
+    }
+
+    /// Declare static rows of cells.
+    pub fn rows<IOE: IntoOptionElement + 'static, I: IntoIterator<Item = IOE>, R: IntoIterator<Item = I>>(
+        mut self,
+        rows_option: impl Into<Option<R>>,
+    ) -> Self
+    where
+        I::IntoIter: Send + 'static,
+    {
+        if let Some(rows) = rows_option.into() {
+            for row in rows {
+                self = self.row(row);
+            }
+        }
+        self
+    }
+
+    /// Declare reactive rows of cells.
+    pub fn rows_signal_vec<
+        IOE: IntoOptionElement + 'static,
+        I: IntoIterator<Item = IOE> + 'static,
+        S: SignalVec<Item = I> + Send + 'static,
+    >(
+        mut self,
+        rows_signal_vec_option: impl Into<Option<S>>,
+    ) -> Self
+    where
+        I::IntoIter: Send + 'static,
+    {
+        if let Some(rows_signal_vec) = rows_signal_vec_option.into() {
+            let column_gap = self.column_gap.clone();
+            self.el = self
+                .el
+                .items_signal_vec(rows_signal_vec.map(move |cells| Self::row_element(cells, &column_gap)));
+        }
+        self
+    }
+}
+
+fn sync_table_column_widths(
+    tables: Query<&Children, With<TableLayoutMarker>>,
+    children: Query<&Children>,
+    cells: Query<&TableCell>,
+    computed_nodes: Query<&ComputedNode>,
+    mut nodes: Query<&mut Node, With<TableCell>>,
+) {
+    for rows in tables.iter() {
+        let row_cells = || {
+            rows.iter()
+                .filter_map(|&row| children.get(row).ok())
+                .flat_map(|row_cells| row_cells.iter().copied())
+                .filter_map(|cell| cells.get(cell).ok().map(|&TableCell(i)| (cell, i)))
+        };
+        let mut widths = Vec::new();
+        for (cell, i) in row_cells() {
+            // the cell's only child does not stretch horizontally, so it has the content's width
+            let width = children
+                .get(cell)
+                .ok()
+                .and_then(|cell_children| cell_children.first())
+                .and_then(|&content| computed_nodes.get(content).ok())
+                .map(|computed_node| computed_node.size().x * computed_node.inverse_scale_factor())
+                .unwrap_or_default();
+            if widths.len() <= i {
+                widths.resize(i + 1, 0.);
+            }
+            widths[i] = f32::max(widths[i], width);
+        }
+        for (cell, i) in row_cells() {
+            if let Ok(mut node) = nodes.get_mut(cell) {
+                let min_width = Val::Px(widths[i]);
+                if node.min_width != min_width {
+                    node.min_width = min_width;
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        sync_table_column_widths.run_if(any_with_component::<TableLayoutMarker>),
+    );
+}
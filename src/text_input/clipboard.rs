@@ -0,0 +1,96 @@
+//! Ctrl+C, Ctrl+X, and Ctrl+V (or Command+C, Command+X, and Command+V) for the focused
+//! [`TextInput`](super::TextInput), through the system clipboard of [`clipboard`](crate::clipboard).
+
+use bevy_app::prelude::*;
+use bevy_cosmic_edit::{prelude::*, *};
+use bevy_ecs::prelude::*;
+use bevy_input::prelude::*;
+use bevy_text::cosmic_text::Edit;
+
+use super::FocusedTextInput;
+use crate::{
+    clipboard::{copy, paste},
+    keyboard::modifiers,
+    utils::spawn,
+};
+
+// text read from the clipboard asynchronously, waiting to be inserted into the input it was pasted
+// into
+#[derive(Resource)]
+struct Pasted {
+    sender: async_channel::Sender<(Entity, String)>,
+    receiver: async_channel::Receiver<(Entity, String)>,
+}
+
+impl Default for Pasted {
+    fn default() -> Self {
+        let (sender, receiver) = async_channel::unbounded();
+        Self { sender, receiver }
+    }
+}
+
+fn handle_clipboard_keys(
+    keys: Res<ButtonInput<KeyCode>>,
+    focused: Res<FocusedTextInput>,
+    mut editors: Query<&mut CosmicEditor>,
+    read_only: Query<(), With<ReadOnly>>,
+    pasted: Res<Pasted>,
+    mut text_changes: EventWriter<CosmicTextChanged>,
+) {
+    let modifiers = modifiers(&keys);
+    if !(modifiers.control || modifiers.super_) {
+        return;
+    }
+    let entity = focused.0;
+    let Ok(mut editor) = editors.get_mut(entity) else {
+        return;
+    };
+    let editable = !read_only.contains(entity);
+    if keys.just_pressed(KeyCode::KeyC) || keys.just_pressed(KeyCode::KeyX) {
+        if let Some(selection) = editor.copy_selection() {
+            copy(selection);
+            if keys.just_pressed(KeyCode::KeyX) && editable && editor.delete_selection() {
+                let text = editor.with_buffer(|buffer| buffer.get_text());
+                text_changes.send(CosmicTextChanged((entity, text)));
+            }
+        }
+    }
+    if keys.just_pressed(KeyCode::KeyV) && editable {
+        let sender = pasted.sender.clone();
+        spawn(async move {
+            if let Some(text) = paste().await {
+                let _ = sender.send((entity, text)).await;
+            }
+        })
+        .detach();
+    }
+}
+
+fn insert_pasted(
+    pasted: Res<Pasted>,
+    mut editors: Query<&mut CosmicEditor>,
+    mut text_changes: EventWriter<CosmicTextChanged>,
+) {
+    while let Ok((entity, text)) = pasted.receiver.try_recv() {
+        if let Ok(mut editor) = editors.get_mut(entity) {
+            editor.delete_selection();
+            editor.insert_string(&text, None);
+            let text = editor.with_buffer(|buffer| buffer.get_text());
+            text_changes.send(CosmicTextChanged((entity, text)));
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Pasted>().add_systems(
+        Update,
+        (
+            handle_clipboard_keys.run_if(
+                resource_exists::<FocusedTextInput>.and(|keys: Res<ButtonInput<KeyCode>>| {
+                    keys.any_just_pressed([KeyCode::KeyC, KeyCode::KeyX, KeyCode::KeyV])
+                }),
+            ),
+            insert_pasted,
+        ),
+    );
+}
@@ -0,0 +1,129 @@
+//! Input method editor (IME) support for [`TextInput`](super::TextInput), without which CJK and
+//! other composed text entry is effectively unusable.
+//!
+//! While a [`TextInput`](super::TextInput) is focused, IME is enabled on the primary window and
+//! its candidate window is positioned at the text cursor. The in progress composition (preedit)
+//! is rendered inline at the cursor and replaced by the committed text once composition finishes.
+
+use bevy_app::prelude::*;
+use bevy_cosmic_edit::{prelude::*, *};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_text::cosmic_text::{Cursor, Edit, Selection};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::ComputedNode;
+use bevy_window::{Ime, PrimaryWindow, Window};
+
+use super::FocusedTextInput;
+
+/// Preedit text currently inserted into a [`TextInput`](super::TextInput)'s editor, which must be
+/// removed before it is replaced or committed.
+struct Preedit {
+    entity: Entity,
+    start: Cursor,
+    len: usize,
+}
+
+fn remove_preedit(editor: &mut CosmicEditor, preedit: Preedit) {
+    let Preedit { start, len, .. } = preedit;
+    editor.set_cursor(Cursor::new(start.line, start.index + len));
+    editor.set_selection(Selection::Normal(start));
+    editor.delete_selection();
+    editor.set_selection(Selection::None);
+}
+
+fn sync_ime_enabled(
+    focused_option: Option<Res<FocusedTextInput>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if let Ok(mut window) = windows.get_single_mut() {
+        let enabled = focused_option.is_some();
+        if window.ime_enabled != enabled {
+            window.ime_enabled = enabled;
+        }
+    }
+}
+
+fn handle_ime(
+    mut imes: EventReader<Ime>,
+    focused_option: Option<Res<FocusedTextInput>>,
+    mut editors: Query<&mut CosmicEditor>,
+    mut preedit_option: Local<Option<Preedit>>,
+    mut text_changes: EventWriter<CosmicTextChanged>,
+) {
+    let focused_option = focused_option.map(|focused| focused.0);
+    for ime in imes.read() {
+        // composition of an input which is no longer focused is abandoned
+        if let Some(preedit) = preedit_option.take_if(|preedit| focused_option != Some(preedit.entity)) {
+            if let Ok(mut editor) = editors.get_mut(preedit.entity) {
+                remove_preedit(&mut editor, preedit);
+            }
+        }
+        let Some(entity) = focused_option else {
+            continue;
+        };
+        let Ok(mut editor) = editors.get_mut(entity) else {
+            continue;
+        };
+        if let Some(preedit) = preedit_option.take() {
+            remove_preedit(&mut editor, preedit);
+        }
+        match ime {
+            Ime::Preedit { value, cursor, .. } => {
+                if value.is_empty() {
+                    continue;
+                }
+                editor.delete_selection();
+                let start = editor.cursor();
+                editor.insert_string(value, None);
+                let offset = cursor.map(|(begin, _)| begin).unwrap_or(value.len());
+                editor.set_cursor(Cursor::new(start.line, start.index + offset));
+                *preedit_option = Some(Preedit {
+                    entity,
+                    start,
+                    len: value.len(),
+                });
+            }
+            Ime::Commit { value, .. } => {
+                editor.delete_selection();
+                editor.insert_string(value, None);
+                let text = editor.with_buffer(|buffer| buffer.get_text());
+                text_changes.send(CosmicTextChanged((entity, text)));
+            }
+            Ime::Enabled { .. } | Ime::Disabled { .. } => (),
+        }
+    }
+}
+
+fn position_ime_candidates(
+    focused: Res<FocusedTextInput>,
+    editors: Query<(&CosmicEditor, &ComputedNode, &GlobalTransform)>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let (Ok((editor, computed_node, transform)), Ok(mut window)) = (editors.get(focused.0), windows.get_single_mut())
+    else {
+        return;
+    };
+    let Some((x, y)) = editor.cursor_position() else {
+        return;
+    };
+    let line_height = editor.with_buffer(|buffer| buffer.metrics().line_height);
+    // physical pixels relative to the node's top left corner, below the cursor's line
+    let top_left = transform.translation().truncate() - computed_node.size() / 2.;
+    let position = (top_left + Vec2::new(x as f32, y as f32 + line_height)) * computed_node.inverse_scale_factor();
+    if window.ime_position != position {
+        window.ime_position = position;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            sync_ime_enabled.run_if(resource_changed_or_removed::<FocusedTextInput>),
+            handle_ime.run_if(on_event::<Ime>),
+            position_ime_candidates.run_if(resource_exists::<FocusedTextInput>),
+        )
+            .chain(),
+    );
+}
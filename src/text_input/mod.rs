@@ -1,6 +1,10 @@
 //! Reactive text input widget and adjacent utilities, a thin wrapper around [`bevy_cosmic_edit`] integrated with [`Signal`]s.
 
-use std::{ops::{Deref, Not}, pin::Pin};
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod ime;
+
+use std::{ops::Deref, pin::Pin};
 
 use bevy_ecs::system::*;
 use bevy_ecs::prelude::*;
@@ -16,8 +20,8 @@ use crate::impl_haalka_methods;
 
 use super::{
     el::El, element::{ElementWrapper, Nameable, UiRootable}, pointer_event_aware::{PointerEventAware, CursorOnHoverable}, raw::{RawElWrapper, register_system}, mouse_wheel_scrollable::MouseWheelScrollable,
-    sizeable::Sizeable, utils::clone, viewport_mutable::ViewportMutable, global_event_aware::GlobalEventAware,
-    raw::{observe, utils::remove_system_holder_on_remove}
+    sizeable::Sizeable, spaceable::Spaceable, utils::clone, viewport_mutable::ViewportMutable, z_layer::ZLayerable, global_event_aware::GlobalEventAware,
+    raw::{observe, utils::remove_system_holder_on_remove}, focus::{self, FocusedElement, KeyboardFocusable}, showable::Showable, layout_aware::LayoutAware, anchorable::Anchorable, scroll_snap::ScrollSnappable, flexible::Flexible,
 };
 use apply::Apply;
 use bevy_cosmic_edit::{self, *, prelude::*, FocusedWidget as CosmicFocusedWidget};
@@ -39,39 +43,57 @@ impl ElementWrapper for TextInput {
     }
 }
 
+impl Anchorable for TextInput {}
+impl Flexible for TextInput {}
 impl GlobalEventAware for TextInput {}
+impl LayoutAware for TextInput {}
 impl Nameable for TextInput {}
 impl PointerEventAware for TextInput {}
 impl MouseWheelScrollable for TextInput {}
+impl ScrollSnappable for TextInput {}
+impl Showable for TextInput {}
 impl Sizeable for TextInput {}
+impl Spaceable for TextInput {}
 impl UiRootable for TextInput {}
 impl ViewportMutable for TextInput {}
+impl ZLayerable for TextInput {}
 impl CursorOnHoverable for TextInput {}
+impl KeyboardFocusable for TextInput {}
 
 /// Marker [`Component`] for [`TextInput`] to prevent focusing on [`Pointer<Down>`] events. Useful when input focus is more conditional.
 #[derive(Component)]
 pub struct TextInputFocusOnDownDisabled;
 
+fn on_focus_on_down_disabled_add(event: Trigger<OnAdd, TextInputFocusOnDownDisabled>, mut commands: Commands) {
+    if let Some(mut entity) = commands.get_entity(event.entity()) {
+        entity.try_insert(focus::FocusOnPressDisabled);
+    }
+}
+
+fn on_focus_on_down_disabled_remove(event: Trigger<OnRemove, TextInputFocusOnDownDisabled>, mut commands: Commands) {
+    if let Some(mut entity) = commands.get_entity(event.entity()) {
+        entity.remove::<focus::FocusOnPressDisabled>();
+    }
+}
+
 // TODO: allow managing multiple spans reactively
 impl TextInput {
     #[allow(missing_docs)]
     pub fn new() -> Self {
         let el = El::<Node>::new().update_raw_el(|raw_el| {
             raw_el
-                .insert((TextEdit, PickingBehavior::default()))
-                .on_event_with_system::<Pointer<Down>, _>(
-                    move |In((_, pointer_down)): In<(_, Pointer<Down>)>,
-                            mut focusable_query: Query<(Entity, &mut Focusable), Without<TextInputFocusOnDownDisabled>>,
-                            mut commands: Commands| {
-                        // TODO: remove this focusable trigger and uncomment .insert_resource below when https://github.com/Dimchikkk/bevy_cosmic_edit/issues/145
-                        // otherwise cursor position is not instantly correct on `Down`
-                        if let Ok((entity, mut focusable)) = focusable_query.get_mut(pointer_down.target) {
-                            focusable.is_focused = true;
-                            commands.trigger_targets(FocusedChange(true), entity);
-                        }
-                        // commands.insert_resource(CosmicFocusedWidget(cosmic_edit_holder.get()));
-                    },
-                )
+                // participate in click to focus and tab traversal with other focusable elements, while
+                // keeping the arrow keys and Enter for caret movement and submission
+                .insert((TextEdit, PickingBehavior::default(), focus::Focusable::default(), focus::FocusNavigationDisabled))
+                // TODO: remove when https://github.com/Dimchikkk/bevy_cosmic_edit/issues/145, otherwise
+                // the cursor position is not instantly correct on `Down`
+                .observe(|down: Trigger<Pointer<Down>>, focus_on_down_disabled: Query<(), With<TextInputFocusOnDownDisabled>>, mut cosmic_focused_widget: ResMut<CosmicFocusedWidget>, mut commands: Commands| {
+                    let entity = down.entity();
+                    if !focus_on_down_disabled.contains(entity) {
+                        cosmic_focused_widget.0 = Some(entity);
+                        commands.insert_resource(FocusedTextInput(entity));
+                    }
+                })
         });
         Self { el }
     }
@@ -146,69 +168,10 @@ impl TextInput {
         self
     }
 
-    /// When this input's focused state changes, run a system which takes [`In`](`System::In`)
-    /// this input's [`Entity`] and its current focused state.
-    pub fn on_focused_change_with_system<Marker>(
-        self,
-        handler: impl IntoSystem<In<(Entity, bool,)>, (), Marker> + Send + 'static,
-    ) -> Self {
-        self.update_raw_el(|raw_el| {
-            let system_holder = Mutable::new(None);
-            raw_el
-            .with_entity(|mut entity| { entity.insert(Focusable { is_focused: false }); })
-            .on_spawn(clone!((system_holder) move |world, entity| {
-                let system = register_system(world, handler);
-                system_holder.set(Some(system));
-                observe(world, entity, move |event: Trigger<FocusedChange>, mut commands: Commands| {
-                    commands.run_system_with_input(system, (entity, event.event().0))
-                });
-            }))
-            .apply(remove_system_holder_on_remove(system_holder.clone()))
-        })
-    }
-
-    /// When this input's focused state changes, run a function with its current focused state.
-    pub fn on_focused_change(self, mut handler: impl FnMut(bool) + Send + Sync + 'static) -> Self {
-        self.on_focused_change_with_system(move |In((_, is_focused))| handler(is_focused))
-    }
-
-    /// Sync a [`Mutable`] with this input's focused state.
-    pub fn focused_sync(self, focused: Mutable<bool>) -> Self {
-        self.on_focused_change(move |is_focused| focused.set_neq(is_focused))
-    }
-
     /// Set the focused state of this input.
     pub fn focus_option(mut self, focus_option: impl Into<Option<bool>>) -> Self {
         if Into::<Option<bool>>::into(focus_option).unwrap_or(false) {
-            self = self.update_raw_el(|raw_el| raw_el.on_spawn_with_system(|In(entity), mut commands: Commands| {
-                commands.insert_resource(FocusedTextInput(entity));
-            }));
-        }
-        self
-    }
-
-    /// Focus this input.
-    pub fn focus(self) -> Self {
-        self.focus_option(true)
-    }
-
-    /// Reactively focus this input.
-    pub fn focus_signal<S: Signal<Item = bool> + Send + 'static>(
-        mut self,
-        focus_signal_option: impl Into<Option<S>>,
-    ) -> Self {
-        if let Some(focus_signal) = focus_signal_option.into() {
-            self = self.update_raw_el(|raw_el| {
-                raw_el.on_signal_one_shot(focus_signal, |In((entity, focus)), focused_option: Option<Res<FocusedTextInput>>, mut commands: Commands| {
-                    if focus {
-                        commands.insert_resource(FocusedTextInput(entity));
-                    } else if let Some(focused) = focused_option {
-                        if focused.0 == entity {
-                            commands.remove_resource::<FocusedTextInput>();
-                        }
-                    }
-                })
-            })
+            self = KeyboardFocusable::focus(self);
         }
         self
     }
@@ -611,14 +574,6 @@ fn on_change(mut changed_events: EventReader<CosmicTextChanged>, mut commands: C
     }
 }
 
-#[derive(Event)]
-struct FocusedChange(bool);
-
-#[derive(Component)]
-struct Focusable {
-    is_focused: bool,
-}
-
 /// [`Resource`] holding the [`Entity`] of the currently focused [`TextInput`]; if one isn't
 /// focused, this resource won't exist in the [`World`]. This resource can be added, mutated, or
 /// removed to control the focused [`TextInput`].
@@ -637,31 +592,53 @@ fn sync_cosmic_focus(
 
 }
 
-fn on_focus_changed(
+// keep haalka's element focus and the focused text input in sync, so text inputs participate in
+// click to focus and tab traversal with other focusable elements
+fn sync_focused_element(
+    focused_element: Res<FocusedElement>,
     focused_option: Option<Res<FocusedTextInput>>,
-    mut text_inputs: Query<(Entity, &mut Focusable)>,
-    mut cosmic_focused_widget: ResMut<CosmicFocusedWidget>,
+    text_inputs: Query<(), With<TextEdit>>,
     mut commands: Commands,
 ) {
-    let focused_option = focused_option.as_deref().map(Deref::deref).copied();
-    for (entity, mut focusable) in text_inputs.iter_mut() {
-        if Some(entity) == focused_option {
-            // TODO: remove condition when https://github.com/Dimchikkk/bevy_cosmic_edit/issues/145
-            if focusable.is_focused.not() {
-                focusable.is_focused = true;
-                commands.trigger_targets(FocusedChange(true), entity);
+    let focused_text_input_option = focused_option.map(|focused| focused.0);
+    match focused_element.0.filter(|&entity| text_inputs.contains(entity)) {
+        Some(entity) => {
+            if focused_text_input_option != Some(entity) {
+                commands.insert_resource(FocusedTextInput(entity));
+            }
+        }
+        None => {
+            if focused_text_input_option.is_some() {
+                commands.remove_resource::<FocusedTextInput>();
             }
-        } else if focusable.is_focused {
-            focusable.is_focused = false;
-            commands.trigger_targets(FocusedChange(false), entity);
         }
     }
-    if let Some(focused) = focused_option {
-        if cosmic_focused_widget.0 != Some(focused) {
-            cosmic_focused_widget.0 = Some(focused);
+}
+
+fn sync_focused_text_input(
+    focused_option: Option<Res<FocusedTextInput>>,
+    mut focused_element: ResMut<FocusedElement>,
+    text_inputs: Query<(), With<TextEdit>>,
+) {
+    match focused_option {
+        Some(focused) => {
+            focused_element.set_if_neq(FocusedElement(Some(focused.0)));
+        }
+        None => {
+            if focused_element.0.is_some_and(|entity| text_inputs.contains(entity)) {
+                focused_element.0 = None;
+            }
         }
-    } else if cosmic_focused_widget.0.is_some() {
-        cosmic_focused_widget.0 = None;
+    }
+}
+
+fn on_focus_changed(
+    focused_option: Option<Res<FocusedTextInput>>,
+    mut cosmic_focused_widget: ResMut<CosmicFocusedWidget>,
+) {
+    let focused_option = focused_option.as_deref().map(Deref::deref).copied();
+    if cosmic_focused_widget.0 != focused_option {
+        cosmic_focused_widget.0 = focused_option;
     }
 }
 
@@ -891,16 +868,22 @@ pub(super) fn plugin(app: &mut App) {
         load_system_fonts: true,
     };
     app
-    .add_plugins(bevy_cosmic_edit::CosmicEditPlugin { font_config })
+    .add_plugins((bevy_cosmic_edit::CosmicEditPlugin { font_config }, ime::plugin))
+    .add_observer(on_focus_on_down_disabled_add)
+    .add_observer(on_focus_on_down_disabled_remove)
     .add_systems(
         Update,
         (
             on_change.run_if(any_with_component::<ListeningToChanges>.and(on_event::<CosmicTextChanged>)),
             (
                 sync_cosmic_focus.run_if(resource_changed::<CosmicFocusedWidget>.and(not(resource_changed_or_removed::<FocusedTextInput>))),
-                on_focus_changed.run_if(resource_changed_or_removed::<FocusedTextInput>)
-            ).chain(),
+                on_focus_changed.run_if(resource_changed_or_removed::<FocusedTextInput>),
+                sync_focused_text_input.run_if(resource_changed_or_removed::<FocusedTextInput>),
+                sync_focused_element.run_if(resource_changed::<FocusedElement>),
+            ).chain().after(focus::FocusSystem),
         )
             .run_if(any_with_component::<TextEdit>),
     );
+    #[cfg(feature = "clipboard")]
+    app.add_plugins(clipboard::plugin);
 }
@@ -0,0 +1,248 @@
+//! Reactive access to the Bevy clock, see [`time_signal`], [`delta_signal`], [`every`],
+//! [`after_frames`], and [`ClockSignalExt`].
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::prelude::*;
+use futures_signals::signal::{Mutable, MutableSignal, Signal, SignalExt};
+use once_cell::sync::Lazy;
+
+static ELAPSED: Lazy<Mutable<Duration>> = Lazy::new(Mutable::default);
+static DELTA: Lazy<Mutable<Duration>> = Lazy::new(Mutable::default);
+static FRAME_COUNT: Lazy<Mutable<u64>> = Lazy::new(Mutable::default);
+
+/// Time elapsed since the app started, according to the default [`Time`] clock; outputs once per
+/// frame.
+pub fn time_signal() -> impl Signal<Item = Duration> + Send + 'static {
+    ELAPSED.signal()
+}
+
+/// Time elapsed since the previous frame, according to the default [`Time`] clock; outputs once per
+/// frame.
+pub fn delta_signal() -> impl Signal<Item = Duration> + Send + 'static {
+    DELTA.signal()
+}
+
+/// Time elapsed since the app started, according to the default [`Time`] clock, as of the start of
+/// the current frame.
+pub(crate) fn elapsed() -> Duration {
+    ELAPSED.get()
+}
+
+/// Number of frames since the app started, as of the start of the current frame.
+pub(crate) fn frame_count() -> u64 {
+    FRAME_COUNT.get()
+}
+
+/// Number of frames since the app started; outputs once per frame.
+pub fn frame_count_signal() -> impl Signal<Item = u64> + Send + 'static {
+    FRAME_COUNT.signal()
+}
+
+/// Resolves once `frames` frames have started, counting from when it is called.
+///
+/// # Example
+/// ```
+/// use haalka::prelude::*;
+///
+/// async fn measure() {
+///     after_frames(2).await;
+///     // ...
+/// }
+/// ```
+pub async fn after_frames(frames: u64) {
+    let target = FRAME_COUNT.get() + frames;
+    FRAME_COUNT
+        .signal()
+        .stop_if(move |&frame| frame >= target)
+        .to_future()
+        .await;
+}
+
+/// Resolves once the next frame has started, see [`after_frames`].
+pub async fn next_frame() {
+    after_frames(1).await
+}
+
+/// Number of whole `period`s elapsed since the app started, according to the default [`Time`]
+/// clock; outputs once per `period`, or every frame if `period` is zero.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// // blinking cursor
+/// El::<Text>::new().visible_signal(every(Duration::from_millis(500)).map(|i| i % 2 == 0));
+/// ```
+pub fn every(period: Duration) -> impl Signal<Item = u64> + Send + 'static {
+    // a zero period would divide by zero, the smallest nonzero one ticks every frame instead
+    let period = period.max(Duration::from_nanos(1));
+    time_signal()
+        .map(move |elapsed| (elapsed.as_secs_f64() / period.as_secs_f64()) as u64)
+        .dedupe()
+}
+
+/// [`Signal`] returned by [`ClockSignalExt::throttle_frames`].
+pub struct ThrottleFrames<S: Signal> {
+    signal: Option<Pin<Box<S>>>,
+    frames: MutableSignal<u64>,
+    frame: u64,
+    frames_between: u64,
+    last_output: Option<u64>,
+    pending: Option<S::Item>,
+}
+
+// fields are never structurally pinned
+impl<S: Signal> Unpin for ThrottleFrames<S> {}
+
+impl<S: Signal> Signal for ThrottleFrames<S> {
+    type Item = S::Item;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        while let Poll::Ready(Some(frame)) = this.frames.poll_change_unpin(cx) {
+            this.frame = frame;
+        }
+        while let Some(signal) = this.signal.as_mut() {
+            match signal.as_mut().poll_change(cx) {
+                Poll::Ready(Some(value)) => this.pending = Some(value),
+                Poll::Ready(None) => this.signal = None,
+                Poll::Pending => break,
+            }
+        }
+        if this.pending.is_some()
+            && this
+                .last_output
+                .is_none_or(|last_output| this.frame >= last_output + this.frames_between)
+        {
+            this.last_output = Some(this.frame);
+            return Poll::Ready(this.pending.take());
+        }
+        if this.signal.is_none() && this.pending.is_none() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// [`Signal`] returned by [`ClockSignalExt::debounce`].
+pub struct Debounce<S: Signal> {
+    signal: Option<Pin<Box<S>>>,
+    elapsed: MutableSignal<Duration>,
+    now: Duration,
+    duration: Duration,
+    last_change: Duration,
+    pending: Option<S::Item>,
+}
+
+// fields are never structurally pinned
+impl<S: Signal> Unpin for Debounce<S> {}
+
+impl<S: Signal> Signal for Debounce<S> {
+    type Item = S::Item;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        while let Poll::Ready(Some(now)) = this.elapsed.poll_change_unpin(cx) {
+            this.now = now;
+        }
+        while let Some(signal) = this.signal.as_mut() {
+            match signal.as_mut().poll_change(cx) {
+                Poll::Ready(Some(value)) => {
+                    this.pending = Some(value);
+                    this.last_change = this.now;
+                }
+                Poll::Ready(None) => this.signal = None,
+                Poll::Pending => break,
+            }
+        }
+        // once the upstream signal has ended, there is nothing left to wait for
+        if this.pending.is_some() && (this.signal.is_none() || this.now >= this.last_change + this.duration) {
+            return Poll::Ready(this.pending.take());
+        }
+        if this.signal.is_none() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Rate limiting [`Signal`] combinators driven by the Bevy clock rather than wall clock sleeps in
+/// the task pool, so their behavior is deterministic with respect to frames.
+pub trait ClockSignalExt: Signal + Sized {
+    /// Outputs the latest value of this signal at most once every `frames` frames; the final
+    /// value is never dropped, only delayed. Useful for rate limiting expensive handlers, e.g.
+    /// updates which trigger relayouts.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let width = Mutable::new(100.);
+    /// El::<Node>::new().width_signal(width.signal().throttle_frames(4).map(Val::Px));
+    /// ```
+    fn throttle_frames(self, frames: u64) -> ThrottleFrames<Self> {
+        ThrottleFrames {
+            signal: Some(Box::pin(self)),
+            frames: FRAME_COUNT.signal(),
+            frame: FRAME_COUNT.get(),
+            frames_between: frames,
+            last_output: None,
+            pending: None,
+        }
+    }
+
+    /// Outputs the latest value of this signal only once it has stopped changing for `duration`,
+    /// according to the default [`Time`] clock.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let query = Mutable::new(String::new());
+    /// El::<Text>::new().text_signal(
+    ///     query
+    ///         .signal_cloned()
+    ///         .debounce(Duration::from_millis(300))
+    ///         .map(|query| Text(format!("searching for {query}"))),
+    /// );
+    /// ```
+    fn debounce(self, duration: Duration) -> Debounce<Self> {
+        let now = ELAPSED.get();
+        Debounce {
+            signal: Some(Box::pin(self)),
+            elapsed: ELAPSED.signal(),
+            now,
+            duration,
+            last_change: now,
+            pending: None,
+        }
+    }
+}
+
+impl<S: Signal> ClockSignalExt for S {}
+
+fn sync_time(time: Res<Time>) {
+    ELAPSED.set(time.elapsed());
+    DELTA.set(time.delta());
+    FRAME_COUNT.set(FRAME_COUNT.get() + 1);
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(PreUpdate, sync_time.run_if(resource_exists::<Time>));
+}
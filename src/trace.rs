@@ -0,0 +1,139 @@
+//! Diagnostics for finding which signals are spamming updates or running slow handlers, see
+//! [`TraceSignalExt`] and [`SignalDiagnostics`].
+
+use std::{
+    any::type_name,
+    collections::HashMap,
+    fmt::Debug,
+    future::{poll_fn, Future},
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_log::prelude::*;
+use bevy_utils::Instant;
+use futures_signals::signal::Signal;
+use once_cell::sync::Lazy;
+
+/// Traced [`Signal`]s which output more than this many times in a single frame are logged as
+/// warnings.
+pub const SIGNAL_EMISSIONS_WARNING_THRESHOLD: u64 = 8;
+
+/// Signal handlers which take longer than this to run are logged as warnings.
+pub const SLOW_SIGNAL_HANDLER_THRESHOLD: Duration = Duration::from_millis(1);
+
+/// Diagnostics of a [`Signal`] labeled with [`TraceSignalExt::trace`].
+#[derive(Clone, Default, Debug)]
+pub struct SignalTrace {
+    /// Number of outputs during the previous frame.
+    pub emissions_last_frame: u64,
+    /// Number of outputs since the signal was first traced.
+    pub emissions: u64,
+    /// [`Debug`] representation of the latest output.
+    pub last_value: String,
+}
+
+static SIGNAL_TRACES: Lazy<Mutex<HashMap<String, (SignalTrace, u64)>>> = Lazy::new(Mutex::default);
+
+/// Diagnostics of every [`Signal`] labeled with [`TraceSignalExt::trace`], keyed by label; updated
+/// once per frame.
+#[derive(Resource, Default, Debug)]
+pub struct SignalDiagnostics(pub HashMap<String, SignalTrace>);
+
+/// [`Signal`] returned by [`TraceSignalExt::trace`].
+pub struct Traced<S> {
+    signal: Pin<Box<S>>,
+    label: String,
+}
+
+// fields are never structurally pinned
+impl<S> Unpin for Traced<S> {}
+
+impl<S: Signal> Signal for Traced<S>
+where
+    S::Item: Debug,
+{
+    type Item = S::Item;
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let poll = this.signal.as_mut().poll_change(cx);
+        if let Poll::Ready(Some(value)) = &poll {
+            let last_value = format!("{value:?}");
+            trace!("signal '{}' output {}", this.label, last_value);
+            let mut traces = SIGNAL_TRACES.lock().unwrap();
+            let (trace, emissions_this_frame) = traces.entry(this.label.clone()).or_default();
+            trace.emissions += 1;
+            trace.last_value = last_value;
+            *emissions_this_frame += 1;
+        }
+        poll
+    }
+}
+
+/// Labeling of [`Signal`]s for diagnostics, see [`SignalDiagnostics`].
+pub trait TraceSignalExt: Signal + Sized {
+    /// Record this signal's outputs under `label`, logging each one at the trace level and warning
+    /// if it outputs more than [`SIGNAL_EMISSIONS_WARNING_THRESHOLD`] times in a single frame.
+    fn trace(self, label: impl Into<String>) -> Traced<Self> {
+        Traced {
+            signal: Box::pin(self),
+            label: label.into(),
+        }
+    }
+}
+
+impl<S: Signal> TraceSignalExt for S {}
+
+/// Run the `future` returned by a handler of a [`Signal`] outputting `T`s, which was called at
+/// `start`, warning once it completes if the handler and `future` together took longer than
+/// [`SLOW_SIGNAL_HANDLER_THRESHOLD`] to run; only time spent polling `future` counts, not time
+/// spent waiting on it.
+pub(crate) fn timed_handler<T>(start: Instant, future: impl Future<Output = ()>) -> impl Future<Output = ()> {
+    let mut future = Box::pin(future);
+    let mut duration = start.elapsed();
+    poll_fn(move |cx| {
+        let poll_start = Instant::now();
+        let poll = future.as_mut().poll(cx);
+        duration += poll_start.elapsed();
+        if poll.is_ready() {
+            check_handler_duration::<T>(duration);
+        }
+        poll
+    })
+}
+
+/// Warn if a handler of a [`Signal`] outputting `T`s took longer than
+/// [`SLOW_SIGNAL_HANDLER_THRESHOLD`] to run.
+fn check_handler_duration<T>(duration: Duration) {
+    if duration > SLOW_SIGNAL_HANDLER_THRESHOLD {
+        warn!(
+            "handler of signal outputting `{}` took {:?} to run",
+            type_name::<T>(),
+            duration
+        );
+    }
+}
+
+fn update_signal_diagnostics(mut diagnostics: ResMut<SignalDiagnostics>) {
+    for (label, (trace, emissions_this_frame)) in SIGNAL_TRACES.lock().unwrap().iter_mut() {
+        trace.emissions_last_frame = *emissions_this_frame;
+        *emissions_this_frame = 0;
+        if trace.emissions_last_frame > SIGNAL_EMISSIONS_WARNING_THRESHOLD {
+            warn!(
+                "signal '{}' output {} times in a single frame",
+                label, trace.emissions_last_frame
+            );
+        }
+        diagnostics.0.insert(label.clone(), trace.clone());
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SignalDiagnostics>()
+        .add_systems(Last, update_signal_diagnostics);
+}
@@ -0,0 +1,136 @@
+//! Animated interpolation between values on the Bevy clock, see [`tween`] and [`tween_signal`].
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_color::{prelude::*, Mix};
+use bevy_math::prelude::*;
+use bevy_ui::prelude::*;
+use futures_signals::signal::{Signal, SignalExt};
+
+use super::time::time_signal;
+
+/// Values which can be interpolated by [`tween`] and [`tween_signal`].
+pub trait Tweenable: Clone + Send + Sync + 'static {
+    /// The value `t` of the way from `self` to `to`, where `t` is usually, but not necessarily,
+    /// in `0..=1`.
+    fn tween(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Tweenable for f32 {
+    fn tween(&self, to: &Self, t: f32) -> Self {
+        self.lerp(*to, t)
+    }
+}
+
+impl Tweenable for Vec2 {
+    fn tween(&self, to: &Self, t: f32) -> Self {
+        self.lerp(*to, t)
+    }
+}
+
+impl Tweenable for Color {
+    fn tween(&self, to: &Self, t: f32) -> Self {
+        self.mix(to, t)
+    }
+}
+
+impl Tweenable for Val {
+    /// [`Val`]s of different units can't be interpolated, so they snap to `to` at the end.
+    fn tween(&self, to: &Self, t: f32) -> Self {
+        match (*self, *to) {
+            (Val::Px(from), Val::Px(to)) => Val::Px(from.tween(&to, t)),
+            (Val::Percent(from), Val::Percent(to)) => Val::Percent(from.tween(&to, t)),
+            (Val::Vw(from), Val::Vw(to)) => Val::Vw(from.tween(&to, t)),
+            (Val::Vh(from), Val::Vh(to)) => Val::Vh(from.tween(&to, t)),
+            (Val::VMin(from), Val::VMin(to)) => Val::VMin(from.tween(&to, t)),
+            (Val::VMax(from), Val::VMax(to)) => Val::VMax(from.tween(&to, t)),
+            _ if t < 1. => *self,
+            _ => *to,
+        }
+    }
+}
+
+/// Interpolate from `from` to `to` over `duration`, according to the [`EaseFunction`], starting
+/// when the signal is first polled; outputs once per frame until `to` is reached.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// // fade in
+/// El::<Node>::new().background_color_signal(
+///     tween(Color::NONE, Color::WHITE, Duration::from_millis(300), EaseFunction::QuadraticOut).map(BackgroundColor),
+/// );
+/// ```
+pub fn tween<T: Tweenable>(
+    from: T,
+    to: T,
+    duration: Duration,
+    easing: EaseFunction,
+) -> impl Signal<Item = T> + Send + 'static {
+    let curve = EasingCurve::new(0., 1., easing);
+    let mut start_option = None;
+    time_signal()
+        .map(move |elapsed| {
+            let start = *start_option.get_or_insert(elapsed);
+            let progress = if duration.is_zero() {
+                1.
+            } else {
+                ((elapsed - start).as_secs_f32() / duration.as_secs_f32()).min(1.)
+            };
+            (from.tween(&to, curve.sample_clamped(progress)), progress >= 1.)
+        })
+        .stop_if(|&(_, done)| done)
+        .map(|(value, _)| value)
+}
+
+/// Interpolate from the current value to each new output of the `target_signal` over `duration`,
+/// according to the [`EaseFunction`]; the first output of the `target_signal` is output as is. If
+/// the target changes mid-interpolation, the new interpolation starts from wherever the previous
+/// one left off.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let hovered = Mutable::new(false);
+/// El::<Node>::new()
+///     .hovered_sync(hovered.clone())
+///     .background_color_signal(
+///         tween_signal(
+///             hovered.signal().map_bool(|| Color::WHITE, || Color::BLACK),
+///             Duration::from_millis(150),
+///             EaseFunction::SineInOut,
+///         )
+///         .map(BackgroundColor),
+///     );
+/// ```
+pub fn tween_signal<T: Tweenable>(
+    target_signal: impl Signal<Item = T> + Send + 'static,
+    duration: Duration,
+    easing: EaseFunction,
+) -> impl Signal<Item = T> + Send + 'static {
+    let current = Arc::new(Mutex::new(None::<T>));
+    target_signal
+        .map({
+            let current = current.clone();
+            move |target| {
+                let from = current.lock().unwrap().clone().unwrap_or_else(|| target.clone());
+                tween(from, target, duration, easing)
+            }
+        })
+        .flatten()
+        .map(move |value| {
+            *current.lock().unwrap() = Some(value.clone());
+            value
+        })
+}
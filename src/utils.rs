@@ -3,13 +3,14 @@ use std::time::Duration;
 use bevy_tasks::{prelude::*, *};
 #[doc(no_inline)]
 pub use enclose::enclose as clone;
-use futures_signals::{
-    map_ref,
-    signal::{Mutable, Signal, SignalExt},
-};
+#[doc(no_inline)]
+pub use futures_signals::map_ref;
+use futures_signals::signal::{Mutable, Signal, SignalExt};
 use haalka_futures_signals_ext::SignalExtExt;
 use std::{future::Future, ops::Not};
 
+use super::signal_driver::{spawn_driven, spawn_local_driven};
+
 /// Block for the `duration`.
 pub async fn sleep(duration: Duration) {
     cfg_if::cfg_if! {
@@ -21,9 +22,21 @@ pub async fn sleep(duration: Duration) {
     }
 }
 
-/// Spawn a non-blocking future onto the [`IoTaskPool`].
+/// Spawn a non-blocking future onto the [`IoTaskPool`], or onto the [`App`](bevy_app::App)'s
+/// executor if it was configured with a [`SignalExecutor`](super::signal_driver::SignalExecutor)
+/// other than the default.
 pub fn spawn<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> Task<T> {
-    IoTaskPool::get().spawn(future)
+    spawn_driven(future)
+}
+
+/// Spawn a non-blocking future which needn't be [`Send`] onto the main thread's executor, see
+/// [`SignalExecutor::Local`](super::signal_driver::SignalExecutor::Local).
+///
+/// # Panics
+/// If not on wasm and not called on the main thread of an [`App`](bevy_app::App) using
+/// [`SignalExecutor::Local`](super::signal_driver::SignalExecutor::Local).
+pub fn spawn_local<T: 'static>(future: impl Future<Output = T> + 'static) -> Task<T> {
+    spawn_local_driven(future)
 }
 
 /// Sync the [`Mutable`] with the [`Signal`].
@@ -2,25 +2,33 @@
 //! [`ViewportMutable`].
 
 use super::{
+    anchorable::logical_rect,
     raw::{
-        observe, register_system, utils::remove_system_holder_on_remove, DeferredUpdaterAppendDirection, RawElWrapper,
-        RawHaalkaEl,
+        observe, register_system, utils::remove_system_holder_on_remove, DeferredUpdaterAppendDirection,
+        IntoOptionRawElement, RawElWrapper, RawHaalkaEl,
     },
-    utils::clone,
+    utils::{clone, spawn},
 };
+use std::time::Duration;
+
 use apply::Apply;
 use bevy_app::prelude::*;
-use bevy_ecs::prelude::*;
+use bevy_ecs::{prelude::*, system::SystemId};
 use bevy_hierarchy::prelude::*;
 use bevy_math::prelude::*;
+use bevy_time::prelude::*;
 use bevy_transform::prelude::*;
 use bevy_ui::prelude::*;
 use bevy_utils::prelude::*;
-use futures_signals::signal::{Mutable, Signal};
+use futures_signals::{
+    map_ref,
+    signal::{Mutable, ReadOnlyMutable, Signal, SignalExt},
+    signal_vec::{MutableVec, MutableVecLockMut, SignalVec, SignalVecExt, VecDiff},
+};
 
 /// Dimensions of an element's "scene", which contains both its visible (via its [`Viewport`]) and
 /// hidden parts.
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
 pub struct Scene {
     #[allow(missing_docs)]
     pub width: f32,
@@ -29,7 +37,7 @@ pub struct Scene {
 }
 
 /// Data specifying the visible portion of an element's [`Scene`].
-#[derive(Clone, Copy, Default, Debug)]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
 pub struct Viewport {
     /// Horizontal offset.
     pub x: f32,
@@ -95,6 +103,10 @@ pub struct ViewportMutation {
     x: Option<f32>,
     /// Optional vertical offset mutation.
     y: Option<f32>,
+    /// Whether the offsets are added to the current ones, or to the ones being animated towards.
+    relative: bool,
+    /// Duration and easing of the animation towards the new offsets.
+    smooth: Option<(Duration, EaseFunction)>,
 }
 
 impl ViewportMutation {
@@ -119,11 +131,43 @@ impl ViewportMutation {
         self.y = Some(y);
         self
     }
+
+    /// Add the offsets to the current ones, or, if the [`Viewport`] is being animated, to the
+    /// ones it is being animated towards, rather than replacing them.
+    pub fn relative(mut self) -> Self {
+        self.relative = true;
+        self
+    }
+
+    /// Animate towards the new offsets over `duration`, according to the [`EaseFunction`], rather
+    /// than jumping to them.
+    pub fn smooth(mut self, duration: Duration, easing: EaseFunction) -> Self {
+        self.smooth = Some((duration, easing));
+        self
+    }
+}
+
+// in progress animation of a [`Scene`]'s offsets, see [`ViewportMutation::smooth`]
+#[derive(Component)]
+pub(crate) struct ViewportAnimation {
+    from: Vec2,
+    pub(crate) to: Vec2,
+    elapsed: Duration,
+    duration: Duration,
+    easing: EaseFunction,
 }
 
 #[derive(Component)]
 struct OnViewportLocationChange;
 
+// anchor child of a [`Scene`] and its vertical position relative to the [`Viewport`], recorded
+// along with the [`Scene`]'s offset, see [`ViewportMutable::scroll_anchoring`]
+#[derive(Component, Default)]
+struct ScrollAnchor {
+    anchor: Option<(Entity, f32)>,
+    offset: f32,
+}
+
 /// Enables the management of a limited visible window (viewport) onto the body of an element.
 /// CRITICALLY NOTE that methods expecting viewport mutability will not function without calling
 /// [`.mutable_viewport(...)`](ViewportMutable::mutable_viewport).
@@ -146,7 +190,9 @@ pub trait ViewportMutable: RawElWrapper {
                           mut nodes: Query<&mut Node>,
                           parents: Query<&Parent>,
                           computed_nodes: Query<&ComputedNode>,
-                          settings: Query<&MutableViewport>| {
+                          settings: Query<&MutableViewport>,
+                          animations: Query<&ViewportAnimation>,
+                          mut commands: Commands| {
                         let entity = mutation.entity();
                         if let Some((((computed_node, parent), settings), mut node)) = computed_nodes
                             .get(entity)
@@ -163,24 +209,59 @@ pub trait ViewportMutable: RawElWrapper {
                             let &ViewportMutation {
                                 x: x_option,
                                 y: y_option,
+                                relative,
+                                smooth,
                             } = mutation.event();
+                            let px = |val: Val| if let Val::Px(px) = val { px } else { 0. };
+                            let current = Vec2::new(px(node.left), px(node.top));
+                            // mutations compound on in progress animations
+                            let base = animations.get(entity).map(|animation| animation.to).unwrap_or(current);
+                            let mut target = base;
                             if let Some(mut x) = x_option {
+                                if relative {
+                                    x += base.x;
+                                }
                                 if matches!(
                                     settings.limit_to_body,
                                     Some(LimitToBody::Horizontal) | Some(LimitToBody::Both)
                                 ) {
                                     x = x.clamp(-(computed_node.size().x - parent.size().x).max(0.), 0.)
                                 };
-                                node.left = Val::Px(x);
+                                target.x = x;
                             }
                             if let Some(mut y) = y_option {
+                                if relative {
+                                    y += base.y;
+                                }
                                 if matches!(
                                     settings.limit_to_body,
                                     Some(LimitToBody::Vertical) | Some(LimitToBody::Both)
                                 ) {
                                     y = y.clamp(-(computed_node.size().y - parent.size().y).max(0.), 0.);
                                 };
-                                node.top = Val::Px(y);
+                                target.y = y;
+                            }
+                            match smooth {
+                                Some((duration, easing)) => {
+                                    commands.entity(entity).insert(ViewportAnimation {
+                                        from: current,
+                                        to: target,
+                                        elapsed: Duration::ZERO,
+                                        duration,
+                                        easing,
+                                    });
+                                }
+                                None => {
+                                    if x_option.is_some() {
+                                        node.left = Val::Px(target.x);
+                                    }
+                                    if y_option.is_some() {
+                                        node.top = Val::Px(target.y);
+                                    }
+                                    if animations.contains(entity) {
+                                        commands.entity(entity).remove::<ViewportAnimation>();
+                                    }
+                                }
                             }
                         }
                     },
@@ -241,6 +322,205 @@ pub trait ViewportMutable: RawElWrapper {
         self.on_viewport_location_change_with_system(move |In((_, (scene, viewport)))| handler(scene, viewport))
     }
 
+    /// Sync a [`Mutable`] with the size of this element's [`Scene`], i.e. its content.
+    fn content_size_sync(self, size: Mutable<Vec2>) -> Self {
+        self.on_viewport_location_change(move |scene, _| size.set_neq(Vec2::new(scene.width, scene.height)))
+    }
+
+    /// Build on this element with a read only view of the size of its [`Scene`], i.e. its content;
+    /// use [`ReadOnlyMutable::signal`] to derive any number of reactive content size signals.
+    fn content_size_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<Vec2>) -> Self) -> Self {
+        let size = Mutable::new(Vec2::ZERO);
+        f(self.content_size_sync(size.clone()), size.read_only())
+    }
+
+    /// Sync a [`Mutable`] with the size of this element's [`Viewport`].
+    fn viewport_size_sync(self, size: Mutable<Vec2>) -> Self {
+        self.on_viewport_location_change(move |_, viewport| size.set_neq(Vec2::new(viewport.width, viewport.height)))
+    }
+
+    /// Build on this element with a read only view of the size of its [`Viewport`]; use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive viewport size signals.
+    fn viewport_size_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<Vec2>) -> Self) -> Self {
+        let size = Mutable::new(Vec2::ZERO);
+        f(self.viewport_size_sync(size.clone()), size.read_only())
+    }
+
+    /// Sync a [`Mutable`] with this element's [`Scene`], i.e. its content size, and [`Viewport`],
+    /// i.e. its scroll offsets and size.
+    fn viewport_sync(self, location: Mutable<(Scene, Viewport)>) -> Self {
+        self.on_viewport_location_change(move |scene, viewport| location.set_neq((scene, viewport)))
+    }
+
+    /// Build on this element with a read only view of its [`Scene`] and [`Viewport`]; use
+    /// [`ReadOnlyMutable::signal`] to derive any number of reactive signals of its scroll offsets,
+    /// content size, and viewport size, e.g. for minimaps, parallax headers, or "back to top"
+    /// buttons.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// Column::<Node>::new()
+    ///     .mutable_viewport(Overflow::clip_y(), LimitToBody::Vertical)
+    ///     .on_scroll_with_system_on_hover(BasicScrollHandler::new().pixels(20.).into_system())
+    ///     .viewport_signal(|column, location| {
+    ///         column.item_signal(
+    ///             location
+    ///                 .signal()
+    ///                 .map(|(_, viewport)| viewport.y > 0.)
+    ///                 .dedupe()
+    ///                 .map_true(|| El::<Text>::new().text(Text::new("back to top"))),
+    ///         )
+    ///     });
+    /// ```
+    fn viewport_signal(self, f: impl FnOnce(Self, ReadOnlyMutable<(Scene, Viewport)>) -> Self) -> Self {
+        let location = Mutable::new(default());
+        f(self.viewport_sync(location.clone()), location.read_only())
+    }
+
+    /// When the bottom of this element's [`Viewport`] comes within `threshold` of the bottom of its
+    /// [`Scene`], run a [`System`] which takes [`In`](`System::In`) this element's [`Entity`]; useful
+    /// for fetching and appending the next page of an infinite list. Fires once per approach, so it
+    /// fires again only after the content grows or the viewport moves away from the end. This
+    /// method can be called repeatedly to register many such handlers.
+    #[allow(clippy::type_complexity)]
+    fn on_near_end_with_system<Marker>(
+        self,
+        threshold: f32,
+        handler: impl IntoSystem<In<Entity>, (), Marker> + Send + 'static,
+    ) -> Self {
+        let system_holder = Mutable::new(None);
+        self.update_raw_el(|raw_el| {
+            raw_el
+                .on_spawn(clone!((system_holder) move |world, _| {
+                    system_holder.set(Some(register_system(world, handler)));
+                }))
+                .apply(remove_system_holder_on_remove(system_holder.clone()))
+        })
+        .on_viewport_location_change_with_system(
+            move |In((entity, (scene, viewport))): In<(Entity, (Scene, Viewport))>,
+                  // [`Scene`] height at the last firing, cleared once the viewport moves away
+                  mut fired_at: Local<Option<f32>>,
+                  mut system: Local<Option<SystemId<In<Entity>>>>,
+                  mut commands: Commands| {
+                if viewport.y + viewport.height < scene.height - threshold {
+                    *fired_at = None;
+                } else if *fired_at != Some(scene.height) {
+                    *fired_at = Some(scene.height);
+                    // only pay the read locking cost once
+                    let &mut system = system.get_or_insert_with(|| system_holder.get().unwrap());
+                    commands.run_system_with_input(system, entity);
+                }
+            },
+        )
+    }
+
+    /// When the bottom of this element's [`Viewport`] comes within `threshold` of the bottom of its
+    /// [`Scene`], run a function; see
+    /// [`.on_near_end_with_system(...)`](ViewportMutable::on_near_end_with_system).
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let items = MutableVec::new_with_values((0..50).collect::<Vec<u32>>());
+    /// Column::<Node>::new()
+    ///     .mutable_viewport(Overflow::clip_y(), LimitToBody::Vertical)
+    ///     .on_scroll_with_system_on_hover(BasicScrollHandler::new().pixels(20.).into_system())
+    ///     .on_near_end(100., clone!((items) move || {
+    ///         let mut lock = items.lock_mut();
+    ///         for item in lock.len() as u32..lock.len() as u32 + 50 {
+    ///             lock.push(item);
+    ///         }
+    ///     }))
+    ///     .items_signal_vec(items.signal_vec().map(|item| El::<Text>::new().text(Text(item.to_string()))));
+    /// ```
+    fn on_near_end(self, threshold: f32, mut handler: impl FnMut() + Send + Sync + 'static) -> Self {
+        self.on_near_end_with_system(threshold, move |_: In<Entity>| handler())
+    }
+
+    /// Keep the content at the top of this element's [`Viewport`] in place when children above it
+    /// are inserted, removed, or resized, e.g. when a chat or log view prepends history, by
+    /// offsetting the viewport by however far that content moved. The first child extending into
+    /// the viewport is the anchor; it is reselected whenever the viewport is scrolled. Requires
+    /// [`.mutable_viewport(...)`](ViewportMutable::mutable_viewport).
+    fn scroll_anchoring(self) -> Self {
+        self.update_raw_el(|raw_el| raw_el.insert(ScrollAnchor::default()))
+    }
+
+    /// Declare reactive children of uniform `item_height`, in logical pixels, only materializing
+    /// those within, or within [`VIRTUAL_CHILDREN_OVERSCAN`] items of, this element's
+    /// [`Viewport`]; spacers above and below them preserve the total scroll height. `item` maps
+    /// each value of the `signal_vec` to its child when it scrolls into view, so lists with tens of
+    /// thousands of items stay cheap. Requires a vertical layout, e.g. a
+    /// [`Column`](super::column::Column), and
+    /// [`.mutable_viewport(...)`](ViewportMutable::mutable_viewport).
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use haalka::prelude::*;
+    ///
+    /// let lines = MutableVec::new_with_values((0..10_000).collect::<Vec<u32>>());
+    /// Column::<Node>::new()
+    ///     .mutable_viewport(Overflow::clip_y(), LimitToBody::Vertical)
+    ///     .on_scroll_with_system_on_hover(BasicScrollHandler::new().pixels(20.).into_system())
+    ///     .children_signal_vec_virtual(lines.signal_vec(), 20., |line| {
+    ///         El::<Text>::new()
+    ///             .with_node(|mut node| node.height = Val::Px(20.))
+    ///             .text(Text(line.to_string()))
+    ///     });
+    /// ```
+    fn children_signal_vec_virtual<T: Clone + Send + Sync + 'static, IORE: IntoOptionRawElement + 'static>(
+        self,
+        signal_vec: impl SignalVec<Item = T> + Send + 'static,
+        item_height: f32,
+        item: impl FnMut(T) -> IORE + Send + 'static,
+    ) -> Self {
+        let items = MutableVec::new();
+        let version = Mutable::new(0u64);
+        let viewport = Mutable::new(Viewport::default());
+        let window = MutableVec::new();
+        let range = Mutable::new((0, 0));
+        let sync_items = signal_vec.for_each(clone!((items, version) move |diff| {
+            apply_vec_diff(&mut items.lock_mut(), diff);
+            *version.lock_mut() += 1;
+            async {}
+        }));
+        let mut last_version_option = None;
+        let sync_window = map_ref! {
+            let &version = version.signal(),
+            let &viewport = viewport.signal() => (version, viewport)
+        }
+        .for_each(clone!((items, window, range) move |(version, viewport)| {
+            let items_changed = last_version_option.replace(version) != Some(version);
+            let items = items.lock_ref();
+            let start = ((viewport.y / item_height).floor().max(0.) as usize).saturating_sub(VIRTUAL_CHILDREN_OVERSCAN);
+            let end = (((viewport.y + viewport.height) / item_height).ceil().max(0.) as usize + VIRTUAL_CHILDREN_OVERSCAN)
+                .min(items.len());
+            let start = start.min(end);
+            shift_window(&window, &items, range.get(), (start, end), items_changed);
+            range.set_neq((start, end));
+            async {}
+        }));
+        let top_height = range.signal().map(move |(start, _)| start as f32 * item_height);
+        let bottom_height = map_ref! {
+            let end = range.signal().map(|(_, end)| end),
+            let len = items.signal_vec_cloned().len() => len.saturating_sub(*end) as f32 * item_height
+        };
+        self.on_viewport_location_change(move |_, new_viewport| viewport.set(new_viewport))
+            .update_raw_el(move |raw_el| {
+                raw_el
+                    .hold_tasks([spawn(sync_items), spawn(sync_window)])
+                    .child(spacer(top_height))
+                    .children_signal_vec(window.signal_vec_cloned().map(item))
+                    .child(spacer(bottom_height))
+            })
+    }
+
     /// Reactively set the horizontal position of the viewport.
     fn viewport_x_signal<S: Signal<Item = f32> + Send + 'static>(
         mut self,
@@ -276,6 +556,121 @@ pub trait ViewportMutable: RawElWrapper {
         }
         self
     }
+
+    /// Reactively set the horizontal and vertical positions of the viewport at once.
+    fn viewport_position_signal<S: Signal<Item = Vec2> + Send + 'static>(
+        mut self,
+        position_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(position_signal) = position_signal_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_one_shot(
+                    position_signal,
+                    |In((entity, Vec2 { x, y })): In<(Entity, Vec2)>, mut commands: Commands| {
+                        commands.trigger_targets(ViewportMutation::x(x).with_y(y), entity);
+                    },
+                )
+            });
+        }
+        self
+    }
+
+    /// Reactively animate the horizontal position of the viewport to each new output of the
+    /// signal over `duration`, according to the [`EaseFunction`].
+    fn viewport_x_signal_smooth<S: Signal<Item = f32> + Send + 'static>(
+        mut self,
+        x_signal_option: impl Into<Option<S>>,
+        duration: Duration,
+        easing: EaseFunction,
+    ) -> Self {
+        if let Some(x_signal) = x_signal_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_one_shot(
+                    x_signal,
+                    move |In((entity, x)): In<(Entity, f32)>, mut commands: Commands| {
+                        commands.trigger_targets(ViewportMutation::x(x).smooth(duration, easing), entity);
+                    },
+                )
+            });
+        }
+        self
+    }
+
+    /// Reactively animate the vertical position of the viewport to each new output of the signal
+    /// over `duration`, according to the [`EaseFunction`].
+    fn viewport_y_signal_smooth<S: Signal<Item = f32> + Send + 'static>(
+        mut self,
+        y_signal_option: impl Into<Option<S>>,
+        duration: Duration,
+        easing: EaseFunction,
+    ) -> Self {
+        if let Some(y_signal) = y_signal_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.on_signal_one_shot(
+                    y_signal,
+                    move |In((entity, y)): In<(Entity, f32)>, mut commands: Commands| {
+                        commands.trigger_targets(ViewportMutation::y(y).smooth(duration, easing), entity);
+                    },
+                )
+            });
+        }
+        self
+    }
+}
+
+/// Number of children materialized beyond each edge of the [`Viewport`] by
+/// [`.children_signal_vec_virtual(...)`](ViewportMutable::children_signal_vec_virtual), so fast
+/// scrolling does not reveal gaps.
+pub const VIRTUAL_CHILDREN_OVERSCAN: usize = 4;
+
+fn apply_vec_diff<T: Clone>(lock: &mut MutableVecLockMut<T>, diff: VecDiff<T>) {
+    match diff {
+        VecDiff::Replace { values } => lock.replace_cloned(values),
+        VecDiff::InsertAt { index, value } => lock.insert_cloned(index, value),
+        VecDiff::UpdateAt { index, value } => lock.set_cloned(index, value),
+        VecDiff::RemoveAt { index } => {
+            lock.remove(index);
+        }
+        VecDiff::Move { old_index, new_index } => lock.move_from_to(old_index, new_index),
+        VecDiff::Push { value } => lock.push_cloned(value),
+        VecDiff::Pop {} => {
+            lock.pop();
+        }
+        VecDiff::Clear {} => lock.clear(),
+    }
+}
+
+fn spacer(height_signal: impl Signal<Item = f32> + Send + 'static) -> RawHaalkaEl {
+    RawHaalkaEl::from(Node::default())
+        .on_signal_with_component::<f32, Node>(height_signal, |mut node, height| node.height = Val::Px(height))
+}
+
+// move the `window` of `items` from `old` to `new`, only adding and removing children at its ends
+// unless `items` changed
+fn shift_window<T: Clone>(
+    window: &MutableVec<T>,
+    items: &[T],
+    (old_start, old_end): (usize, usize),
+    (start, end): (usize, usize),
+    items_changed: bool,
+) {
+    let mut lock = window.lock_mut();
+    if items_changed || start >= old_end || old_start >= end {
+        lock.replace_cloned(items[start..end].to_vec());
+        return;
+    }
+    for _ in old_start..start {
+        lock.remove(0);
+    }
+    for i in (start..old_start).rev() {
+        lock.insert_cloned(0, items[i].clone());
+    }
+    for _ in end..old_end {
+        lock.pop();
+    }
+    for item in &items[old_end.min(items.len())..end] {
+        lock.push_cloned(item.clone());
+    }
 }
 
 #[derive(Event)]
@@ -325,7 +720,72 @@ fn viewport_change_dispatcher(
     }
 }
 
+fn animate_viewports(
+    time: Res<Time>,
+    mut animations: Query<(Entity, &mut ViewportAnimation, &mut Node)>,
+    mut commands: Commands,
+) {
+    for (entity, mut animation, mut node) in animations.iter_mut() {
+        animation.elapsed += time.delta();
+        let progress = if animation.duration.is_zero() {
+            1.
+        } else {
+            (animation.elapsed.as_secs_f32() / animation.duration.as_secs_f32()).min(1.)
+        };
+        let offset = animation.from.lerp(
+            animation.to,
+            EasingCurve::new(0., 1., animation.easing).sample_clamped(progress),
+        );
+        node.left = Val::Px(offset.x);
+        node.top = Val::Px(offset.y);
+        if progress >= 1. {
+            commands.entity(entity).remove::<ViewportAnimation>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn anchor_scrolls(
+    mut scenes: Query<(&mut ScrollAnchor, &mut Node, &Children, &Parent)>,
+    rects: Query<(&ComputedNode, &GlobalTransform)>,
+) {
+    for (mut scroll_anchor, mut node, children, parent) in scenes.iter_mut() {
+        let Ok(viewport) = rects.get(parent.get()).map(|(c, g)| logical_rect(c, g)) else {
+            continue;
+        };
+        let offset = if let Val::Px(top) = node.top { top } else { 0. };
+        // only content moving without the viewport being scrolled is compensated for
+        if let Some((anchor, top)) = scroll_anchor.anchor.filter(|_| offset == scroll_anchor.offset) {
+            if let Ok(rect) = rects.get(anchor).map(|(c, g)| logical_rect(c, g)) {
+                let shift = rect.min.y - viewport.min.y - top;
+                if shift.abs() > 0.5 {
+                    // the anchor's new position is only laid out next frame
+                    node.top = Val::Px(offset - shift);
+                    scroll_anchor.offset = offset - shift;
+                    continue;
+                }
+            }
+        }
+        scroll_anchor.anchor = children
+            .iter()
+            .filter_map(|&child| rects.get(child).ok().map(|(c, g)| (child, logical_rect(c, g))))
+            .find(|(_, rect)| rect.max.y > viewport.min.y)
+            .map(|(child, rect)| (child, rect.min.y - viewport.min.y));
+        scroll_anchor.offset = offset;
+    }
+}
+
 pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        animate_viewports.run_if(any_with_component::<ViewportAnimation>),
+    );
+    app.add_systems(
+        PostUpdate,
+        anchor_scrolls
+            .after(TransformSystem::TransformPropagate)
+            .run_if(any_with_component::<ScrollAnchor>),
+    );
     app.add_systems(
         Update,
         (scene_change_dispatcher, viewport_change_dispatcher)
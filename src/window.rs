@@ -0,0 +1,134 @@
+//! Reactive access to the primary window's dimensions, cursor, and focus, see
+//! [`window_size_signal`], [`scale_factor_signal`], [`cursor_position_signal`],
+//! [`window_focused_signal`], and [`window_occluded_signal`], as well as the [`UiScale`], see
+//! [`ui_scale_sync`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::prelude::*;
+use bevy_ui::UiScale;
+use bevy_window::{PrimaryWindow, Window, WindowOccluded};
+use futures_signals::signal::{Mutable, Signal};
+use once_cell::sync::Lazy;
+
+static WINDOW_SIZE: Lazy<Mutable<Vec2>> = Lazy::new(Mutable::default);
+static SCALE_FACTOR: Lazy<Mutable<f32>> = Lazy::new(|| Mutable::new(1.));
+static CURSOR_POSITION: Lazy<Mutable<Option<Vec2>>> = Lazy::new(Mutable::default);
+static WINDOW_FOCUSED: Lazy<Mutable<bool>> = Lazy::new(|| Mutable::new(true));
+static WINDOW_OCCLUDED: Lazy<Mutable<bool>> = Lazy::new(Mutable::default);
+
+/// Logical size of the primary window. Outputs [`Vec2::ZERO`] until the primary window is
+/// available.
+pub fn window_size_signal() -> impl Signal<Item = Vec2> + Send + 'static {
+    WINDOW_SIZE.signal()
+}
+
+/// Scale factor of the primary window, i.e. the ratio of physical to logical pixels.
+pub fn scale_factor_signal() -> impl Signal<Item = f32> + Send + 'static {
+    SCALE_FACTOR.signal()
+}
+
+/// Logical position of the cursor in the primary window, relative to its top left corner, or
+/// [`None`] if the cursor is outside of it. See
+/// [`PointerEventAware::on_cursor_position_change`](super::pointer_event_aware::PointerEventAware::on_cursor_position_change)
+/// for the position relative to a specific element.
+pub fn cursor_position_signal() -> impl Signal<Item = Option<Vec2>> + Send + 'static {
+    CURSOR_POSITION.signal()
+}
+
+/// Whether the primary window has focus, e.g. for dimming the UI or showing a "paused" overlay when
+/// it does not.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// El::<Text>::new()
+///     .text(Text::new("paused"))
+///     .visible_signal(window_focused_signal().map(|focused| !focused));
+/// ```
+pub fn window_focused_signal() -> impl Signal<Item = bool> + Send + 'static {
+    WINDOW_FOCUSED.signal()
+}
+
+/// Whether the primary window is fully hidden from view, e.g. minimized or covered by other
+/// windows; not reported on all platforms.
+pub fn window_occluded_signal() -> impl Signal<Item = bool> + Send + 'static {
+    WINDOW_OCCLUDED.signal()
+}
+
+/// [`Mutable`] synced with the [`UiScale`] and its last synced value, see [`ui_scale_sync`].
+#[derive(Resource)]
+struct UiScaleSync {
+    mutable: Mutable<f32>,
+    last_option: Option<f32>,
+}
+
+/// Keep `mutable` and the [`UiScale`] in sync in both directions, e.g. for an accessibility scale
+/// slider which rescales the whole UI; `mutable` is initialized from the current [`UiScale`]. If
+/// both sides change in the same frame, the [`UiScale`] wins. Only the latest `mutable` passed here
+/// is kept in sync.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use haalka::prelude::*;
+///
+/// let scale = Mutable::new(1.);
+/// El::<Node>::new()
+///     .update_raw_el(clone!((scale) move |raw_el| raw_el.on_spawn(move |world, _| ui_scale_sync(world, scale))))
+///     .on_click(move || scale.set(scale.get() + 0.25));
+/// ```
+pub fn ui_scale_sync(world: &mut World, mutable: Mutable<f32>) {
+    world.insert_resource(UiScaleSync {
+        mutable,
+        last_option: None,
+    });
+}
+
+fn sync_ui_scale(mut ui_scale: ResMut<UiScale>, mut ui_scale_sync: ResMut<UiScaleSync>) {
+    let ui_scale_changed = ui_scale.is_changed();
+    let UiScaleSync { mutable, last_option } = &mut *ui_scale_sync;
+    if (ui_scale_changed || last_option.is_none()) && *last_option != Some(ui_scale.0) {
+        *last_option = Some(ui_scale.0);
+        mutable.set_neq(ui_scale.0);
+        return;
+    }
+    let value = mutable.get();
+    if *last_option != Some(value) {
+        *last_option = Some(value);
+        ui_scale.0 = value;
+    }
+}
+
+fn sync_primary_window(windows: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>) {
+    if let Ok(window) = windows.get_single() {
+        WINDOW_SIZE.set_neq(window.size());
+        SCALE_FACTOR.set_neq(window.scale_factor());
+        CURSOR_POSITION.set_neq(window.cursor_position());
+        WINDOW_FOCUSED.set_neq(window.focused);
+    }
+}
+
+fn sync_primary_window_occluded(
+    mut occluded_events: EventReader<WindowOccluded>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    for &WindowOccluded { window, occluded } in occluded_events.read() {
+        if primary_window.get(window).is_ok() {
+            WINDOW_OCCLUDED.set_neq(occluded);
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        (
+            sync_primary_window,
+            sync_primary_window_occluded.run_if(on_event::<WindowOccluded>),
+            sync_ui_scale.run_if(resource_exists::<UiScale>.and(resource_exists::<UiScaleSync>)),
+        ),
+    );
+}
@@ -0,0 +1,117 @@
+//! Semantics for stacking independently built elements in named layers, see [`ZLayerable`] and
+//! [`ZLayers`].
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ui::{prelude::*, UiSystem};
+use futures_signals::signal::{Signal, SignalExt};
+
+use super::raw::{DeferredUpdaterAppendDirection, RawElWrapper};
+
+/// Named stacking layer, whose [`GlobalZIndex`] is determined by the [`ZLayers`] [`Resource`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[require(GlobalZIndex)]
+pub enum ZLayer {
+    #[allow(missing_docs)]
+    Background,
+    #[allow(missing_docs)]
+    Content,
+    #[allow(missing_docs)]
+    Popup,
+    #[allow(missing_docs)]
+    Tooltip,
+    #[allow(missing_docs)]
+    Drag,
+}
+
+/// [`Resource`] specifying the [`GlobalZIndex`] of each [`ZLayer`]; modifying it restacks all
+/// layered elements.
+#[derive(Resource, Clone, Copy, Debug)]
+#[allow(missing_docs)]
+pub struct ZLayers {
+    pub background: i32,
+    pub content: i32,
+    pub popup: i32,
+    pub tooltip: i32,
+    pub drag: i32,
+}
+
+impl Default for ZLayers {
+    fn default() -> Self {
+        Self {
+            background: -1000,
+            content: 0,
+            popup: 1000,
+            tooltip: 2000,
+            drag: 3000,
+        }
+    }
+}
+
+impl ZLayers {
+    /// The [`GlobalZIndex`] value of the [`ZLayer`].
+    pub fn z_index(&self, layer: ZLayer) -> i32 {
+        match layer {
+            ZLayer::Background => self.background,
+            ZLayer::Content => self.content,
+            ZLayer::Popup => self.popup,
+            ZLayer::Tooltip => self.tooltip,
+            ZLayer::Drag => self.drag,
+        }
+    }
+}
+
+/// Enables an element to be stacked in a static or reactive [`ZLayer`], with consideration for any
+/// potential [haalka](crate) managed wrapper nodes.
+pub trait ZLayerable: RawElWrapper {
+    /// Stack this element in the [`ZLayer`].
+    fn z_layer(mut self, layer_option: impl Into<Option<ZLayer>>) -> Self {
+        if let Some(layer) = layer_option.into() {
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| raw_el.insert(layer))
+            });
+        }
+        self
+    }
+
+    /// Reactively stack this element in a [`ZLayer`]. If the signal outputs [`None`], the element is
+    /// removed from its [`ZLayer`].
+    fn z_layer_signal<S: Signal<Item = impl Into<Option<ZLayer>>> + Send + 'static>(
+        mut self,
+        layer_option_signal_option: impl Into<Option<S>>,
+    ) -> Self {
+        if let Some(layer_option_signal) = layer_option_signal_option.into() {
+            let layer_option_signal = layer_option_signal.map(|layer_option| layer_option.into());
+            self = self.update_raw_el(|raw_el| {
+                raw_el.defer_update(DeferredUpdaterAppendDirection::Back, move |raw_el| {
+                    raw_el.on_signal_with_entity::<Option<ZLayer>>(layer_option_signal, |mut entity, layer_option| {
+                        if let Some(layer) = layer_option {
+                            entity.insert(layer);
+                        } else {
+                            entity.remove::<(ZLayer, GlobalZIndex)>();
+                        }
+                    })
+                })
+            });
+        }
+        self
+    }
+}
+
+fn apply_z_layers(z_layers: Res<ZLayers>, mut layered: Query<(Ref<ZLayer>, &mut GlobalZIndex)>) {
+    let restack = z_layers.is_changed();
+    for (layer, mut global_z_index) in layered.iter_mut() {
+        if restack || layer.is_changed() {
+            global_z_index.0 = z_layers.z_index(*layer);
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ZLayers>().add_systems(
+        PostUpdate,
+        apply_z_layers
+            .before(UiSystem::Stack)
+            .run_if(any_with_component::<ZLayer>),
+    );
+}